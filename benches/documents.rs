@@ -0,0 +1,127 @@
+//! Benchmarks on representative documents, from `benches/data`.
+//!
+//! Each benchmark reports the throughput in bytes of input per second, and
+//! prints the number of allocations and the peak of allocated memory of one
+//! run, counted by a global allocator.
+
+#![feature(test)]
+
+extern crate test;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use html_filter::prelude::*;
+use test::Bencher;
+
+/// Allocator counting the allocations of the benchmarks
+struct Counter;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+const SMALL: &str = include_str!("data/small.html");
+const MEDIUM: &str = include_str!("data/medium.html");
+const LARGE: &str = include_str!("data/large.html");
+
+/// Builds a text-only document of about 1 MB, with a line break every few
+/// words.
+fn text_only() -> String {
+    "Lorem ipsum dolor sit amet, consectetur adipiscing elit.\n".repeat(18_000)
+}
+
+/// Runs `f` once, and prints its allocations and the peak of memory it
+/// allocated on top of the memory already in use.
+fn report_allocations<T>(name: &str, f: impl FnOnce() -> T) {
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(test::black_box(f()));
+    let count = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let peak = PEAK.load(Ordering::Relaxed) - start;
+    eprintln!("{name}: {count} allocations, {peak} bytes at peak");
+}
+
+/// Benchmarks `f` on the tree of the document, after reporting its
+/// allocations.
+fn bench_tree<T>(bencher: &mut Bencher, name: &str, input: &str, f: impl Fn(&Html) -> T) {
+    let tree = Html::parse(input).unwrap();
+    report_allocations(name, || f(&tree));
+    bencher.bytes = input.len() as u64;
+    bencher.iter(|| f(test::black_box(&tree)));
+}
+
+/// Benchmarks the parsing of the document, after reporting its allocations.
+fn bench_parse(bencher: &mut Bencher, name: &str, input: &str) {
+    report_allocations(name, || Html::parse(input).unwrap());
+    bencher.bytes = input.len() as u64;
+    bencher.iter(|| Html::parse(test::black_box(input)).unwrap());
+}
+
+#[bench]
+fn parse_small(bencher: &mut Bencher) {
+    bench_parse(bencher, "parse_small", SMALL);
+}
+
+#[bench]
+fn parse_medium(bencher: &mut Bencher) {
+    bench_parse(bencher, "parse_medium", MEDIUM);
+}
+
+#[bench]
+fn parse_large(bencher: &mut Bencher) {
+    bench_parse(bencher, "parse_large", LARGE);
+}
+
+#[bench]
+fn parse_text_only(bencher: &mut Bencher) {
+    bench_parse(bencher, "parse_text_only", &text_only());
+}
+
+#[bench]
+fn display_small(bencher: &mut Bencher) {
+    bench_tree(bencher, "display_small", SMALL, ToString::to_string);
+}
+
+#[bench]
+fn display_medium(bencher: &mut Bencher) {
+    bench_tree(bencher, "display_medium", MEDIUM, ToString::to_string);
+}
+
+#[bench]
+fn display_large(bencher: &mut Bencher) {
+    bench_tree(bencher, "display_large", LARGE, ToString::to_string);
+}
+
+#[bench]
+fn text_small(bencher: &mut Bencher) {
+    bench_tree(bencher, "text_small", SMALL, |tree| tree.text_content(false));
+}
+
+#[bench]
+fn text_medium(bencher: &mut Bencher) {
+    bench_tree(bencher, "text_medium", MEDIUM, |tree| tree.text_content(false));
+}
+
+#[bench]
+fn text_large(bencher: &mut Bencher) {
+    bench_tree(bencher, "text_large", LARGE, |tree| tree.text_content(false));
+}
@@ -0,0 +1,50 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::fs::read_to_string;
+
+use html_filter::prelude::*;
+use test::Bencher;
+
+fn count_tags(html: &Html) -> usize {
+    match html {
+        Html::Tag { child, .. } => 1 + count_tags(child),
+        Html::Vec(vec) => vec.iter().map(count_tags).sum(),
+        _ => 0,
+    }
+}
+
+fn load() -> Html {
+    let content = read_to_string("tests/data/index.html").unwrap();
+    Html::parse(&content.repeat(50)).unwrap()
+}
+
+#[bench]
+fn count_tags_tree(bencher: &mut Bencher) {
+    let tree = load();
+    bencher.iter(|| count_tags(test::black_box(&tree)));
+}
+
+#[bench]
+fn count_tags_flat(bencher: &mut Bencher) {
+    let flat = load().to_flat();
+    bencher.iter(|| {
+        test::black_box(&flat)
+            .iter()
+            .filter(|(_, node)| matches!(node.as_data(), NodeData::Tag(_)))
+            .count()
+    });
+}
+
+#[bench]
+fn display_tree(bencher: &mut Bencher) {
+    let tree = load();
+    bencher.iter(|| test::black_box(&tree).to_string());
+}
+
+#[bench]
+fn display_flat(bencher: &mut Bencher) {
+    let flat = load().to_flat();
+    bencher.iter(|| test::black_box(&flat).to_string());
+}
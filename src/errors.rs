@@ -9,7 +9,7 @@
 /// Macro to add a developer error with a generic failure text.
 macro_rules! safe_expect {
     ($code:expr, $reason:expr) => {
-        $code.expect(&format!(
+        $code.expect(&alloc::format!(
             "
 This is not meant to happen.
 Please report this problem at https://github.com/t-webber/html-parser/issues/new.
@@ -2,7 +2,9 @@
 //! either be blacklisted or whitelisted by the user. This module handles the
 //! logic for the combination of these rules.
 
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString as _};
+use alloc::vec::Vec;
 
 use crate::types::tag::Attribute;
 
@@ -24,8 +26,8 @@ pub struct BlackWhiteList {
     ///
     /// The hashmap maps a name to a target, and a bool. The boolean is `true`
     /// if the item is whitelisted, and `false` if the item is blacklisted.
-    items: HashMap<String, bool>,
-    /// Indicates if a whitelisted element was pushed into the [`HashMap`].
+    items: BTreeMap<String, bool>,
+    /// Indicates if a whitelisted element was pushed into the [`BTreeMap`].
     whitelist_empty: bool,
 }
 
@@ -80,7 +82,7 @@ impl BlackWhiteList {
 
 impl Default for BlackWhiteList {
     fn default() -> Self {
-        Self { items: HashMap::new(), whitelist_empty: true, default: true }
+        Self { items: BTreeMap::new(), whitelist_empty: true, default: true }
     }
 }
 
@@ -135,7 +137,7 @@ pub struct ValueAssociateHash {
 impl ValueAssociateHash {
     /// Checks if the attributes form a correct combination of rules
     pub fn check(&self, attrs: &[Attribute]) -> ElementState {
-        let attrs_map: HashMap<_, _> = attrs
+        let attrs_map: BTreeMap<_, _> = attrs
             .iter()
             .map(|attr| (attr.as_name().to_string(), attr.as_value()))
             .collect();
@@ -172,7 +174,7 @@ impl ValueAssociateHash {
             .blacklist
             .iter()
             .map(|(name, value)| (name, value))
-            .collect::<HashMap<_, _>>();
+            .collect::<BTreeMap<_, _>>();
         for attr in attrs {
             if let Some(value) = blacklist.get(&attr.as_name().to_string()) {
                 if attr.as_value() == value.as_ref() {
@@ -0,0 +1,278 @@
+//! Module to map the content of an [`Html`] tree onto typed structs.
+//!
+//! The structs are declared with the [`from_html!`](crate::from_html) macro,
+//! that implements [`FromHtml`] by reading each field with [`Html::extract`].
+
+use alloc::borrow::ToOwned as _;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use super::selector::SelectorError;
+use crate::prelude::Html;
+
+/// Declares a struct that can be extracted from an [`Html`] tree
+///
+/// Each field is read with [`Html::extract`], with the selector and optional
+/// attribute given in its `#[html(...)]` attribute, that must come before the
+/// other attributes of the field. The type of a field must implement
+/// [`FromHtmlField`].
+///
+/// This plays the role of a `#[derive(FromHtml)]`: the struct is declared
+/// inside the macro, and [`FromHtml`] is implemented for it.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// from_html! {
+///     #[derive(Debug)]
+///     struct Product {
+///         #[html(selector = ".name")]
+///         name: String,
+///         #[html(selector = ".price", attr = "data-value")]
+///         price: f64,
+///         #[html(selector = ".tag")]
+///         tags: Vec<String>,
+///     }
+/// }
+///
+/// let html = Html::parse(
+///     r#"<div><h2 class="name">Lamp</h2><span class="price" data-value="19.5">19,50 €</span>
+///     <span class="tag">home</span><span class="tag">light</span></div>"#,
+/// )
+/// .unwrap();
+/// let product = Product::from_html(&html).unwrap();
+/// assert!(product.name == "Lamp");
+/// assert!(product.price == 19.5);
+/// assert!(product.tags == ["home", "light"]);
+/// ```
+#[macro_export]
+macro_rules! from_html {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                #[html(selector = $selector:literal $(, attr = $attr:literal)? $(,)?)]
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $crate::prelude::FromHtml for $name {
+            fn from_html(
+                html: &$crate::prelude::Html,
+            ) -> ::core::result::Result<Self, $crate::prelude::ExtractError> {
+                ::core::result::Result::Ok(Self {
+                    $(
+                        $field: html.extract::<$ty>(
+                            $selector,
+                            $crate::from_html!(@attr $($attr)?),
+                        )?,
+                    )*
+                })
+            }
+        }
+    };
+    (@attr) => {
+        ::core::option::Option::None
+    };
+    (@attr $attr:literal) => {
+        ::core::option::Option::Some($attr)
+    };
+}
+
+/// Implements [`FromHtmlValue`] with [`FromStr`](core::str::FromStr).
+macro_rules! impl_from_html_value {
+    ($($ty:ty)*) => {
+        $(
+            impl FromHtmlValue for $ty {
+                fn from_value(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+/// Errors returned when a value couldn't be extracted from an [`Html`] tree
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let html = Html::parse("<p class='price'>free</p>").unwrap();
+/// let err = html.extract::<f64>(".price", None).unwrap_err();
+/// assert!(err.to_string() == "Invalid value 'free' for selector '.price'.");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtractError {
+    /// The selector is invalid
+    InvalidSelector(SelectorError),
+    /// A value couldn't be converted into the type of the field
+    #[non_exhaustive]
+    InvalidValue {
+        /// Selector of the field
+        selector: String,
+        /// Value that couldn't be converted
+        value: String,
+    },
+    /// No value was found for a required field
+    #[non_exhaustive]
+    Missing {
+        /// Selector of the field
+        selector: String,
+    },
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSelector(err) => err.fmt(f),
+            Self::InvalidValue { selector, value } =>
+                write!(f, "Invalid value '{value}' for selector '{selector}'."),
+            Self::Missing { selector } => write!(f, "No value found for selector '{selector}'."),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Error for ExtractError {}
+
+/// Types that can be extracted from an [`Html`] tree
+///
+/// This is implemented by the structs declared with the
+/// [`from_html!`](crate::from_html) macro.
+pub trait FromHtml: Sized {
+    /// Extracts a value from an [`Html`] tree
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtractError`] if a selector is invalid, or if a value is
+    /// missing or invalid.
+    fn from_html(html: &Html) -> Result<Self, ExtractError>;
+}
+
+/// Types of the fields of a struct declared with
+/// [`from_html!`](crate::from_html)
+///
+/// This is implemented for the types of [`FromHtmlValue`], that require
+/// exactly one value, for [`Option`], that accepts a missing value, and for
+/// [`Vec`], that collects all the values.
+pub trait FromHtmlField: Sized {
+    /// Builds the field from the values found for the `selector`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtractError`] if a value is missing or invalid.
+    fn from_values(selector: &str, values: Vec<String>) -> Result<Self, ExtractError>;
+}
+
+impl<T: FromHtmlValue> FromHtmlField for Option<T> {
+    fn from_values(selector: &str, values: Vec<String>) -> Result<Self, ExtractError> {
+        values
+            .into_iter()
+            .next()
+            .map(|value| parse_value(selector, value))
+            .transpose()
+    }
+}
+
+impl<T: FromHtmlValue> FromHtmlField for T {
+    fn from_values(selector: &str, values: Vec<String>) -> Result<Self, ExtractError> {
+        Option::<Self>::from_values(selector, values)?
+            .ok_or_else(|| ExtractError::Missing { selector: selector.to_owned() })
+    }
+}
+
+impl<T: FromHtmlValue> FromHtmlField for Vec<T> {
+    fn from_values(selector: &str, values: Vec<String>) -> Result<Self, ExtractError> {
+        values
+            .into_iter()
+            .map(|value| parse_value(selector, value))
+            .collect()
+    }
+}
+
+/// Types that can be read from a single text or attribute value
+///
+/// This is implemented for [`String`], [`bool`], [`char`] and the numeric
+/// types, with their [`FromStr`](core::str::FromStr) implementation.
+pub trait FromHtmlValue: Sized {
+    /// Converts a value, or returns `None` if it is invalid
+    fn from_value(value: &str) -> Option<Self>;
+}
+
+impl_from_html_value!(String bool char f32 f64 i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl Html {
+    /// Extracts a value from the tags that match a selector
+    ///
+    /// The value of each matching tag is its trimmed text content or, if
+    /// `attr` is given, the value of that attribute. The tags without this
+    /// attribute are skipped. The values are then converted into `T`:
+    ///
+    /// - a [`FromHtmlValue`], like [`String`] or [`f64`], requires at least one
+    ///   value, and uses the first one;
+    /// - an [`Option`] is `None` if there are no values;
+    /// - a [`Vec`] contains all the values.
+    ///
+    /// This is the method used by the structs declared with
+    /// [`from_html!`](crate::from_html).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtractError`] if the selector is invalid, or if a value
+    /// is missing or can't be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<ul><li data-id="1"> A </li><li data-id="2">B</li></ul>"#).unwrap();
+    /// assert!(html.extract::<String>("li", None).unwrap() == "A");
+    /// assert!(html.extract::<Vec<u32>>("li", Some("data-id")).unwrap() == [1, 2]);
+    /// assert!(html.extract::<Option<String>>("p", None).unwrap().is_none());
+    /// ```
+    pub fn extract<T>(&self, selector: &str, attr: Option<&str>) -> Result<T, ExtractError>
+    where
+        T: FromHtmlField,
+    {
+        let values = self
+            .select(selector)
+            .map_err(ExtractError::InvalidSelector)?
+            .into_iter()
+            .filter_map(|node| match (node, attr) {
+                (Self::Tag { tag, .. }, Some(name)) => tag.find_attr_value(name).cloned(),
+                (_, Some(_)) => None,
+                (_, None) => Some(node.text_content(false).trim().to_owned()),
+            })
+            .collect();
+        T::from_values(selector, values)
+    }
+}
+
+/// Converts a value found for a `selector`.
+fn parse_value<T>(selector: &str, value: String) -> Result<T, ExtractError>
+where
+    T: FromHtmlValue,
+{
+    T::from_value(&value)
+        .ok_or_else(|| ExtractError::InvalidValue { selector: selector.to_owned(), value })
+}
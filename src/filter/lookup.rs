@@ -0,0 +1,238 @@
+//! Module to look up specific nodes in an [`Html`] tree without building a
+//! [`Filter`](crate::prelude::Filter).
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter;
+
+use crate::prelude::Html;
+
+impl Html {
+    /// Returns the content of all the comments of the tree
+    ///
+    /// The comments are returned in document order, whatever their depth,
+    /// including the one that was still open at the end of the input, like
+    /// `<!-- end`. The conditional comments are only returned if they weren't
+    /// parsed, see
+    /// [`ParseOptions::parse_conditional_comments`](crate::prelude::ParseOptions::parse_conditional_comments).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<!-- build: 42 --><p>a<!-- note --></p><!-- end").unwrap();
+    /// assert!(html.comments() == [" build: 42 ", " note ", " end"]);
+    /// ```
+    #[must_use]
+    pub fn comments(&self) -> Vec<&str> {
+        self.descendants()
+            .filter_map(|node| match node {
+                Self::BogusComment(comment) | Self::Comment(comment) => Some(comment.as_str()),
+                Self::Conditional(_)
+                | Self::Doctype { .. }
+                | Self::Empty
+                | Self::Tag { .. }
+                | Self::Text(_)
+                | Self::Vec(_) => None,
+            })
+            .collect()
+    }
+
+    /// Checks if the tree contains a tag with the given name
+    ///
+    /// The names are compared case-insensitively, except in `<svg>` and
+    /// `<math>`. The walk stops at the first matching tag, whatever its depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<div><FORM><input /></FORM></div>").unwrap();
+    /// assert!(html.contains_tag("form"));
+    /// assert!(!html.contains_tag("button"));
+    /// ```
+    #[must_use]
+    pub fn contains_tag(&self, name: &str) -> bool {
+        self.descendants().any(|node| {
+            if let Self::Tag { tag, .. } = node {
+                tag.as_namespace().eq_names(tag.as_name(), name)
+            } else {
+                false
+            }
+        })
+    }
+
+    /// Checks if a text of the tree contains the given substring
+    ///
+    /// The text nodes are checked one by one, in document order, until one of
+    /// them contains `substr`, so a substring split over several text nodes,
+    /// like `a<b>b</b>`, isn't found. Comments and attribute values are not
+    /// checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>Out of <b>stock</b></p><!-- in stock -->").unwrap();
+    /// assert!(html.contains_text("stock"));
+    /// assert!(!html.contains_text("in stock"));
+    /// ```
+    #[must_use]
+    pub fn contains_text(&self, substr: &str) -> bool {
+        self.descendants()
+            .any(|node| matches!(node, Self::Text(text) if text.contains(substr)))
+    }
+
+    /// Returns an iterator over all the nodes of the tree, in document order
+    ///
+    /// [`Html::Vec`] and [`Html::Empty`] nodes are not yielded, only their
+    /// content.
+    pub(crate) fn descendants(&self) -> impl Iterator<Item = &Self> {
+        let mut stack = vec![self];
+        iter::from_fn(move || {
+            while let Some(node) = stack.pop() {
+                match node {
+                    Self::Empty => (),
+                    Self::Vec(vec) => stack.extend(vec.iter().rev()),
+                    Self::Tag { child, .. } => {
+                        stack.push(child);
+                        return Some(node);
+                    }
+                    Self::BogusComment(_)
+                    | Self::Comment(_)
+                    | Self::Conditional(_)
+                    | Self::Doctype { .. }
+                    | Self::Text(_) => return Some(node),
+                }
+            }
+            None
+        })
+    }
+
+    /// Finds the tags whose attribute `key` satisfies a predicate
+    ///
+    /// The predicate is called with the name and the value of the attribute,
+    /// the value being `None` for attributes without values, like `enabled`
+    /// in `<button enabled>`. Tags that don't have the attribute `key` are
+    /// skipped. The names are compared case-insensitively, except in `<svg>`
+    /// and `<math>`.
+    ///
+    /// # Returns
+    ///
+    /// The matching tags, in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(
+    ///     r#"<ul><li class="item active">A</li><li class="item">B</li><li class="active">C</li></ul>"#,
+    /// )
+    /// .unwrap();
+    /// let active = html.find_by_attr("class", |_, value| {
+    ///     value.is_some_and(|classes| classes.split_whitespace().any(|class| class == "active"))
+    /// });
+    /// assert!(active.len() == 2);
+    /// assert!(active[1].to_string() == r#"<li class="active">C</li>"#);
+    /// ```
+    #[must_use]
+    pub fn find_by_attr<F>(&self, key: &str, pred: F) -> Vec<&Self>
+    where
+        F: Fn(&str, Option<&str>) -> bool,
+    {
+        self.descendants()
+            .filter(|node| {
+                if let Self::Tag { tag, .. } = node {
+                    tag.as_attrs()
+                        .iter()
+                        .find(|attr| tag.as_namespace().eq_names(attr.as_name(), key))
+                        .is_some_and(|attr| {
+                            pred(attr.as_name(), attr.as_value().map(String::as_str))
+                        })
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    /// Pushes the text of the tree into `text`.
+    ///
+    /// See [`Html::text_content`].
+    fn push_text(&self, templates: bool, text: &mut String) {
+        match self {
+            Self::Text(content) => text.push_str(content),
+            Self::Tag { tag, child } =>
+                if templates
+                    || tag.as_namespace().is_foreign()
+                    || !tag.as_name().eq_ignore_ascii_case("template")
+                {
+                    child.push_text(templates, text);
+                },
+            Self::Vec(vec) =>
+                for node in vec {
+                    node.push_text(templates, text);
+                },
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty => (),
+        }
+    }
+
+    /// Returns the names of all the tags of the tree
+    ///
+    /// The names are yielded in document order, whatever their depth, with a
+    /// name for each tag, so the duplicates are kept. Collect them into a set
+    /// to find which elements are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<ul><li>a</li><li><b>b</b></li></ul><!-- c --><p>d</p>").unwrap();
+    /// assert!(html.tag_names().collect::<Vec<_>>() == ["ul", "li", "li", "b", "p"]);
+    /// ```
+    pub fn tag_names(&self) -> impl Iterator<Item = &str> {
+        self.descendants().filter_map(|node| match node {
+            Self::Tag { tag, .. } => Some(tag.as_name().as_str()),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_)
+            | Self::Vec(_) => None,
+        })
+    }
+
+    /// Returns the text of the tree, without the tags and the comments
+    ///
+    /// The text nodes are concatenated in document order. The content of a
+    /// `<template>` tag is parsed into the tree, but is an inert fragment that
+    /// isn't displayed by browsers: it is only included if `templates` is
+    /// `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>Hello <b>world</b><template><i>!</i></template></p>").unwrap();
+    /// assert!(html.text_content(false) == "Hello world");
+    /// assert!(html.text_content(true) == "Hello world!");
+    /// ```
+    #[must_use]
+    pub fn text_content(&self, templates: bool) -> String {
+        let mut text = String::new();
+        self.push_text(templates, &mut text);
+        text
+    }
+}
@@ -7,12 +7,18 @@
 //! For more information on how to define the filtering rules, please refer to
 //! [`Filter`].
 
-extern crate alloc;
 mod element;
+pub mod extract;
+mod lookup;
 mod node_type;
+mod replace;
+pub mod selector;
+mod strip;
 pub mod types;
 
-use alloc::borrow::Cow;
+use alloc::borrow::{Cow, ToOwned as _};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 
 use node_type::NodeTypeFilter;
@@ -100,7 +106,12 @@ impl Html {
     /// will be discarded if it is deeper in the tree.
     fn check_depth(&self, max_depth: usize, filter: &Filter) -> Option<usize> {
         match self {
-            Self::Empty | Self::Text(_) | Self::Comment { .. } | Self::Doctype { .. } => None,
+            Self::Empty
+            | Self::Text(_)
+            | Self::BogusComment(_)
+            | Self::Comment { .. }
+            | Self::Conditional(_)
+            | Self::Doctype { .. } => None,
             Self::Tag { tag, .. } if filter.tag_explicitly_allowed(tag) => Some(0),
             Self::Tag { .. } | Self::Vec(_) if max_depth == 0 => None,
             Self::Tag { child, .. } => child
@@ -212,13 +223,15 @@ impl Html {
 fn filter_aux(cow_html: Cow<'_, Html>, filter: &Filter, found: bool) -> FilterSuccess {
     use Html::*;
     match cow_html {
-        Cow::Borrowed(Comment(_)) | Cow::Owned(Comment(_))
+        Cow::Borrowed(BogusComment(_) | Comment(_) | Conditional(_))
+        | Cow::Owned(BogusComment(_) | Comment(_) | Conditional(_))
             if found || !filter.comment_explicitly_allowed() =>
             None,
         Cow::Borrowed(Doctype { .. }) | Cow::Owned(Doctype { .. })
             if found || !filter.doctype_allowed() =>
             None,
-        Cow::Borrowed(Doctype { .. } | Comment(_)) | Cow::Owned(Doctype { .. } | Comment(_)) =>
+        Cow::Borrowed(Doctype { .. } | BogusComment(_) | Comment(_) | Conditional(_))
+        | Cow::Owned(Doctype { .. } | BogusComment(_) | Comment(_) | Conditional(_)) =>
             FilterSuccess::make_none(cow_html),
         Cow::Borrowed(Text(_) | Empty) | Cow::Owned(Text(_) | Empty) => None,
         Cow::Borrowed(Tag { tag, child }) =>
@@ -337,7 +350,9 @@ fn filter_light(cow_html: Cow<'_, Html>, filter: &Filter) -> Html {
     match cow_html {
         Cow::Borrowed(Text(_)) | Cow::Owned(Text(_)) if filter.text_allowed() =>
             cow_html.into_owned(),
-        Cow::Borrowed(Comment(_)) | Cow::Owned(Comment(_)) if filter.comment_allowed() =>
+        Cow::Borrowed(BogusComment(_) | Comment(_) | Conditional(_))
+        | Cow::Owned(BogusComment(_) | Comment(_) | Conditional(_))
+            if filter.comment_allowed() =>
             cow_html.into_owned(),
         Cow::Borrowed(Doctype { .. }) | Cow::Owned(Doctype { .. }) if filter.doctype_allowed() =>
             cow_html.into_owned(),
@@ -359,8 +374,12 @@ fn filter_light(cow_html: Cow<'_, Html>, filter: &Filter) -> Html {
                 .map(|child| filter_light(Cow::Owned(child), filter))
                 .collect(),
         ),
-        Cow::Borrowed(Empty | Text(_) | Comment { .. } | Doctype { .. })
-        | Cow::Owned(Empty | Text(_) | Comment { .. } | Doctype { .. }) => Html::Empty,
+        Cow::Borrowed(
+            Empty | Text(_) | BogusComment(_) | Comment { .. } | Conditional(_) | Doctype { .. },
+        )
+        | Cow::Owned(
+            Empty | Text(_) | BogusComment(_) | Comment { .. } | Conditional(_) | Doctype { .. },
+        ) => Html::Empty,
     }
 }
 
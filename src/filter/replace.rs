@@ -0,0 +1,109 @@
+//! Module to edit the texts of an [`Html`] tree in place.
+
+use alloc::string::String;
+
+use crate::parse::{RAW_TEXT_ELEMENTS, WHITESPACE_SENSITIVE};
+use crate::prelude::Html;
+
+impl Html {
+    /// Collapses the runs of whitespace inside the texts of the tree
+    ///
+    /// Each run of ASCII whitespace in a text node is replaced by a single
+    /// space. Unlike [`Html::trim_whitespace_nodes`], no node is removed and
+    /// the texts aren't trimmed, so a whitespace-only text between two tags
+    /// becomes a single space. The content of whitespace-sensitive elements
+    /// (`<pre>`, `<textarea>`, `<script>` and `<style>`) is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<p>\n  a  b\n</p>\n<pre>x  y</pre>").unwrap();
+    /// html.collapse_text_whitespace();
+    /// assert!(html.to_string() == "<p> a b </p> <pre>x  y</pre>");
+    /// ```
+    pub fn collapse_text_whitespace(&mut self) {
+        match self {
+            Self::Text(text) => *text = collapse_whitespace(text),
+            Self::Tag { tag, child } =>
+                if !tag.is_one_of(&WHITESPACE_SENSITIVE) {
+                    child.collapse_text_whitespace();
+                },
+            Self::Vec(vec) => vec.iter_mut().for_each(Self::collapse_text_whitespace),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty => (),
+        }
+    }
+
+    /// Replaces all the occurrences of `from` by `to` in the texts of the tree
+    ///
+    /// Only the text nodes are edited: the attributes, comments and doctypes
+    /// are left untouched, as is the raw content of `<script>` and `<style>`.
+    /// An occurrence can't span several text nodes. Nothing is replaced if
+    /// `from` is empty.
+    ///
+    /// # Returns
+    ///
+    /// The number of replaced occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse(r#"<p title="name">Hello name, <b>name</b>!</p>"#).unwrap();
+    /// assert!(html.replace_text("name", "Alice") == 2);
+    /// assert!(html.to_string() == r#"<p title="name">Hello Alice, <b>Alice</b>!</p>"#);
+    /// ```
+    pub fn replace_text(&mut self, from: &str, to: &str) -> usize {
+        if from.is_empty() {
+            return 0;
+        }
+        match self {
+            Self::Text(text) => {
+                let count = text.matches(from).count();
+                if count > 0 {
+                    *text = text.replace(from, to);
+                }
+                count
+            }
+            Self::Tag { tag, child } =>
+                if tag.is_one_of(&RAW_TEXT_ELEMENTS) {
+                    0
+                } else {
+                    child.replace_text(from, to)
+                },
+            Self::Vec(vec) => vec
+                .iter_mut()
+                .map(|child| child.replace_text(from, to))
+                .fold(0, usize::saturating_add),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty => 0,
+        }
+    }
+}
+
+/// Replaces each run of ASCII whitespace of the text by a single space.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = false;
+    for ch in text.chars() {
+        if ch.is_ascii_whitespace() {
+            if !space {
+                collapsed.push(' ');
+            }
+            space = true;
+        } else {
+            collapsed.push(ch);
+            space = false;
+        }
+    }
+    collapsed
+}
@@ -0,0 +1,629 @@
+//! Module to find the nodes of an [`Html`] tree that match a CSS selector.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::error::Error;
+use core::iter::Peekable;
+use core::ops::ControlFlow;
+use core::str::CharIndices;
+use core::{fmt, mem};
+
+use crate::parse::tokenizer::{Lexeme, Tokenizer};
+use crate::parse::{VOID_ELEMENTS, is_text_only};
+use crate::prelude::Html;
+use crate::types::tag::{Namespace, TagLexeme};
+
+/// Characters of a selector, with their byte positions
+type SelectorChars<'input> = Peekable<CharIndices<'input>>;
+
+/// Relation between two compound selectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    /// `a > b`: `b` is a child of `a`
+    Child,
+    /// `a b`: `b` is a descendant of `a`
+    Descendant,
+}
+
+/// Selector that applies to a single tag, like `a.link[href]`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Compound {
+    /// Required attributes, with their values if specified
+    attrs: Vec<(String, Option<String>)>,
+    /// Required classes
+    classes: Vec<String>,
+    /// Required ids
+    ids: Vec<String>,
+    /// Name of the tag, if specified
+    name: Option<String>,
+}
+
+impl Compound {
+    /// Checks if a node satisfies the compound selector.
+    fn matches(&self, node: &Html) -> bool {
+        let Html::Tag { tag, .. } = node else {
+            return false;
+        };
+        let namespace = tag.as_namespace();
+        self.name
+            .as_ref()
+            .is_none_or(|name| namespace.eq_names(tag.as_name(), name))
+            && self
+                .ids
+                .iter()
+                .all(|id| tag.find_attr_value("id") == Some(id))
+            && self.classes.iter().all(|class| node.has_class(class))
+            && self.attrs.iter().all(|(name, value)| {
+                tag.as_attrs().iter().any(|attr| {
+                    namespace.eq_names(attr.as_name(), name)
+                        && value
+                            .as_ref()
+                            .is_none_or(|wanted| attr.as_value() == Some(wanted))
+                })
+            })
+    }
+
+    /// Parses a compound selector.
+    fn parse(chars: &mut SelectorChars<'_>) -> Result<Self, SelectorError> {
+        let mut compound = Self::default();
+        let mut empty = chars.next_if(|&(_, ch)| ch == '*').is_none();
+        if empty {
+            let name = parse_ident(chars);
+            if !name.is_empty() {
+                compound.name = Some(name);
+                empty = false;
+            }
+        }
+        while let Some(&(_, ch)) = chars.peek() {
+            match ch {
+                '#' => {
+                    chars.next();
+                    compound.ids.push(parse_required_ident(chars)?);
+                }
+                '.' => {
+                    chars.next();
+                    compound.classes.push(parse_required_ident(chars)?);
+                }
+                '[' => {
+                    chars.next();
+                    compound.attrs.push(parse_attribute(chars)?);
+                }
+                _ => break,
+            }
+            empty = false;
+        }
+        if empty {
+            Err(SelectorError::at(chars))
+        } else {
+            Ok(compound)
+        }
+    }
+}
+
+/// Compound selectors separated by combinators, like `ul > li a`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComplexSelector {
+    /// Compounds before the subject, from the closest to the furthest
+    ///
+    /// Each compound is stored with the combinator that follows it.
+    ancestors: Vec<(Combinator, Compound)>,
+    /// Compound that must match the selected node
+    subject: Compound,
+}
+
+impl ComplexSelector {
+    /// Checks if a node matches the selector, given its ancestors.
+    fn matches(&self, node: &Html, ancestors: &[&Html]) -> bool {
+        self.subject.matches(node) && matches_ancestors(&self.ancestors, ancestors)
+    }
+
+    /// Parses a complex selector, until the next `,` or the end.
+    fn parse(chars: &mut SelectorChars<'_>) -> Result<Self, SelectorError> {
+        skip_whitespace(chars);
+        let mut subject = Compound::parse(chars)?;
+        let mut ancestors = vec![];
+        loop {
+            let spaced = skip_whitespace(chars);
+            let combinator = match chars.peek() {
+                None | Some((_, ',')) => break,
+                Some((_, '>')) => {
+                    chars.next();
+                    skip_whitespace(chars);
+                    Combinator::Child
+                }
+                Some(_) if spaced => Combinator::Descendant,
+                Some(_) => return Err(SelectorError::at(chars)),
+            };
+            let next = Compound::parse(chars)?;
+            ancestors.push((combinator, mem::replace(&mut subject, next)));
+        }
+        ancestors.reverse();
+        Ok(Self { ancestors, subject })
+    }
+
+    /// Parses a compound selector without combinators, until the next `,` or
+    /// the end.
+    fn parse_compound(chars: &mut SelectorChars<'_>) -> Result<Self, SelectorError> {
+        skip_whitespace(chars);
+        let subject = Compound::parse(chars)?;
+        skip_whitespace(chars);
+        match chars.peek() {
+            None | Some((_, ',')) => Ok(Self { ancestors: vec![], subject }),
+            Some(_) => Err(SelectorError::at(chars)),
+        }
+    }
+}
+
+/// CSS selector, to find nodes in an [`Html`] tree
+///
+/// The supported syntax is:
+///
+/// - the type selector `p` and the universal selector `*`;
+/// - the id `#main`, class `.item` and attribute `[href]` or `[type="text"]`
+///   selectors;
+/// - the descendant `ul li` and child `ul > li` combinators;
+/// - selector lists, like `h1, h2`.
+///
+/// Tag and attribute names are case-insensitive for the HTML elements, but
+/// case-sensitive in the foreign content, like `foreignObject` or `[viewBox]`
+/// in `<svg>`. Ids, classes and attribute values are always case-sensitive.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// assert!(Selector::parse("nav > a.active, #logo").is_ok());
+/// assert!(Selector::parse("a >").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    /// Selectors of the list: a node matches if it matches any of them
+    list: Vec<ComplexSelector>,
+}
+
+impl Selector {
+    /// Checks if a node matches the selector, given its ancestors.
+    ///
+    /// The ancestors are ordered from the root to the parent of the node.
+    pub(crate) fn matches(&self, node: &Html, ancestors: &[&Html]) -> bool {
+        self.list
+            .iter()
+            .any(|complex| complex.matches(node, ancestors))
+    }
+
+    /// Parses a CSS selector
+    ///
+    /// See [`Selector`] for the supported syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorError`] if the selector is invalid or uses an
+    /// unsupported syntax.
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        Self::parse_list(selector, ComplexSelector::parse)
+    }
+
+    /// Parses a list of compound selectors, without combinators.
+    fn parse_compounds(selector: &str) -> Result<Self, SelectorError> {
+        Self::parse_list(selector, ComplexSelector::parse_compound)
+    }
+
+    /// Parses a list of selectors separated by `,`, each one being read by
+    /// `parse_one`.
+    fn parse_list(
+        selector: &str,
+        parse_one: fn(&mut SelectorChars<'_>) -> Result<ComplexSelector, SelectorError>,
+    ) -> Result<Self, SelectorError> {
+        let mut chars = selector.char_indices().peekable();
+        let mut list = vec![parse_one(&mut chars)?];
+        while chars.next().is_some() {
+            list.push(parse_one(&mut chars)?);
+        }
+        Ok(Self { list })
+    }
+}
+
+/// Errors returned when a [`Selector`] couldn't be parsed
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let err = Selector::parse("div + p").unwrap_err();
+/// assert!(err.to_string() == "Invalid character '+' at position 4 in selector.");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorError {
+    /// A character is not allowed at this place, or isn't supported
+    #[non_exhaustive]
+    InvalidCharacter {
+        /// Invalid character
+        character: char,
+        /// Byte offset of the character in the selector
+        position: usize,
+    },
+    /// The selector ended in the middle of a compound selector
+    ///
+    /// # Examples
+    ///
+    /// `ul >` and `a[href`
+    UnexpectedEnd,
+}
+
+impl SelectorError {
+    /// Returns the error for the next character of the selector.
+    fn at(chars: &mut SelectorChars<'_>) -> Self {
+        chars
+            .peek()
+            .map_or(Self::UnexpectedEnd, |&(position, character)| Self::InvalidCharacter {
+                character,
+                position,
+            })
+    }
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter { character, position } =>
+                write!(f, "Invalid character '{character}' at position {position} in selector."),
+            Self::UnexpectedEnd => f.write_str("Unexpected end of selector."),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Error for SelectorError {}
+
+impl Html {
+    /// Finds the first tag of an HTML string that matches a CSS selector,
+    /// without parsing the whole string
+    ///
+    /// The input is tokenized until the first matching tag, only keeping the
+    /// stack of the opened tags to match the combinators, and only the
+    /// subtree of that tag is parsed. This bounds the time and memory needed
+    /// to grab a single element, like the `<title>`, from a huge page. See
+    /// [`Selector`] for the supported syntax.
+    ///
+    /// The stack of opened tags only follows the closing tags of the input,
+    /// so the ancestors may differ from the ones of [`Html::parse`] when tags
+    /// are closed implicitly, like in `<p>a<p>b`.
+    ///
+    /// # Returns
+    ///
+    /// The subtree of the first matching tag, or `None` if no tag matches or
+    /// if the input is invalid before the end of that subtree.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorError`] if the selector is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let page = "<html><head><title>Home</title></head><body><p>text</body></html>";
+    /// let title = Html::find_first_streaming(page, "head > title").unwrap();
+    /// assert!(title.is_some_and(|node| node.to_string() == "<title>Home</title>"));
+    /// assert!(
+    ///     Html::find_first_streaming(page, "body > title")
+    ///         .unwrap()
+    ///         .is_none()
+    /// );
+    /// ```
+    pub fn find_first_streaming(
+        input: &str,
+        selector: &str,
+    ) -> Result<Option<Self>, SelectorError> {
+        let parsed = Selector::parse(selector)?;
+        let mut tokenizer = Tokenizer::new(input);
+        let mut ancestors: Vec<(Self, Namespace)> = vec![];
+        loop {
+            let namespace = ancestors
+                .last()
+                .map_or(Namespace::Html, |(_, namespace)| *namespace);
+            let lexeme = match tokenizer.lex(namespace) {
+                Some(Ok(Lexeme::Tag(lexeme))) => lexeme,
+                Some(Ok(
+                    Lexeme::BogusComment(_)
+                    | Lexeme::Comment { .. }
+                    | Lexeme::Conditional(_)
+                    | Lexeme::Text(_),
+                )) => continue,
+                Some(Err(_)) | None => return Ok(None),
+            };
+            let (mut tag, slash) = match lexeme {
+                TagLexeme::Open(tag) => (tag, false),
+                TagLexeme::OpenClose(tag) => (tag, true),
+                TagLexeme::Close(name) => {
+                    if let Some(idx) = ancestors.iter().rposition(|(opened, _)| {
+                        let Self::Tag { tag, .. } = opened else {
+                            return false;
+                        };
+                        tag.as_namespace().eq_names(tag.as_name(), &name)
+                    }) {
+                        ancestors.truncate(idx);
+                    }
+                    continue;
+                }
+                TagLexeme::Doctype { .. } => continue,
+                TagLexeme::OpenComment | TagLexeme::UnterminatedAttribute(_) => return Ok(None),
+            };
+            let is_void = VOID_ELEMENTS.contains(&tag.as_name().as_str())
+                || (slash && tag.as_namespace().is_foreign());
+            tag.decode_attr_values(&BTreeMap::new());
+            if !is_void && is_text_only(&tag) {
+                tokenizer.start_raw_text(tag.as_name());
+            }
+            let children_namespace = tag.children_namespace();
+            let node = Self::from(tag);
+            let parents = ancestors
+                .iter()
+                .map(|(parent, _)| parent)
+                .collect::<Vec<_>>();
+            if parsed.matches(&node, &parents) {
+                let (start, _) = tokenizer.span();
+                return Ok(Self::parse_prefix_in(
+                    input.get(start..).unwrap_or_default(),
+                    namespace,
+                )
+                .ok()
+                .and_then(|(subtree, _)| subtree.into_fragment().into_iter().next()));
+            }
+            if !is_void {
+                ancestors.push((node, children_namespace));
+            }
+        }
+    }
+
+    /// Checks if the node matches a compound selector
+    ///
+    /// This is the equivalent of `Element.matches` in the DOM, to filter the
+    /// nodes during a manual walk. As the ancestors of the node are unknown,
+    /// only compound selectors are supported, like `a.link[href]` or `#main`,
+    /// optionally in a list, like `h1, h2`. See [`Selector`] for their syntax.
+    ///
+    /// Only tags can match: the other nodes, including an [`Html::Vec`] of
+    /// tags, never do.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorError`] if the selector is invalid, or if it
+    /// contains a combinator, like `ul li` or `ul > li`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let link = Html::parse("<a class='nav' href='/'>Home</a>").unwrap();
+    /// assert!(link.matches("a.nav[href]").unwrap());
+    /// assert!(!link.matches("a#home").unwrap());
+    ///
+    /// let err = link.matches("nav > a").unwrap_err();
+    /// assert!(err.to_string() == "Invalid character '>' at position 4 in selector.");
+    /// ```
+    pub fn matches(&self, selector: &str) -> Result<bool, SelectorError> {
+        Ok(Selector::parse_compounds(selector)?.matches(self, &[]))
+    }
+
+    /// Clones the subtrees that match a CSS selector
+    ///
+    /// This is the owned version of [`Html::select`]: the results don't
+    /// borrow the tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorError`] if the selector is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let items = Html::parse("<ul><li class='item'>A</li><li>B</li></ul>")
+    ///     .unwrap()
+    ///     .query_selector_all(".item")
+    ///     .unwrap();
+    /// assert!(items == [Html::parse("<li class='item'>A</li>").unwrap()]);
+    /// ```
+    pub fn query_selector_all(&self, selector: &str) -> Result<Vec<Self>, SelectorError> {
+        Ok(self.select(selector)?.into_iter().cloned().collect())
+    }
+
+    /// Finds the tags that match a CSS selector
+    ///
+    /// See [`Selector`] for the supported syntax.
+    ///
+    /// # Returns
+    ///
+    /// The matching tags, in document order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorError`] if the selector is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<nav><a href='/'>Home</a></nav><p><a>Text</a></p>").unwrap();
+    /// let links = html.select("nav > a[href]").unwrap();
+    /// assert!(links.len() == 1);
+    /// assert!(links[0].to_string() == "<a href='/'>Home</a>");
+    /// ```
+    pub fn select(&self, selector: &str) -> Result<Vec<&Self>, SelectorError> {
+        let parsed = Selector::parse(selector)?;
+        let mut found = vec![];
+        let ControlFlow::Continue(()) = self.select_in(&parsed, &mut vec![], &mut |node| {
+            found.push(node);
+            ControlFlow::<Infallible>::Continue(())
+        });
+        Ok(found)
+    }
+
+    /// Calls `found` on the nodes of the tree that match the selector, in
+    /// document order.
+    ///
+    /// The walk stops as soon as `found` returns [`ControlFlow::Break`], and
+    /// the break value is returned.
+    fn select_in<'html, B, F>(
+        &'html self,
+        selector: &Selector,
+        ancestors: &mut Vec<&'html Self>,
+        found: &mut F,
+    ) -> ControlFlow<B>
+    where
+        F: FnMut(&'html Self) -> ControlFlow<B>,
+    {
+        match self {
+            Self::Tag { child, .. } => {
+                if selector.matches(self, ancestors) {
+                    found(self)?;
+                }
+                ancestors.push(self);
+                let flow = child.select_in(selector, ancestors, found);
+                ancestors.pop();
+                flow
+            }
+            Self::Vec(vec) => vec
+                .iter()
+                .try_for_each(|node| node.select_in(selector, ancestors, found)),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => ControlFlow::Continue(()),
+        }
+    }
+
+    /// Finds the first tag that matches a CSS selector
+    ///
+    /// This is the first result of [`Html::select`], but the walk of the tree
+    /// stops at the first match, so it is faster on large trees. See
+    /// [`Selector`] for the supported syntax.
+    ///
+    /// # Returns
+    ///
+    /// The first matching tag in document order, or `None` if no tag matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorError`] if the selector is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<ul><li>A</li><li class='x'>B</li><li class='x'>C</li></ul>").unwrap();
+    /// let first = html.select_one("li.x").unwrap();
+    /// assert!(first.is_some_and(|node| node.to_string() == "<li class='x'>B</li>"));
+    /// assert!(html.select_one("ol").unwrap().is_none());
+    /// ```
+    pub fn select_one(&self, selector: &str) -> Result<Option<&Self>, SelectorError> {
+        let parsed = Selector::parse(selector)?;
+        Ok(self
+            .select_in(&parsed, &mut vec![], &mut ControlFlow::Break)
+            .break_value())
+    }
+}
+
+/// Checks if the ancestors of a node match the compounds of a selector.
+///
+/// The compounds are ordered from the closest to the furthest, and the
+/// ancestors from the root to the parent of the node.
+fn matches_ancestors(compounds: &[(Combinator, Compound)], ancestors: &[&Html]) -> bool {
+    let Some(((combinator, compound), rest)) = compounds.split_first() else {
+        return true;
+    };
+    match combinator {
+        Combinator::Child => ancestors.split_last().is_some_and(|(parent, above)| {
+            compound.matches(parent) && matches_ancestors(rest, above)
+        }),
+        Combinator::Descendant => (0..ancestors.len()).rev().any(|idx| {
+            ancestors
+                .get(idx)
+                .is_some_and(|ancestor| compound.matches(ancestor))
+                && matches_ancestors(rest, ancestors.get(..idx).unwrap_or_default())
+        }),
+    }
+}
+
+/// Parses an attribute selector, after the opening `[`.
+fn parse_attribute(
+    chars: &mut SelectorChars<'_>,
+) -> Result<(String, Option<String>), SelectorError> {
+    skip_whitespace(chars);
+    let name = parse_required_ident(chars)?;
+    skip_whitespace(chars);
+    let value = if chars.next_if(|&(_, ch)| ch == '=').is_some() {
+        skip_whitespace(chars);
+        let value = if let Some((_, quote)) = chars.next_if(|&(_, ch)| ch == '"' || ch == '\'') {
+            let mut quoted = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, ch)) if ch == quote => break,
+                    Some((_, ch)) => quoted.push(ch),
+                    None => return Err(SelectorError::UnexpectedEnd),
+                }
+            }
+            quoted
+        } else {
+            parse_required_ident(chars)?
+        };
+        skip_whitespace(chars);
+        Some(value)
+    } else {
+        None
+    };
+    if chars.next_if(|&(_, ch)| ch == ']').is_some() {
+        Ok((name, value))
+    } else {
+        Err(SelectorError::at(chars))
+    }
+}
+
+/// Parses an identifier, like a tag name or a class.
+///
+/// The identifier may be empty.
+fn parse_ident(chars: &mut SelectorChars<'_>) -> String {
+    let mut ident = String::new();
+    while let Some((_, ch)) =
+        chars.next_if(|&(_, ch)| ch.is_alphanumeric() || ch == '-' || ch == '_')
+    {
+        ident.push(ch);
+    }
+    ident
+}
+
+/// Parses a non-empty identifier.
+fn parse_required_ident(chars: &mut SelectorChars<'_>) -> Result<String, SelectorError> {
+    let ident = parse_ident(chars);
+    if ident.is_empty() {
+        Err(SelectorError::at(chars))
+    } else {
+        Ok(ident)
+    }
+}
+
+/// Skips the whitespace and returns `true` if there was any.
+fn skip_whitespace(chars: &mut SelectorChars<'_>) -> bool {
+    let mut skipped = false;
+    while chars.next_if(|&(_, ch)| ch.is_whitespace()).is_some() {
+        skipped = true;
+    }
+    skipped
+}
@@ -0,0 +1,120 @@
+//! Module to remove nodes from an [`Html`] tree in place.
+
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::parse::WHITESPACE_SENSITIVE;
+use crate::prelude::Html;
+
+impl Html {
+    /// Keeps only the nodes that satisfy the predicate
+    ///
+    /// The predicate is called on every node of the tree, from the root to
+    /// the leaves. When a node is removed, its whole subtree is removed.
+    /// [`Html::Vec`] with less than 2 nodes after the removal are flattened.
+    pub(crate) fn retain<F>(&mut self, pred: &mut F)
+    where
+        F: FnMut(&Self) -> bool,
+    {
+        if !pred(self) {
+            *self = Self::Empty;
+            return;
+        }
+        match self {
+            Self::Tag { child, .. } => child.retain(pred),
+            Self::Vec(vec) => {
+                let kept = mem::take(vec)
+                    .into_vec()
+                    .into_iter()
+                    .filter_map(|mut node| {
+                        node.retain(pred);
+                        (!node.is_empty()).then_some(node)
+                    })
+                    .collect::<Vec<_>>();
+                *self = Self::from_nodes(kept);
+            }
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => (),
+        }
+    }
+
+    /// Removes all the comments of the tree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<p>a<!-- note -->b</p><!-- end -->").unwrap();
+    /// html.strip_comments();
+    /// assert!(html.to_string() == "<p>ab</p>");
+    /// ```
+    pub fn strip_comments(&mut self) {
+        self.retain(&mut |node| !matches!(node, Self::BogusComment(_) | Self::Comment(_)));
+    }
+
+    /// Removes all the tags with one of the given names, with their content
+    ///
+    /// The names are compared case-insensitively, except in `<svg>` and
+    /// `<math>`, so `<SCRIPT>` is removed by `"script"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<p>a<script>alert(1)</script>b<style></style></p>").unwrap();
+    /// html.strip_elements(&["script", "style"]);
+    /// assert!(html.to_string() == "<p>ab</p>");
+    /// ```
+    pub fn strip_elements(&mut self, names: &[&str]) {
+        self.retain(&mut |node| !matches!(node, Self::Tag { tag, .. } if tag.is_one_of(names)));
+    }
+
+    /// Removes the text nodes that only contain ASCII whitespace
+    ///
+    /// The other text nodes are left untouched, even if they start or end with
+    /// whitespace. The content of whitespace-sensitive elements (`<pre>`,
+    /// `<textarea>`, `<script>` and `<style>`) is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<ul>\n  <li> a </li>\n  <li><pre> </pre></li>\n</ul>").unwrap();
+    /// html.trim_whitespace_nodes();
+    /// assert!(html.to_string() == "<ul><li> a </li><li><pre> </pre></li></ul>");
+    /// ```
+    pub fn trim_whitespace_nodes(&mut self) {
+        match self {
+            Self::Text(text) if text.bytes().all(|byte| byte.is_ascii_whitespace()) =>
+                *self = Self::Empty,
+            Self::Tag { tag, child } =>
+                if !tag.is_one_of(&WHITESPACE_SENSITIVE) {
+                    child.trim_whitespace_nodes();
+                },
+            Self::Vec(vec) => {
+                let kept = mem::take(vec)
+                    .into_vec()
+                    .into_iter()
+                    .filter_map(|mut node| {
+                        node.trim_whitespace_nodes();
+                        (!node.is_empty()).then_some(node)
+                    })
+                    .collect::<Vec<_>>();
+                *self = Self::from_nodes(kept);
+            }
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => (),
+        }
+    }
+}
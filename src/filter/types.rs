@@ -1,5 +1,7 @@
 //! Module to define structs to filter
 
+use alloc::string::String;
+
 use super::NodeTypeFilter;
 use super::element::{BlackWhiteList, ValueAssociateHash};
 use crate::types::tag::Tag;
@@ -0,0 +1,537 @@
+//! Module to convert an [`Html`] tree to and from a JSON string.
+//!
+//! The JSON format is DOM-friendly:
+//!
+//! - a tag is an object `{"tag":"div","attrs":{"id":"x"},"children":[]}`, where
+//!   attributes without values are `null`;
+//! - a text is a JSON string;
+//! - a comment is an object `{"comment":"content"}`, and a bogus comment an
+//!   object `{"bogus_comment":"content"}`;
+//! - a conditional comment marker is an object `{"conditional":"lt IE 9"}`,
+//!   with a `null` condition for the closing `<![endif]-->`;
+//! - a doctype is an object `{"doctype":"DOCTYPE","attr":"html"}`, with a
+//!   `"subset"` string if it has an internal subset;
+//! - a list of nodes is an array (an empty tree is an empty array).
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::fmt::Write as _;
+use core::iter::Peekable;
+use core::str::Chars;
+
+use crate::errors::safe_expect;
+use crate::prelude::{Html, Namespace, Tag};
+use crate::types::tag::Attribute;
+
+/// Maximum number of nested JSON arrays and objects
+///
+/// This bounds the recursion depth of the JSON parser, so deeply nested inputs
+/// like `[[[[...` can't overflow the stack. A tag is an object containing an
+/// array, so this allows 256 nested tags.
+const MAX_DEPTH: usize = 512;
+
+/// Errors returned when a JSON string can't be converted to an [`Html`] tree
+///
+/// See [`Html::from_json`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonError {
+    /// A character was found where another was expected
+    #[non_exhaustive]
+    Expected {
+        /// What was expected, like `':' after object key`
+        expected: &'static str,
+    },
+    /// A value of an attribute is neither a string nor `null`
+    #[non_exhaustive]
+    InvalidAttributeValue {
+        /// Name of the attribute
+        name: String,
+    },
+    /// An escape sequence of a string isn't valid, like `\x`
+    InvalidEscape,
+    /// A `\u` escape sequence isn't followed by 4 hexadecimal digits
+    InvalidHexDigits,
+    /// A `\u` escape sequence doesn't encode a valid character
+    InvalidUnicode,
+    /// An object has none of the `tag`, `comment`, `bogus_comment`,
+    /// `conditional` and `doctype` keys
+    MissingNodeKind,
+    /// The arrays and objects are nested too deeply
+    #[non_exhaustive]
+    TooDeep {
+        /// Maximum number of nested arrays and objects
+        max: usize,
+    },
+    /// Some characters were found after the JSON value
+    TrailingCharacters,
+    /// The input ended in the middle of a value
+    UnexpectedEnd,
+    /// An object has a key that isn't used to represent an [`Html`] node
+    #[non_exhaustive]
+    UnexpectedKey {
+        /// Name of the key
+        key: String,
+    },
+    /// A `null` was found where a node was expected
+    UnexpectedNull,
+    /// A high surrogate escape isn't followed by a low surrogate escape
+    UnpairedSurrogate,
+    /// A value starts with a character that doesn't start any supported value
+    ///
+    /// The numbers and booleans aren't supported, as they can't represent an
+    /// [`Html`] node.
+    #[non_exhaustive]
+    UnsupportedValue {
+        /// First character of the value
+        character: char,
+    },
+    /// A string isn't terminated by a double quote
+    UnterminatedString,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Expected { expected } => write!(f, "Invalid JSON: expected {expected}."),
+            Self::InvalidAttributeValue { name } => write!(
+                f,
+                "Invalid HTML JSON: value of attribute '{name}' must be a string or null."
+            ),
+            Self::InvalidEscape => f.write_str("Invalid JSON: invalid escape sequence."),
+            Self::InvalidHexDigits => f.write_str("Invalid JSON: expected 4 hexadecimal digits."),
+            Self::InvalidUnicode => f.write_str("Invalid JSON: invalid unicode escape."),
+            Self::MissingNodeKind => f.write_str(
+                "Invalid HTML JSON: object must have a 'tag', 'comment', 'bogus_comment', \
+                 'conditional' or 'doctype' key.",
+            ),
+            Self::TooDeep { max } =>
+                write!(f, "Invalid JSON: more than {max} nested arrays and objects."),
+            Self::TrailingCharacters =>
+                f.write_str("Invalid JSON: trailing characters after value."),
+            Self::UnexpectedEnd => f.write_str("Invalid JSON: unexpected end of input."),
+            Self::UnexpectedKey { key } => write!(f, "Invalid HTML JSON: unexpected key '{key}'."),
+            Self::UnexpectedNull => f.write_str("Invalid HTML JSON: unexpected null node."),
+            Self::UnpairedSurrogate =>
+                f.write_str("Invalid JSON: unpaired surrogate in escape sequence."),
+            Self::UnsupportedValue { character } =>
+                write!(f, "Invalid JSON: unsupported value starting with '{character}'."),
+            Self::UnterminatedString => f.write_str("Invalid JSON: unterminated string."),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Error for JsonError {}
+
+/// Parsed JSON value
+///
+/// Only the values needed to represent an [`Html`] tree are supported.
+enum JsonValue {
+    /// Array of values
+    Array(Vec<Self>),
+    /// `null` value
+    Null,
+    /// Object, with its keys in order of appearance
+    Object(Vec<(String, Self)>),
+    /// String value
+    String(String),
+}
+
+impl JsonValue {
+    /// Converts a JSON value into an [`Html`] node.
+    ///
+    /// The `namespace` is the namespace of the parent tag.
+    fn into_html(self, namespace: Namespace) -> Result<Html, JsonError> {
+        match self {
+            Self::Null => Err(JsonError::UnexpectedNull),
+            Self::String(text) => Ok(Html::Text(text)),
+            Self::Array(values) => {
+                let mut nodes = Vec::with_capacity(values.len());
+                for value in values {
+                    nodes.push(value.into_html(namespace)?);
+                }
+                Ok(if nodes.len() <= 1 {
+                    nodes.pop().unwrap_or_default()
+                } else {
+                    Html::Vec(nodes.into_boxed_slice())
+                })
+            }
+            Self::Object(fields) => object_into_html(fields, namespace),
+        }
+    }
+
+    /// Parses a JSON value from a stream of chars.
+    ///
+    /// The `depth` is the number of arrays and objects the value is nested
+    /// in. See [`MAX_DEPTH`].
+    fn parse(chars: &mut Peekable<Chars<'_>>, depth: usize) -> Result<Self, JsonError> {
+        skip_whitespace(chars);
+        if depth >= MAX_DEPTH && matches!(chars.peek(), Some('[' | '{')) {
+            return Err(JsonError::TooDeep { max: MAX_DEPTH });
+        }
+        let inner = depth.saturating_add(1);
+        match chars.next() {
+            Some('"') => parse_string(chars).map(Self::String),
+            Some('[') => {
+                let mut values = vec![];
+                skip_whitespace(chars);
+                if chars.next_if_eq(&']').is_some() {
+                    return Ok(Self::Array(values));
+                }
+                loop {
+                    values.push(Self::parse(chars, inner)?);
+                    skip_whitespace(chars);
+                    match chars.next() {
+                        Some(',') => (),
+                        Some(']') => return Ok(Self::Array(values)),
+                        _ => return Err(JsonError::Expected { expected: "',' or ']' in array" }),
+                    }
+                }
+            }
+            Some('{') => {
+                let mut fields = vec![];
+                skip_whitespace(chars);
+                if chars.next_if_eq(&'}').is_some() {
+                    return Ok(Self::Object(fields));
+                }
+                loop {
+                    skip_whitespace(chars);
+                    if chars.next() != Some('"') {
+                        return Err(JsonError::Expected { expected: "string key in object" });
+                    }
+                    let key = parse_string(chars)?;
+                    skip_whitespace(chars);
+                    if chars.next() != Some(':') {
+                        return Err(JsonError::Expected { expected: "':' after object key" });
+                    }
+                    fields.push((key, Self::parse(chars, inner)?));
+                    skip_whitespace(chars);
+                    match chars.next() {
+                        Some(',') => (),
+                        Some('}') => return Ok(Self::Object(fields)),
+                        _ => return Err(JsonError::Expected { expected: "',' or '}' in object" }),
+                    }
+                }
+            }
+            Some('n') =>
+                if chars.next() == Some('u')
+                    && chars.next() == Some('l')
+                    && chars.next() == Some('l')
+                {
+                    Ok(Self::Null)
+                } else {
+                    Err(JsonError::Expected { expected: "'null'" })
+                },
+            Some(character) => Err(JsonError::UnsupportedValue { character }),
+            None => Err(JsonError::UnexpectedEnd),
+        }
+    }
+}
+
+impl Html {
+    /// Parses a JSON string into an [`Html`] tree.
+    ///
+    /// This is the inverse of [`Html::to_json`]: refer to its documentation
+    /// for the expected format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid JSON, if it doesn't
+    /// represent an [`Html`] tree, or if its arrays and objects are nested
+    /// more than 512 times. See [`JsonError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let json = r#"[{"comment":" nav "},{"tag":"a","attrs":{"href":"/"},"children":["Home"]}]"#;
+    /// let html = Html::from_json(json).unwrap();
+    /// assert!(html.to_string() == r#"<!-- nav --><a href="/">Home</a>"#);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, JsonError> {
+        let mut chars = json.chars().peekable();
+        let value = JsonValue::parse(&mut chars, 0)?;
+        skip_whitespace(&mut chars);
+        if chars.next().is_some() {
+            return Err(JsonError::TrailingCharacters);
+        }
+        value.into_html(Namespace::Html)
+    }
+
+    /// Converts an [`Html`] tree to a JSON string.
+    ///
+    /// The output is a DOM-friendly representation of the tree:
+    ///
+    /// - a tag is an object `{"tag":"div","attrs":{"id":"x"},"children":[]}`,
+    ///   where attributes without values are `null`;
+    /// - a text is a JSON string;
+    /// - a comment is an object `{"comment":"content"}`, and a bogus comment an
+    ///   object `{"bogus_comment":"content"}`;
+    /// - a conditional comment marker is an object `{"conditional":"lt IE 9"}`,
+    ///   with a `null` condition for the closing `<![endif]-->`;
+    /// - a doctype is an object `{"doctype":"DOCTYPE","attr":"html"}`, with a
+    ///   `"subset"` string if it has an internal subset;
+    /// - a list of nodes is an array (an empty tree is an empty array).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<div id="main" hidden>Hello</div>"#).unwrap();
+    /// assert!(
+    ///     html.to_json()
+    ///         == r#"{"tag":"div","attrs":{"id":"main","hidden":null},"children":["Hello"]}"#
+    /// );
+    /// assert!(Html::from_json(&html.to_json()).unwrap().to_json() == html.to_json());
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    /// Writes the JSON representation of an [`Html`] tree.
+    ///
+    /// Refer to [`Html::to_json`] for more information.
+    fn write_json(&self, json: &mut String) {
+        match self {
+            Self::BogusComment(content) => {
+                json.push_str(r#"{"bogus_comment":"#);
+                write_json_string(content, json);
+                json.push('}');
+            }
+            Self::Comment(content) => {
+                json.push_str(r#"{"comment":"#);
+                write_json_string(content, json);
+                json.push('}');
+            }
+            Self::Conditional(condition) => {
+                json.push_str(r#"{"conditional":"#);
+                write_json_option(condition.as_ref(), json);
+                json.push('}');
+            }
+            Self::Doctype { name, attr, subset, .. } => {
+                json.push_str(r#"{"doctype":"#);
+                write_json_string(name, json);
+                json.push_str(r#","attr":"#);
+                write_json_option(attr.as_ref(), json);
+                if let Some(subset_str) = subset {
+                    json.push_str(r#","subset":"#);
+                    write_json_string(subset_str, json);
+                }
+                json.push('}');
+            }
+            Self::Empty => json.push_str("[]"),
+            Self::Tag { tag, child } => {
+                json.push_str(r#"{"tag":"#);
+                write_json_string(tag.as_name(), json);
+                json.push_str(r#","attrs":{"#);
+                for (idx, attr) in tag.as_attrs().iter().enumerate() {
+                    if idx != 0 {
+                        json.push(',');
+                    }
+                    write_json_string(attr.as_name(), json);
+                    json.push(':');
+                    write_json_option(attr.as_value(), json);
+                }
+                json.push_str(r#"},"children":"#);
+                child.write_json_children(json);
+                json.push('}');
+            }
+            Self::Text(text) => write_json_string(text, json),
+            Self::Vec(vec) => {
+                json.push('[');
+                for (idx, node) in vec.iter().enumerate() {
+                    if idx != 0 {
+                        json.push(',');
+                    }
+                    node.write_json(json);
+                }
+                json.push(']');
+            }
+        }
+    }
+
+    /// Writes the JSON representation of the children of a tag.
+    fn write_json_children(&self, json: &mut String) {
+        match self {
+            Self::Empty | Self::Vec(_) => self.write_json(json),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Tag { .. }
+            | Self::Text(_) => {
+                json.push('[');
+                self.write_json(json);
+                json.push(']');
+            }
+        }
+    }
+}
+
+/// Converts a JSON object into an [`Html`] node.
+///
+/// The object can be a tag, a comment, a conditional comment marker or a
+/// doctype.
+fn object_into_html(
+    fields: Vec<(String, JsonValue)>,
+    namespace: Namespace,
+) -> Result<Html, JsonError> {
+    let mut kind = None;
+    let mut attr = None;
+    let mut subset = None;
+    let mut attrs = vec![];
+    let mut children = JsonValue::Array(vec![]);
+    for (key, field) in fields {
+        match (key.as_str(), field) {
+            (
+                "tag" | "bogus_comment" | "comment" | "conditional" | "doctype",
+                JsonValue::String(name),
+            ) if kind.is_none() => kind = Some((key, Some(name))),
+            ("conditional", JsonValue::Null) if kind.is_none() => kind = Some((key, None)),
+            ("attr", JsonValue::String(attr_value)) => attr = Some(attr_value),
+            ("attr", JsonValue::Null) => attr = None,
+            ("subset", JsonValue::String(subset_value)) => subset = Some(subset_value),
+            ("attrs", JsonValue::Object(values)) =>
+                for (name, attr_value) in values {
+                    attrs.push(match attr_value {
+                        JsonValue::Null => Attribute::NameNoValue(name),
+                        JsonValue::String(value) => Attribute::NameValue {
+                            double_quote: !value.contains('"'),
+                            name,
+                            value,
+                        },
+                        JsonValue::Array(_) | JsonValue::Object(_) =>
+                            return Err(JsonError::InvalidAttributeValue { name }),
+                    });
+                },
+            ("children", array @ JsonValue::Array(_)) => children = array,
+            _ => return Err(JsonError::UnexpectedKey { key }),
+        }
+    }
+    match kind {
+        Some((key, Some(name))) if key == "tag" => {
+            let tag_namespace = Namespace::from_parent(&name, namespace);
+            let tag = Tag::from((name, attrs.into_boxed_slice(), tag_namespace));
+            let child = children.into_html(tag.children_namespace())?;
+            Ok(Html::Tag { tag, child: Box::new(child) })
+        }
+        Some((key, Some(content))) if key == "bogus_comment" => Ok(Html::BogusComment(content)),
+        Some((key, Some(content))) if key == "comment" => Ok(Html::Comment(content)),
+        Some((key, condition)) if key == "conditional" => Ok(Html::Conditional(condition)),
+        Some((_, Some(name))) => Ok(Html::Doctype { name, attr, subset, source: None }),
+        Some((_, None)) | None => Err(JsonError::MissingNodeKind),
+    }
+}
+
+/// Parses 4 hexadecimal digits.
+fn parse_hex4(chars: &mut Peekable<Chars<'_>>) -> Result<u32, JsonError> {
+    let mut code: u32 = 0;
+    let mut count: u32 = 0;
+    for ch in chars.by_ref().take(4) {
+        let digit = ch.to_digit(16).ok_or(JsonError::InvalidHexDigits)?;
+        #[expect(clippy::arithmetic_side_effects, reason = "at most 0xFFFF")]
+        {
+            code = code * 16 + digit;
+            count += 1;
+        }
+    }
+    if count != 4 {
+        return Err(JsonError::InvalidHexDigits);
+    }
+    Ok(code)
+}
+
+/// Parses a JSON string, after its opening double quote.
+fn parse_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, JsonError> {
+    let mut string = String::new();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => return Ok(string),
+            '\\' => string.push(match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('b') => '\u{8}',
+                Some('f') => '\u{c}',
+                Some('u') => parse_unicode_escape(chars)?,
+                Some(escaped @ ('"' | '\\' | '/')) => escaped,
+                _ => return Err(JsonError::InvalidEscape),
+            }),
+            _ => string.push(ch),
+        }
+    }
+    Err(JsonError::UnterminatedString)
+}
+
+/// Parses the 4 hexadecimal digits of a `\u` escape sequence.
+///
+/// If the code is a high surrogate, the following `\u` escape sequence is
+/// also read to build the character.
+fn parse_unicode_escape(chars: &mut Peekable<Chars<'_>>) -> Result<char, JsonError> {
+    let high = parse_hex4(chars)?;
+    let code = if (0xD800..0xDC00).contains(&high) {
+        if chars.next() != Some('\\') || chars.next() != Some('u') {
+            return Err(JsonError::UnpairedSurrogate);
+        }
+        let low = parse_hex4(chars)?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(JsonError::UnpairedSurrogate);
+        }
+        #[expect(clippy::arithmetic_side_effects, reason = "checked by ranges")]
+        {
+            0x10000 + (high - 0xD800) * 0x400 + (low - 0xDC00)
+        }
+    } else {
+        high
+    };
+    char::from_u32(code).ok_or(JsonError::InvalidUnicode)
+}
+
+/// Skips the JSON whitespace characters.
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars
+        .next_if(|ch| matches!(ch, ' ' | '\n' | '\r' | '\t'))
+        .is_some()
+    {}
+}
+
+/// Writes an optional string as a JSON string or `null`.
+fn write_json_option(option: Option<&String>, json: &mut String) {
+    match option {
+        Some(value) => write_json_string(value, json),
+        None => json.push_str("null"),
+    }
+}
+
+/// Writes a string as a JSON string, with escaped characters.
+fn write_json_string(string: &str, json: &mut String) {
+    json.push('"');
+    for ch in string.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            _ if ch.is_control() => safe_expect!(
+                write!(json, "\\u{:04x}", u32::from(ch)),
+                "Writing to a string never fails."
+            ),
+            _ => json.push(ch),
+        }
+    }
+    json.push('"');
+}
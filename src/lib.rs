@@ -26,7 +26,6 @@
 #![expect(
     clippy::implicit_return,
     clippy::question_mark_used,
-    clippy::else_if_without_else,
     clippy::module_name_repetitions,
     reason = "bad lint"
 )]
@@ -47,12 +46,18 @@
 #![expect(clippy::multiple_inherent_impl, reason = "useful when lots of methods")]
 #![feature(coverage_attribute)]
 #![feature(trivial_bounds)]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 // All modules are private to prevent a breaking change after refactoring this
 // crate's structure.
 
 mod errors;
 mod filter;
+mod json;
 mod parse;
 pub mod prelude;
 mod types;
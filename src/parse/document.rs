@@ -0,0 +1,147 @@
+//! Module to build the `<html>`, `<head>` and `<body>` structure of a
+//! document.
+//!
+//! See [`ParseOptions::document_mode`](super::options::ParseOptions::document_mode).
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::prelude::{Html, Tag};
+
+/// Elements that belong to the `<head>` when they precede the content
+const METADATA_ELEMENTS: [&str; 10] = [
+    "base", "basefont", "bgsound", "link", "meta", "noframes", "script", "style", "template",
+    "title",
+];
+
+/// Part of the document in which the next nodes are inserted
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Before the `<html>` tag: only the doctype, comments and whitespace
+    #[default]
+    Before,
+    /// In the `<body>`, after the first content node
+    Body,
+    /// In the `<head>`, before the first content node
+    Head,
+}
+
+/// Document being built from the nodes of a parsed tree
+#[derive(Debug, Default)]
+struct Document {
+    /// Nodes before the `<html>` tag, like the doctype
+    before: Vec<Html>,
+    /// Children of the `<body>` tag
+    body: Vec<Html>,
+    /// `<body>` tag read in the input, if any
+    body_tag: Option<Tag>,
+    /// Children of the `<head>` tag
+    head: Vec<Html>,
+    /// `<head>` tag read in the input, if any
+    head_tag: Option<Tag>,
+    /// `<html>` tag read in the input, if any
+    html_tag: Option<Tag>,
+    /// Part of the document in which the next nodes are inserted
+    phase: Phase,
+}
+
+impl Document {
+    /// Builds the tree of the document.
+    ///
+    /// The missing `<html>`, `<head>` and `<body>` tags are created.
+    fn into_html(self) -> Html {
+        let head = Html::Tag {
+            tag: self
+                .head_tag
+                .unwrap_or_else(|| Tag::builder("head").build()),
+            child: Box::new(Html::from_nodes(self.head)),
+        };
+        let body = Html::Tag {
+            tag: self
+                .body_tag
+                .unwrap_or_else(|| Tag::builder("body").build()),
+            child: Box::new(Html::from_nodes(self.body)),
+        };
+        let mut nodes = self.before;
+        nodes.push(Html::Tag {
+            tag: self
+                .html_tag
+                .unwrap_or_else(|| Tag::builder("html").build()),
+            child: Box::new(Html::Vec(Box::new([head, body]))),
+        });
+        Html::from_nodes(nodes)
+    }
+
+    /// Inserts a node in the part of the document where it belongs.
+    fn push(&mut self, node: Html) {
+        match node {
+            Html::Empty => (),
+            Html::Vec(vec) => vec
+                .into_vec()
+                .into_iter()
+                .for_each(|child| self.push(child)),
+            Html::Tag { tag, child } if tag.as_name() == "html" => {
+                self.html_tag.get_or_insert(tag);
+                if self.phase == Phase::Before {
+                    self.phase = Phase::Head;
+                }
+                self.push(*child);
+            }
+            Html::Tag { tag, child } if tag.as_name() == "head" && self.phase != Phase::Body => {
+                self.head_tag.get_or_insert(tag);
+                self.phase = Phase::Head;
+                self.push(*child);
+            }
+            Html::Tag { tag, child } if tag.as_name() == "body" => {
+                self.body_tag.get_or_insert(tag);
+                self.phase = Phase::Body;
+                self.push(*child);
+            }
+            Html::BogusComment(_)
+            | Html::Comment(_)
+            | Html::Conditional(_)
+            | Html::Doctype { .. }
+                if self.phase == Phase::Before =>
+                self.before.push(node),
+            Html::Text(text)
+                if self.phase != Phase::Body
+                    && text.bytes().all(|byte| byte.is_ascii_whitespace()) =>
+                if self.phase == Phase::Before {
+                    self.before.push(Html::Text(text));
+                } else {
+                    self.head.push(Html::Text(text));
+                },
+            Html::BogusComment(_) | Html::Comment(_) | Html::Conditional(_)
+                if self.phase == Phase::Head =>
+                self.head.push(node),
+            Html::Tag { tag, child }
+                if self.phase != Phase::Body
+                    && METADATA_ELEMENTS.contains(&tag.as_name().as_str()) =>
+            {
+                self.phase = Phase::Head;
+                self.head.push(Html::Tag { tag, child });
+            }
+            Html::BogusComment(_)
+            | Html::Comment(_)
+            | Html::Conditional(_)
+            | Html::Doctype { .. }
+            | Html::Tag { .. }
+            | Html::Text(_) => {
+                self.phase = Phase::Body;
+                self.body.push(node);
+            }
+        }
+    }
+}
+
+impl Html {
+    /// Moves the nodes of a parsed tree into the `<html>`, `<head>` and
+    /// `<body>` structure of a document.
+    ///
+    /// See [`ParseOptions::document_mode`](super::options::ParseOptions::document_mode).
+    pub(crate) fn into_document(self) -> Self {
+        let mut document = Document::default();
+        document.push(self);
+        document.into_html()
+    }
+}
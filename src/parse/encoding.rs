@@ -0,0 +1,206 @@
+//! Module to decode HTML byte strings before parsing them.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str;
+
+use super::error::ParseError;
+
+/// Number of bytes in which the `<meta charset>` declaration is looked for
+#[cfg(feature = "charset")]
+const SNIFF_LEN: usize = 1024;
+
+/// Character encoding of an HTML byte string
+///
+/// See [`Html::parse_bytes`](crate::prelude::Html::parse_bytes) and
+/// [`Html::parse_bytes_with_encoding`](crate::prelude::Html::parse_bytes_with_encoding).
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// assert!(Encoding::detect(b"\xEF\xBB\xBF<p>") == Encoding::Utf8);
+/// assert!(Encoding::detect(b"\xFF\xFE<\0p\0>\0") == Encoding::Utf16Le);
+/// assert!(Encoding::Latin1.decode(b"caf\xE9").unwrap() == "café");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// ISO-8859-1, where every byte is a character
+    Latin1,
+    /// UTF-16, big endian
+    Utf16Be,
+    /// UTF-16, little endian
+    Utf16Le,
+    /// UTF-8
+    Utf8,
+}
+
+impl Encoding {
+    /// Returns the name of the encoding, for the error messages.
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Latin1 => "ISO-8859-1",
+            Self::Utf16Be => "UTF-16BE",
+            Self::Utf16Le => "UTF-16LE",
+            Self::Utf8 => "UTF-8",
+        }
+    }
+
+    /// Returns the byte order mark of the encoding, if it has one.
+    const fn bom(self) -> &'static [u8] {
+        match self {
+            Self::Latin1 => &[],
+            Self::Utf16Be => &[0xFE, 0xFF],
+            Self::Utf16Le => &[0xFF, 0xFE],
+            Self::Utf8 => &[0xEF, 0xBB, 0xBF],
+        }
+    }
+
+    /// Decodes a byte string into a [`String`]
+    ///
+    /// The byte order mark of the encoding is removed, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidEncoding`] if the bytes aren't valid in
+    /// this encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Encoding::Utf16Be.decode(b"\xFE\xFF\0a").unwrap() == "a");
+    ///
+    /// let err = Encoding::Utf8.decode(b"ab\xFFc").unwrap_err();
+    /// assert!(err.to_string() == "Invalid UTF-8 sequence at byte 2.");
+    /// ```
+    pub fn decode(self, bytes: &[u8]) -> Result<String, ParseError> {
+        let bom = self.bom();
+        let content = bytes.strip_prefix(bom).unwrap_or(bytes);
+        let bom_len = bytes.len().saturating_sub(content.len());
+        let invalid = |offset: usize| ParseError::InvalidEncoding {
+            encoding: self.as_str(),
+            offset: offset.saturating_add(bom_len),
+        };
+        match self {
+            Self::Latin1 => Ok(content.iter().copied().map(char::from).collect()),
+            Self::Utf16Be => decode_utf16(content, u16::from_be_bytes).map_err(invalid),
+            Self::Utf16Le => decode_utf16(content, u16::from_le_bytes).map_err(invalid),
+            Self::Utf8 => str::from_utf8(content)
+                .map(String::from)
+                .map_err(|err| invalid(err.valid_up_to())),
+        }
+    }
+
+    /// Detects the encoding of an HTML byte string
+    ///
+    /// The encoding is given by the byte order mark, if there is one. With
+    /// the `charset` feature, a `<meta charset>` declaration at the start of
+    /// the document is then honoured. Otherwise, the input is assumed to be
+    /// UTF-8.
+    #[must_use]
+    pub fn detect(bytes: &[u8]) -> Self {
+        [Self::Utf8, Self::Utf16Le, Self::Utf16Be]
+            .into_iter()
+            .find(|encoding| bytes.starts_with(encoding.bom()))
+            .or_else(|| sniff_meta(bytes))
+            .unwrap_or(Self::Utf8)
+    }
+
+    /// Returns the encoding with the given label, like `utf-8` or `latin1`.
+    ///
+    /// The label is case-insensitive.
+    #[cfg(feature = "charset")]
+    fn from_label(label: &[u8]) -> Option<Self> {
+        match label.to_ascii_lowercase().as_slice() {
+            b"iso-8859-1" | b"iso8859-1" | b"iso_8859-1" | b"latin1" | b"l1" => Some(Self::Latin1),
+            b"utf-16be" => Some(Self::Utf16Be),
+            b"utf-16" | b"utf-16le" => Some(Self::Utf16Le),
+            b"utf-8" | b"utf8" | b"unicode-1-1-utf-8" => Some(Self::Utf8),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes UTF-16 bytes, with the given function to read a code unit.
+///
+/// # Errors
+///
+/// Returns the offset of the first invalid code unit.
+fn decode_utf16(bytes: &[u8], read: fn([u8; 2]) -> u16) -> Result<String, usize> {
+    let mut units = Vec::with_capacity(bytes.len().div_euclid(2));
+    let mut chunks = bytes.chunks_exact(2);
+    for chunk in &mut chunks {
+        if let [first, second] = *chunk {
+            units.push(read([first, second]));
+        }
+    }
+    let mut decoded = String::with_capacity(units.len());
+    let mut offset: usize = 0;
+    for ch in char::decode_utf16(units) {
+        let valid = ch.map_err(|_err| offset)?;
+        decoded.push(valid);
+        offset = offset.saturating_add(valid.len_utf16().saturating_mul(2));
+    }
+    if chunks.remainder().is_empty() {
+        Ok(decoded)
+    } else {
+        Err(offset)
+    }
+}
+
+/// Finds the first occurrence of `needle` in `haystack`.
+#[cfg(feature = "charset")]
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Looks for a `<meta charset>` declaration at the start of the input.
+///
+/// Both `<meta charset="...">` and
+/// `<meta http-equiv="Content-Type" content="text/html; charset=...">` are
+/// supported.
+#[cfg(feature = "charset")]
+fn sniff_meta(bytes: &[u8]) -> Option<Encoding> {
+    let head = bytes.get(..SNIFF_LEN).unwrap_or(bytes).to_ascii_lowercase();
+    let mut rest = head.as_slice();
+    while let Some(start) = find(rest, b"<meta") {
+        let after = rest.get(start..).unwrap_or_default();
+        let end = after
+            .iter()
+            .position(|byte| *byte == b'>')
+            .unwrap_or(after.len());
+        let tag = after.get(..end).unwrap_or_default();
+        if let Some(idx) = find(tag, b"charset=") {
+            let after_eq = tag
+                .get(idx.saturating_add(b"charset=".len())..)
+                .unwrap_or_default();
+            let value = after_eq
+                .strip_prefix(b"\"")
+                .or_else(|| after_eq.strip_prefix(b"'"))
+                .unwrap_or(after_eq);
+            let len = value
+                .iter()
+                .position(|byte| matches!(byte, b'"' | b'\'' | b';' | b'/' | b' '))
+                .unwrap_or(value.len());
+            if let Some(encoding) = Encoding::from_label(value.get(..len).unwrap_or_default()) {
+                return Some(encoding);
+            }
+        }
+        rest = rest.get(start.saturating_add(1)..).unwrap_or_default();
+    }
+    None
+}
+
+/// Looks for a `<meta charset>` declaration at the start of the input.
+///
+/// This requires the `charset` feature.
+#[cfg(not(feature = "charset"))]
+const fn sniff_meta(_bytes: &[u8]) -> Option<Encoding> {
+    None
+}
@@ -0,0 +1,144 @@
+//! Module to decode the character references of texts and attribute values.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Named character references supported by the parser
+///
+/// Only the references of the characters that need to be escaped in HTML are
+/// supported, with a few common symbols. The boolean indicates a legacy
+/// reference, that is also recognised without its semicolon, like `&copy`.
+const NAMED_ENTITIES: [(&str, char, bool); 10] = [
+    ("amp", '&', true),
+    ("apos", '\'', false),
+    ("copy", '\u{a9}', true),
+    ("gt", '>', true),
+    ("lt", '<', true),
+    ("nbsp", '\u{a0}', true),
+    ("not", '\u{ac}', true),
+    ("notin", '\u{2209}', false),
+    ("quot", '"', true),
+    ("reg", '\u{ae}', true),
+];
+
+/// Value of a valid character reference
+enum Reference<'custom> {
+    /// Character of a numeric or a standard named reference
+    Char(char),
+    /// Replacement text of a custom named reference
+    Custom(&'custom str),
+}
+
+/// Decodes the character references of a string, like `&amp;` or `&#x41;`.
+///
+/// The references must end with a semicolon, except the legacy named ones,
+/// like `&copy`. Without their semicolon, these are read as the longest legacy
+/// name, so `&notit;` is `¬it;`. In an `attribute` value, they are kept when
+/// followed by an alphanumeric character or `=`, as in `?a=1&copy=2`.
+///
+/// The `custom` named references, given without `&` and `;`, must end with a
+/// semicolon. They take precedence over the standard ones.
+///
+/// Unknown or invalid references are kept as they were written, so no
+/// character is lost.
+pub fn decode_entities(input: &str, attribute: bool, custom: &BTreeMap<String, String>) -> String {
+    let mut decoded = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(rest.get(..start).unwrap_or_default());
+        rest = rest.get(start..).unwrap_or_default();
+        let (reference, len) =
+            read_reference(rest, attribute, custom).unwrap_or((Reference::Char('&'), 1));
+        match reference {
+            Reference::Char(ch) => decoded.push(ch),
+            Reference::Custom(text) => decoded.push_str(text),
+        }
+        rest = rest.get(len..).unwrap_or_default();
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Splits a string into runs of plain text and character references.
+///
+/// Every valid reference is a run of its own, so `ab&amp;cd` gives `ab`,
+/// `&amp;` and `cd`. The references are recognised as in
+/// [`decode_entities`].
+pub fn split_references<'input>(
+    input: &'input str,
+    custom: &BTreeMap<String, String>,
+) -> Vec<&'input str> {
+    let mut runs = Vec::new();
+    let mut run_start: usize = 0;
+    let mut cursor: usize = 0;
+    while let Some(found) = input.get(cursor..).and_then(|rest| rest.find('&')) {
+        let start = cursor.saturating_add(found);
+        let rest = input.get(start..).unwrap_or_default();
+        if let Some((_, len)) = read_reference(rest, false, custom) {
+            if run_start < start {
+                runs.push(input.get(run_start..start).unwrap_or_default());
+            }
+            run_start = start.saturating_add(len);
+            runs.push(rest.get(..len).unwrap_or_default());
+            cursor = run_start;
+        } else {
+            cursor = start.saturating_add(1);
+        }
+    }
+    if run_start < input.len() {
+        runs.push(input.get(run_start..).unwrap_or_default());
+    }
+    runs
+}
+
+/// Reads the character reference at the start of `rest`, that starts with
+/// `&`.
+///
+/// Returns the referenced character and the length of the reference, if it is
+/// valid. See [`decode_entities`] for the rules.
+fn read_reference<'custom>(
+    rest: &str,
+    attribute: bool,
+    custom: &'custom BTreeMap<String, String>,
+) -> Option<(Reference<'custom>, usize)> {
+    let terminated = rest.find(';').and_then(|end| {
+        let name = rest.get(1..end)?;
+        let reference = custom.get(name).map_or_else(
+            || decode_reference(name).map(Reference::Char),
+            |text| Some(Reference::Custom(text)),
+        )?;
+        Some((reference, end.saturating_add(1)))
+    });
+    terminated.or_else(|| {
+        let after = rest.get(1..)?;
+        let (name, ch, _) = NAMED_ENTITIES
+            .iter()
+            .filter(|(name, _, legacy)| *legacy && after.starts_with(name))
+            .max_by_key(|(name, ..)| name.len())?;
+        let next = after.get(name.len()..).and_then(|tail| tail.chars().next());
+        let ambiguous =
+            next.is_some_and(|following| following.is_ascii_alphanumeric() || following == '=');
+        (!(attribute && ambiguous)).then_some((Reference::Char(*ch), name.len().saturating_add(1)))
+    })
+}
+
+/// Decodes the content of a character reference, between `&` and `;`.
+fn decode_reference(reference: &str) -> Option<char> {
+    reference.strip_prefix('#').map_or_else(
+        || {
+            NAMED_ENTITIES
+                .iter()
+                .find(|(name, ..)| *name == reference)
+                .map(|(_, ch, _)| *ch)
+        },
+        |number| {
+            number
+                .strip_prefix(['x', 'X'])
+                .map_or_else(|| number.parse(), |hex| u32::from_str_radix(hex, 16))
+                .ok()
+                .and_then(char::from_u32)
+                .filter(|ch| *ch != '\0')
+        },
+    )
+}
@@ -0,0 +1,186 @@
+//! Module to define the errors returned by the parser.
+
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+use super::warning::Position;
+
+/// Errors returned when an HTML string couldn't be parsed
+///
+/// Errors are returned by [`Html::parse`](crate::prelude::Html::parse) and
+/// [`Html::parse_with_warnings`](crate::prelude::Html::parse_with_warnings).
+/// Some of them can be recovered from with
+/// [`ParseOptions::lenient`](super::options::ParseOptions::lenient).
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let err = Html::parse("<div></span></div>").unwrap_err();
+/// assert!(matches!(&err, ParseError::UnmatchedClosingTag { name, .. } if name == "span"));
+/// assert!(
+///     err.to_string()
+///         == "Invalid closing tag: Found closing tag for 'span' but it isn't open at line 1, column 6."
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Attributes were found on a closing tag
+    ///
+    /// # Examples
+    ///
+    /// `</a id='blob'>`
+    ClosingTagAttributes,
+    /// A doctype attribute was given a value
+    ///
+    /// # Examples
+    ///
+    /// `<!doctype html=''>`
+    DoctypeAttributeValue,
+    /// A doctype was given more than one attribute
+    ///
+    /// # Examples
+    ///
+    /// `<!doctype html xml>`
+    DoctypeTooManyAttributes,
+    /// A character is not allowed at this place
+    ///
+    /// # Examples
+    ///
+    /// `<button!>`
+    #[non_exhaustive]
+    InvalidCharacter {
+        /// Invalid character
+        character: char,
+        /// Context in which the character was found, like `tag name`
+        context: &'static str,
+    },
+    /// The input bytes aren't valid in their encoding
+    ///
+    /// # Examples
+    ///
+    /// `b"<p>\xFF</p>"` in UTF-8
+    #[non_exhaustive]
+    InvalidEncoding {
+        /// Name of the encoding, like `UTF-8`
+        encoding: &'static str,
+        /// Offset of the first invalid byte
+        offset: usize,
+    },
+    /// A limit set in [`ParseOptions`](crate::prelude::ParseOptions) was
+    /// exceeded
+    ///
+    /// # Examples
+    ///
+    /// `<p id='a' hidden>` with
+    /// [`ParseOptions::max_attributes_per_tag`](crate::prelude::ParseOptions::max_attributes_per_tag)
+    /// set to 1.
+    #[non_exhaustive]
+    LimitExceeded {
+        /// Description of the limited quantity, like `attributes in a tag`
+        limit: &'static str,
+        /// Maximum allowed
+        max: usize,
+    },
+    /// A closing tag matches an opened tag that isn't the innermost one
+    ///
+    /// This is only an error with
+    /// [`MismatchPolicy::Error`](crate::prelude::MismatchPolicy::Error).
+    ///
+    /// # Examples
+    ///
+    /// `<div><span></div>`
+    #[non_exhaustive]
+    MisnestedClosingTag {
+        /// Name of the closing tag
+        name: String,
+        /// Name of the innermost tag that is still open
+        open: String,
+        /// Position of the closing tag in the input
+        position: Position,
+    },
+    /// An attribute value was not surrounded by quotes
+    ///
+    /// # Examples
+    ///
+    /// `<p id=blob>`
+    #[non_exhaustive]
+    MissingAttributeQuote {
+        /// Character found instead of a quote
+        character: char,
+    },
+    /// The end of the input was reached inside a tag
+    ///
+    /// # Examples
+    ///
+    /// `<textarea`
+    UnclosedTag,
+    /// A closing tag was found but no tag of that name was open
+    ///
+    /// # Examples
+    ///
+    /// `<div></span></div>`
+    #[non_exhaustive]
+    UnmatchedClosingTag {
+        /// Name of the closing tag
+        name: String,
+        /// Position of the closing tag in the input
+        position: Position,
+    },
+    /// A closing `-->` was found but no comment was open
+    ///
+    /// # Examples
+    ///
+    /// `<!---->-->`
+    UnopenedComment,
+    /// The end of the input was reached inside a quoted attribute value
+    ///
+    /// # Examples
+    ///
+    /// `<a href="page`
+    #[non_exhaustive]
+    UnterminatedAttribute {
+        /// Name of the attribute
+        name: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ClosingTagAttributes => f.write_str("Closing tags don't support attributes."),
+            Self::DoctypeAttributeValue => f.write_str("Doctype attribute must not have a value."),
+            Self::DoctypeTooManyAttributes =>
+                f.write_str("Doctype expected at most one attribute."),
+            Self::InvalidCharacter { character, context } =>
+                write!(f, "Invalid character '{character}' in {context}."),
+            Self::InvalidEncoding { encoding, offset } =>
+                write!(f, "Invalid {encoding} sequence at byte {offset}."),
+            Self::LimitExceeded { limit, max } =>
+                write!(f, "Limit exceeded: more than {max} {limit}."),
+            Self::MisnestedClosingTag { name, open, position } => write!(
+                f,
+                "Invalid closing tag: Found closing tag for '{name}' but '{open}' is still open at {position}."
+            ),
+            Self::MissingAttributeQuote { character } =>
+                write!(f, "Invalid character '{character}': expected '\'' or '\"' after '=' sign."),
+            Self::UnclosedTag => f.write_str("EOF: Missing closing '>'."),
+            Self::UnmatchedClosingTag { name, position } => write!(
+                f,
+                "Invalid closing tag: Found closing tag for '{name}' but it isn't open at {position}."
+            ),
+            Self::UnopenedComment => f.write_str("Tried to close unopened comment."),
+            Self::UnterminatedAttribute { name } =>
+                write!(f, "EOF: Missing closing quote for the value of the attribute '{name}'."),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Error for ParseError {}
@@ -1,11 +1,31 @@
-//! Module that transforms a [`String`] into an [`Html`] tree.
+//! Module that transforms a [`String`] into an [`Html`]
+//! tree.
 
+mod document;
+pub mod encoding;
+pub mod entities;
+pub mod error;
+pub mod options;
 mod tag;
-use core::str::Chars;
+pub mod tokenizer;
+pub mod warning;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::str::FromStr;
 
-use crate::prelude::Html;
+use encoding::Encoding;
+use entities::{decode_entities, split_references};
+use error::ParseError;
+use options::{MismatchPolicy, ParseOptions};
+use tokenizer::{Lexeme, Tokenizer};
+use warning::{ParseWarning, Position};
+
+use crate::errors::safe_unreachable;
+use crate::prelude::{Html, Tag};
 use crate::types::html_builder::HtmlBuilder;
-use crate::types::tag::TagBuilder;
+use crate::types::tag::{Namespace, TagLexeme};
 
 /// Tags that cannot have a content
 ///
@@ -13,6 +33,74 @@ use crate::types::tag::TagBuilder;
 /// closed.
 const AUTO_CLOSING_TAGS: [&str; 2] = ["meta", "br"];
 
+/// HTML formatting elements
+///
+/// These are the elements reopened after a misnested closing tag with
+/// [`ParseOptions::adoption_agency`].
+const FORMATTING_ELEMENTS: [&str; 14] = [
+    "a", "b", "big", "code", "em", "font", "i", "nobr", "s", "small", "strike", "strong", "tt", "u",
+];
+
+/// Elements whose content is read as text, with character references
+///
+/// Only the closing tag of these RCDATA elements ends their content, so a `<`
+/// in a `<title>` is text. Unlike in `<script>` and `<style>` (see
+/// [`RAW_TEXT_ELEMENTS`]), the character references can be decoded with
+/// [`ParseOptions::decode_text_entities`].
+const ESCAPABLE_RAW_TEXT_ELEMENTS: [&str; 2] = ["textarea", "title"];
+
+/// Elements whose closing tag can be omitted
+///
+/// These elements are closed implicitly by the closing tag of their parent,
+/// like `<li>` by `</ul>`, so they don't make it misnested (see
+/// [`ParseOptions::on_mismatch`]).
+const OPTIONAL_END_TAGS: [&str; 19] = [
+    "body", "caption", "colgroup", "dd", "dt", "head", "html", "li", "optgroup", "option", "p",
+    "rp", "rt", "tbody", "td", "tfoot", "th", "thead", "tr",
+];
+
+/// Tags that hide the open `<p>` from a new `<p>`
+///
+/// A `<p>` implicitly closes the innermost open `<p>`, unless one of these
+/// tags was opened inside it, like in `<p><button><p>`.
+const PARAGRAPH_SCOPE: [&str; 6] = ["button", "caption", "table", "td", "template", "th"];
+
+/// Elements whose content is read as raw text
+///
+/// Only the closing tag of these elements ends their content, and their
+/// character references are never decoded.
+pub const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Table parts implicitly closed when opening another table part
+///
+/// Each tag is associated with the open tags it closes, if they are in the
+/// same table. Closing a tag also closes the tags opened inside it, so a new
+/// row also closes the open cell.
+const TABLE_PARTS: [(&str, &[&str]); 6] = [
+    ("tbody", &["tbody", "tfoot", "thead"]),
+    ("td", &["td", "th"]),
+    ("tfoot", &["tbody", "tfoot", "thead"]),
+    ("th", &["td", "th"]),
+    ("thead", &["tbody", "tfoot", "thead"]),
+    ("tr", &["tr"]),
+];
+
+/// Elements in which whitespace is significant
+///
+/// The whitespace-only text nodes inside these elements are kept by
+/// [`Html::trim_whitespace_nodes`] and
+/// [`SerializePolicy::trim_whitespace`](crate::prelude::SerializePolicy::trim_whitespace).
+pub const WHITESPACE_SENSITIVE: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// HTML elements that can't have any content
+///
+/// These are the only HTML elements on which the self-closing slash is
+/// honoured, as in `<br/>`.
+pub const VOID_ELEMENTS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
 impl Html {
     /// Parses an HTML string into a Dom tree.
     ///
@@ -39,82 +127,832 @@ impl Html {
     /// let tree: Html = Html::parse(html).expect("Invalid HTML");
     /// assert!(format!("{tree}") == html);
     /// ```
-    pub fn parse(html: &str) -> Result<Self, String> {
+    pub fn parse(html: &str) -> Result<Self, ParseError> {
+        Self::parse_with_warnings(html, &ParseOptions::default()).map(|(tree, _)| tree)
+    }
+
+    /// Parses an HTML byte string into a Dom tree.
+    ///
+    /// The encoding of the input is detected with [`Encoding::detect`]: a
+    /// byte order mark is removed, and with the `charset` feature, a
+    /// `<meta charset>` declaration is honoured. Use
+    /// [`Html::parse_bytes_with_encoding`] to choose the encoding.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error when the input isn't valid in the
+    /// detected encoding, or when the HTML's syntax is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let tree = Html::parse_bytes(b"\xEF\xBB\xBF<p>Hi</p>").unwrap();
+    /// assert!(tree.to_string() == "<p>Hi</p>");
+    ///
+    /// let err = Html::parse_bytes(b"<p>\xFF</p>").unwrap_err();
+    /// assert!(matches!(err, ParseError::InvalidEncoding { offset: 3, .. }));
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_bytes_with_encoding(bytes, Encoding::detect(bytes))
+    }
+
+    /// Parses an HTML byte string in the given encoding into a Dom tree.
+    ///
+    /// The byte order mark of the encoding is removed, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error when the input isn't valid in the
+    /// given encoding, or when the HTML's syntax is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let tree = Html::parse_bytes_with_encoding(b"<p>caf\xE9</p>", Encoding::Latin1).unwrap();
+    /// assert!(tree.to_string() == "<p>café</p>");
+    /// ```
+    pub fn parse_bytes_with_encoding(bytes: &[u8], encoding: Encoding) -> Result<Self, ParseError> {
+        Self::parse(&encoding.decode(bytes)?)
+    }
+
+    /// Parses several HTML documents concatenated with a separator
+    ///
+    /// The input is split on each occurrence of `separator`, and each segment
+    /// is parsed independently with [`Html::parse`], so an invalid document
+    /// doesn't prevent the others from being parsed. The positions of the
+    /// errors are relative to the start of their segment. An empty separator
+    /// doesn't split the input.
+    ///
+    /// # Returns
+    ///
+    /// The result of the parsing of each segment, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let results = Html::parse_many("<p>a</p>\n---\n<div></span>", "\n---\n");
+    /// assert!(results.len() == 2);
+    /// assert!(results[0] == Html::parse("<p>a</p>"));
+    /// assert!(results[1].is_err());
+    /// ```
+    #[must_use]
+    pub fn parse_many(input: &str, separator: &str) -> Vec<Result<Self, ParseError>> {
+        if separator.is_empty() {
+            vec![Self::parse(input)]
+        } else {
+            input.split(separator).map(Self::parse).collect()
+        }
+    }
+
+    /// Parses an HTML string into the list of its top-level nodes.
+    ///
+    /// This is the same as [`Html::parse`], without the [`Html::Vec`]
+    /// wrapper: the list is empty for an empty input, and has one node if
+    /// the input has a single root.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error when the input HTML's syntax is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let nodes = Html::parse_nodes("a<br>c").unwrap();
+    /// assert!(
+    ///     nodes
+    ///         == [
+    ///             Html::text("a"),
+    ///             Html::parse("<br>").unwrap(),
+    ///             Html::text("c")
+    ///         ]
+    /// );
+    /// assert!(Html::parse_nodes("").unwrap().is_empty());
+    /// ```
+    pub fn parse_nodes(html: &str) -> Result<Vec<Self>, ParseError> {
+        Self::parse(html).map(Self::into_fragment)
+    }
+
+    /// Parses the first node of an HTML string, and returns the number of
+    /// bytes consumed.
+    ///
+    /// The parsing stops at the end of the first top-level element, comment,
+    /// doctype or run of text, so the rest of the input can be parsed with
+    /// another call. This is useful to parse a stream of concatenated
+    /// fragments. If the first element is never closed, the whole input is
+    /// consumed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error when the syntax of the first node is
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let input = "<p>a<br>b</p>text<p>c</p>";
+    /// let (first, consumed) = Html::parse_prefix(input).unwrap();
+    /// assert!(first.to_string() == "<p>a<br>b</p>");
+    ///
+    /// let (second, _) = Html::parse_prefix(&input[consumed..]).unwrap();
+    /// assert!(second == Html::text("text"));
+    /// ```
+    pub fn parse_prefix(html: &str) -> Result<(Self, usize), ParseError> {
+        Self::parse_prefix_in(html, Namespace::Html)
+    }
+
+    /// Parses the first node of an HTML string, as if it were inside a tag
+    /// whose children are in the given namespace.
+    ///
+    /// See [`Html::parse_prefix`].
+    pub(crate) fn parse_prefix_in(
+        html: &str,
+        namespace: Namespace,
+    ) -> Result<(Self, usize), ParseError> {
+        let options = ParseOptions::default();
         let mut tree = HtmlBuilder::default();
-        tree.parse(&mut html.chars()).map(|()| tree.into_html())
+        let mut state = ParserState {
+            input: html,
+            last_position: Position::from_offset(html, 0),
+            namespace,
+            nodes: 0,
+            opened: vec![],
+            options: &options,
+            prefix: true,
+            warnings: vec![],
+        };
+        let consumed = tree.parse(&mut Tokenizer::new(html), &mut state)?;
+        Ok((tree.into_html(), consumed))
+    }
+
+    /// Parses an HTML string into a Dom tree, and returns the warnings found
+    /// while parsing.
+    ///
+    /// See [`ParseOptions`] to customise the parser, and [`ParseWarning`] for
+    /// the list of possible warnings.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error when the input HTML's syntax is invalid
+    /// and the error couldn't be recovered (see [`ParseOptions::lenient`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<div><p>Some text</span></p></div>";
+    /// assert!(Html::parse(html).is_err());
+    ///
+    /// let options = ParseOptions::new().lenient(true);
+    /// let (tree, warnings) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == "<div><p>Some text</p></div>");
+    /// assert!(warnings.first().unwrap().position().column() == 18);
+    /// ```
+    pub fn parse_with_warnings(
+        html: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), ParseError> {
+        let mut tree = HtmlBuilder::default();
+        let mut state = ParserState {
+            input: html,
+            last_position: Position::from_offset(html, 0),
+            namespace: Namespace::Html,
+            nodes: 0,
+            opened: vec![],
+            options,
+            prefix: false,
+            warnings: vec![],
+        };
+        let mut tokenizer = Tokenizer::new(html);
+        if options.is_parsing_conditional_comments() {
+            tokenizer.parse_conditional_comments();
+        }
+        tree.parse(&mut tokenizer, &mut state)?;
+        let parsed = tree.into_html();
+        if options.is_document_mode() {
+            Ok((parsed.into_document(), state.warnings))
+        } else {
+            Ok((parsed, state.warnings))
+        }
+    }
+}
+
+/// Parses an HTML string with the default options.
+///
+/// See [`Html::parse`].
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let tree: Html = "<p>a</p>".parse().unwrap();
+/// assert!(tree == Html::parse("<p>a</p>").unwrap());
+/// ```
+impl FromStr for Html {
+    type Err = ParseError;
+
+    fn from_str(html: &str) -> Result<Self, Self::Err> {
+        Self::parse(html)
+    }
+}
+
+/// Parses an HTML string with the default options.
+///
+/// See [`Html::parse`].
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// fn count_paragraphs<T: TryInto<Html, Error = ParseError>>(
+///     html: T,
+/// ) -> Result<usize, ParseError> {
+///     Ok(html.try_into()?.stats().tag_count("p"))
+/// }
+///
+/// assert!(count_paragraphs("<p><b>a</b></p><p>") == Ok(2));
+/// assert!(count_paragraphs("<p></span>").is_err());
+/// ```
+impl TryFrom<&str> for Html {
+    type Error = ParseError;
+
+    fn try_from(html: &str) -> Result<Self, Self::Error> {
+        Self::parse(html)
+    }
+}
+
+/// Parses an HTML string with the default options.
+///
+/// See [`Html::parse`]. The tree owns its strings, so it doesn't borrow the
+/// input, that is dropped after parsing.
+impl TryFrom<String> for Html {
+    type Error = ParseError;
+
+    fn try_from(html: String) -> Result<Self, Self::Error> {
+        Self::parse(&html)
+    }
+}
+
+/// Tag opened and not yet closed while parsing
+struct OpenedTag {
+    /// Namespace of the children of the tag
+    children: Namespace,
+    /// Name of the tag
+    name: String,
+    /// Namespace of the tag
+    namespace: Namespace,
+}
+
+/// State of the parser
+///
+/// Contains the information shared by the whole parsing of an input.
+struct ParserState<'input> {
+    /// String that is being parsed
+    input: &'input str,
+    /// Last position computed, from which the next ones are computed
+    last_position: Position,
+    /// Namespace of the top-level nodes
+    namespace: Namespace,
+    /// Number of nodes read so far
+    nodes: usize,
+    /// Tags opened in the tree, from the outermost to the innermost
+    ///
+    /// This mirrors the opened tags of the tree, to avoid walking the tree
+    /// for each lexeme.
+    opened: Vec<OpenedTag>,
+    /// Options given by the user
+    options: &'input ParseOptions,
+    /// Whether to stop after the first top-level node
+    ///
+    /// See [`Html::parse_prefix`].
+    prefix: bool,
+    /// Warnings found so far
+    warnings: Vec<ParseWarning>,
+}
+
+impl<'input> ParserState<'input> {
+    /// Checks that a new tag can be opened without exceeding the maximum
+    /// depth.
+    ///
+    /// See [`ParseOptions::max_depth`].
+    const fn check_depth(&self) -> Result<(), ParseError> {
+        check_limit(
+            self.opened.len().saturating_add(1),
+            self.options.get_max_depth(),
+            "levels of nesting",
+        )
+    }
+
+    /// Checks the attributes of a tag and reports the duplicates.
+    fn check_duplicates(&mut self, tag: &Tag, position: Position) {
+        let attrs = tag.as_attrs();
+        for (idx, attr) in attrs.iter().enumerate() {
+            if attrs
+                .iter()
+                .take(idx)
+                .any(|other| other.as_name() == attr.as_name())
+            {
+                self.warnings.push(ParseWarning::DuplicateAttribute {
+                    name: attr.as_name().to_owned(),
+                    position,
+                });
+            }
+        }
+    }
+
+    /// Counts a new node, and checks that the maximum number of nodes isn't
+    /// exceeded.
+    ///
+    /// See [`ParseOptions::max_total_nodes`].
+    const fn count_node(&mut self) -> Result<(), ParseError> {
+        self.nodes = self.nodes.saturating_add(1);
+        check_limit(self.nodes, self.options.get_max_total_nodes(), "nodes")
+    }
+
+    /// Checks if the self-closing slash of a tag must be honoured.
+    ///
+    /// If it isn't, a warning is pushed and the tag must be considered open.
+    fn is_self_closing(&mut self, tag: &Tag, position: Position) -> bool {
+        if self.options.is_xml_self_closing() || is_self_closable(tag) {
+            true
+        } else {
+            self.warnings.push(ParseWarning::IgnoredSelfClosingSlash {
+                name: tag.as_name().to_owned(),
+                position,
+            });
+            false
+        }
+    }
+
+    /// Finds the tag that makes the closing tag `name` misnested, if any.
+    ///
+    /// This is the innermost tag opened inside the innermost opened tag
+    /// `name`, ignoring the tags whose closing tag can be omitted (see
+    /// [`OPTIONAL_END_TAGS`]), and the formatting tags if they are reopened
+    /// (see [`ParseOptions::adoption_agency`]).
+    fn misnested_tag(&self, name: &str) -> Option<String> {
+        let idx = self.opened.iter().rposition(|opened| opened.name == name)?;
+        self.opened
+            .iter()
+            .skip(idx.saturating_add(1))
+            .map(|opened| opened.name.as_str())
+            .rfind(|tag_name| {
+                let implicit = OPTIONAL_END_TAGS.contains(tag_name)
+                    || (self.options.is_adoption_agency()
+                        && FORMATTING_ELEMENTS.contains(tag_name));
+                !implicit
+            })
+            .map(ToOwned::to_owned)
+    }
+
+    /// Returns the namespace in which the next tag will be opened.
+    fn open_namespace(&self) -> Namespace {
+        self.opened
+            .last()
+            .map_or(self.namespace, |opened| opened.children)
+    }
+
+    /// Returns the position of the character at the given byte `offset`.
+    ///
+    /// The position is computed from the last one, as the lexemes are read in
+    /// order.
+    fn position(&mut self, offset: usize) -> Position {
+        self.last_position = self.last_position.advance(self.input, offset);
+        self.last_position
+    }
+
+    /// Prepares a tag read by the tokenizer before pushing it in the tree.
+    ///
+    /// This checks the limits on the attributes, reports the duplicate
+    /// attributes, stores the `source` of the tag, converts its names to
+    /// lowercase and decodes its attribute values if needed.
+    fn prepare_tag(
+        &mut self,
+        tag: &mut Tag,
+        position: Position,
+        source: Option<&str>,
+    ) -> Result<(), ParseError> {
+        check_limit(
+            tag.as_attrs().len(),
+            self.options.get_max_attributes_per_tag(),
+            "attributes in a tag",
+        )?;
+        for attr in tag.as_attrs() {
+            check_limit(
+                attr.as_value().map_or(0, String::len),
+                self.options.get_max_attribute_value_len(),
+                "bytes in an attribute value",
+            )?;
+        }
+        self.check_duplicates(tag, position);
+        tag.set_open_source(source);
+        if self.options.is_lowercasing_names() && !tag.as_namespace().is_foreign() {
+            tag.lowercase_names();
+        }
+        if self.options.is_keeping_raw_attr_values() {
+            tag.store_raw_values();
+        }
+        if self.options.is_decoding_attr_entities() {
+            tag.decode_attr_values(self.options.get_custom_entities());
+        }
+        Ok(())
+    }
+
+    /// Recovers from an error if possible.
+    ///
+    /// # Returns
+    ///
+    /// - in lenient mode, the warning is stored and the parsing can continue.
+    /// - in strict mode, the error is returned.
+    fn recover(&mut self, err: ParseError, warning: ParseWarning) -> Result<(), ParseError> {
+        if self.options.is_lenient() {
+            self.warnings.push(warning);
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Recovers from a tag interrupted inside the value of its last attribute.
+    ///
+    /// See [`ParserState::recover`].
+    fn recover_unterminated(&mut self, tag: &Tag, position: Position) -> Result<(), ParseError> {
+        let name = tag
+            .as_attrs()
+            .last()
+            .map(|attr| attr.as_name().to_owned())
+            .unwrap_or_default();
+        self.recover(
+            ParseError::UnterminatedAttribute { name: name.clone() },
+            ParseWarning::UnterminatedAttribute { name, position },
+        )
+    }
+
+    /// Returns the source between `start` and `end`, if the source must be
+    /// preserved.
+    ///
+    /// See [`ParseOptions::preserve_source`].
+    fn source(&self, start: usize, end: usize) -> Option<&'input str> {
+        if self.options.is_preserving_source() {
+            self.input.get(start..end)
+        } else {
+            None
+        }
     }
 }
 
 impl HtmlBuilder {
+    /// Closes the innermost opened tag `name`, and the tags opened inside it.
+    ///
+    /// See [`HtmlBuilder::close_tag_aux`].
+    fn close_opened_tag(
+        &mut self,
+        name: &str,
+        source: Option<&str>,
+        implicit: bool,
+        state: &mut ParserState<'_>,
+    ) -> bool {
+        let closed = self.close_tag_aux(name, source, implicit);
+        if closed && let Some(idx) = state.opened.iter().rposition(|opened| opened.name == name) {
+            state.opened.truncate(idx);
+        }
+        closed
+    }
+
+    /// Closes the tag `name` read by the tokenizer.
+    ///
+    /// Empty closing tags `</>` close the innermost tag with an empty name,
+    /// like `<>`, and are ignored with a warning if there is none. Then, the
+    /// [`ParseOptions`] are applied: the name is converted to lowercase, the
+    /// misnested formatting tags are reopened and the unmatched closing tags
+    /// are recovered from.
+    fn close_parsed_tag(
+        &mut self,
+        mut name: String,
+        position: Position,
+        source: Option<&str>,
+        state: &mut ParserState<'_>,
+    ) -> Result<(), ParseError> {
+        if name.is_empty() {
+            if !self.close_opened_tag(&name, source, false, state) {
+                state
+                    .warnings
+                    .push(ParseWarning::EmptyClosingTag { position });
+            }
+            return Ok(());
+        }
+        if state.options.is_lowercasing_names()
+            && !state
+                .opened
+                .last()
+                .is_some_and(|opened| opened.children.is_foreign())
+        {
+            name.make_ascii_lowercase();
+        }
+        if let Some(open) = state.misnested_tag(&name) {
+            match state.options.get_mismatch_policy() {
+                MismatchPolicy::CloseAncestor => state
+                    .warnings
+                    .push(ParseWarning::MisnestedClosingTag { name: name.clone(), open, position }),
+                MismatchPolicy::Error =>
+                    return Err(ParseError::MisnestedClosingTag { name, open, position }),
+                MismatchPolicy::Ignore => {
+                    state
+                        .warnings
+                        .push(ParseWarning::MisnestedClosingTag { name, open, position });
+                    return Ok(());
+                }
+            }
+        }
+        let reopened = if state.options.is_adoption_agency() {
+            self.formatting_to_reopen(&name, &state.opened)
+        } else {
+            vec![]
+        };
+        if !self.close_opened_tag(&name, source, false, state) {
+            state.recover(
+                ParseError::UnmatchedClosingTag { name: name.clone(), position },
+                ParseWarning::UnmatchedClosingTag { name, position },
+            )?;
+        }
+        for tag in reopened {
+            self.open_tag(tag, state);
+        }
+        Ok(())
+    }
+
+    /// Returns the formatting tags to reopen after closing the tag `name`.
+    ///
+    /// These are the formatting tags opened inside the innermost opened tag
+    /// `name`, without their source. See [`ParseOptions::adoption_agency`].
+    ///
+    /// The tree is only walked to clone the tags if there are some.
+    fn formatting_to_reopen(&self, name: &str, opened: &[OpenedTag]) -> Vec<Tag> {
+        let Some(idx) = opened.iter().rposition(|tag| tag.name == name) else {
+            return vec![];
+        };
+        let is_formatting = |tag_name: &str| FORMATTING_ELEMENTS.contains(&tag_name);
+        if !opened
+            .iter()
+            .skip(idx.saturating_add(1))
+            .any(|tag| is_formatting(&tag.name))
+        {
+            return vec![];
+        }
+        self.opened_tags()
+            .into_iter()
+            .skip(idx.saturating_add(1))
+            .filter(|tag| is_formatting(tag.as_name()))
+            .map(|tag| {
+                let mut reopened = tag.clone();
+                reopened.set_open_source(None);
+                reopened
+            })
+            .collect()
+    }
+
+    /// Checks if the tree contains something else than comments and
+    /// whitespace.
+    ///
+    /// A doctype read after such content is misplaced.
+    fn has_content(&self) -> bool {
+        match self {
+            Self::BogusComment(_) | Self::Comment { .. } | Self::Conditional(_) | Self::Empty =>
+                false,
+            Self::Doctype { .. } | Self::Tag { .. } => true,
+            Self::Text(text) => !text.bytes().all(|byte| byte.is_ascii_whitespace()),
+            Self::Vec(vec) => vec.iter().any(Self::has_content),
+        }
+    }
+
+    /// Prepares the tree before opening a paragraph.
+    ///
+    /// This closes the open `<p>` implicitly closed by `tag`, if `tag` is a
+    /// `<p>` (see [`PARAGRAPH_SCOPE`]) and [`ParseOptions::close_paragraphs`]
+    /// is set.
+    fn open_paragraph(&mut self, tag: &Tag, state: &mut ParserState<'_>) {
+        if !state.options.is_closing_paragraphs()
+            || tag.as_name() != "p"
+            || tag.as_namespace().is_foreign()
+        {
+            return;
+        }
+        let opened = state
+            .opened
+            .iter()
+            .rev()
+            .take_while(|opened| {
+                !opened.namespace.is_foreign() && !PARAGRAPH_SCOPE.contains(&opened.name.as_str())
+            })
+            .any(|opened| opened.name == "p");
+        if opened {
+            self.close_opened_tag("p", None, true, state);
+        }
+    }
+
+    /// Prepares the tree before opening a table part, like `<td>`.
+    ///
+    /// This closes the table parts implicitly closed by `tag` (see
+    /// [`TABLE_PARTS`]) and, with [`ParseOptions::normalize_tables`], opens
+    /// the implicit `<tbody>` of a row written directly in a table.
+    fn open_table_part(&mut self, tag: &Tag, state: &mut ParserState<'_>) {
+        let Some((_, closed)) = TABLE_PARTS.iter().find(|(name, _)| tag.as_name() == name) else {
+            return;
+        };
+        if tag.as_namespace().is_foreign() {
+            return;
+        }
+        let to_close = state
+            .opened
+            .iter()
+            .rev()
+            .take_while(|opened| opened.name != "table")
+            .find(|opened| closed.contains(&opened.name.as_str()))
+            .map(|opened| opened.name.clone());
+        if let Some(name) = to_close {
+            self.close_opened_tag(&name, None, true, state);
+        }
+        if state.options.is_normalizing_tables()
+            && tag.as_name() == "tr"
+            && state
+                .opened
+                .last()
+                .is_some_and(|opened| opened.name == "table")
+        {
+            self.open_tag(Tag::builder("tbody").build(), state);
+        }
+    }
+
+    /// Pushes an opened tag into the tree.
+    ///
+    /// The tag is also pushed on the stack of the opened tags of the `state`.
+    fn open_tag(&mut self, tag: Tag, state: &mut ParserState<'_>) {
+        state.opened.push(OpenedTag {
+            children: tag.children_namespace(),
+            name: tag.as_name().to_owned(),
+            namespace: tag.as_namespace(),
+        });
+        self.push_tag(tag, false);
+    }
+
     /// Wrapper for the [`Html::parse`] method.
     ///
-    /// This method transforms a flow of chars into an Html tree.
-    fn parse(&mut self, chars: &mut Chars<'_>) -> Result<(), String> {
-        let mut dash_count: u32 = 0;
-        let mut style = false;
-        let mut script = false;
-        let mut comment = false;
-        while let Some(ch) = chars.next() {
-            if !comment && (style || script) {
-                if ch == '<' {
-                    if let Ok(TagBuilder::Close(name)) = TagBuilder::parse(chars) {
-                        if style && name == "style" {
-                            style = false;
-                            self.close_tag(&name)?;
-                            continue;
-                        }
-                        if script && name == "script" {
-                            script = false;
-                            self.close_tag(&name)?;
-                            continue;
-                        }
+    /// This method transforms a flow of lexemes into an Html tree, and
+    /// returns the number of bytes consumed.
+    fn parse(
+        &mut self,
+        tokenizer: &mut Tokenizer<'_>,
+        state: &mut ParserState<'_>,
+    ) -> Result<usize, ParseError> {
+        while let Some(lexeme) = tokenizer.lex(state.open_namespace()) {
+            let (start, end) = tokenizer.span();
+            let position = state.position(start);
+            let source = state.source(start, end);
+            if !matches!(lexeme, Err(_) | Ok(Lexeme::Tag(TagLexeme::Close(_)))) {
+                state.count_node()?;
+            }
+            match lexeme? {
+                Lexeme::BogusComment(content) => self.push_bogus_comment(content),
+                Lexeme::Comment { content, full } => self.push_comment(content, full),
+                Lexeme::Conditional(condition) => self.push_conditional(condition),
+                Lexeme::Text(text) => self.push_text(&text, state),
+                Lexeme::Tag(TagLexeme::Doctype { name, attr, subset }) => {
+                    if self.has_content() {
+                        state
+                            .warnings
+                            .push(ParseWarning::MisplacedDoctype { position });
                     }
+                    self.push_node(Self::Doctype {
+                        name,
+                        attr,
+                        subset,
+                        source: source.map(ToOwned::to_owned),
+                    });
                 }
-                self.push_char(ch);
-            } else if ch == '-' {
-                #[expect(clippy::arithmetic_side_effects, reason = "checked")]
-                if dash_count == 2 {
-                    self.push_char('-');
-                } else {
-                    dash_count += 1;
-                }
-            } else if ch == '>' && dash_count == 2 {
-                if !self.close_comment() {
-                    return Err("Tried to close unopened comment.".to_owned());
-                }
-                comment = false;
-                dash_count = 0;
-            } else {
-                for _ in 0..dash_count {
-                    self.push_char('-');
+                Lexeme::Tag(TagLexeme::OpenClose(mut tag))
+                    if state.is_self_closing(&tag, position) =>
+                {
+                    state.prepare_tag(&mut tag, position, source)?;
+                    self.push_tag(tag, true);
                 }
-                dash_count = 0;
-                if comment {
-                    self.push_char(ch);
-                } else if ch == '<' {
-                    match TagBuilder::parse(chars)? {
-                        TagBuilder::Doctype { name, attr } =>
-                            self.push_node(Self::Doctype { name, attr }),
-                        TagBuilder::Open(tag) => {
-                            if tag.as_name() == "style" {
-                                style = true;
-                            } else if tag.as_name() == "script" {
-                                script = true;
-                            }
-                            self.push_tag(tag, false);
-                        }
-                        TagBuilder::OpenClose(tag) => self.push_tag(tag, true),
-                        TagBuilder::Close(name) => self.close_tag(&name)?,
-                        TagBuilder::OpenComment => {
-                            self.push_comment();
-                            comment = true;
-                        }
+                Lexeme::Tag(TagLexeme::Open(mut tag) | TagLexeme::OpenClose(mut tag)) => {
+                    state.prepare_tag(&mut tag, position, source)?;
+                    self.open_paragraph(&tag, state);
+                    self.open_table_part(&tag, state);
+                    state.check_depth()?;
+                    if is_text_only(&tag) {
+                        tokenizer.start_raw_text(tag.as_name());
                     }
-                } else {
-                    self.push_char(ch);
+                    self.open_tag(tag, state);
+                }
+                Lexeme::Tag(TagLexeme::Close(name)) =>
+                    self.close_parsed_tag(name, position, source, state)?,
+                Lexeme::Tag(TagLexeme::UnterminatedAttribute(mut tag)) => {
+                    state.recover_unterminated(&tag, position)?;
+                    state.prepare_tag(&mut tag, position, source)?;
+                    state.check_depth()?;
+                    self.open_tag(tag, state);
                 }
+                Lexeme::Tag(TagLexeme::OpenComment) =>
+                    safe_unreachable("Comments are read by the tokenizer."),
+            }
+            if state.prefix && !matches!(self, Self::Empty) && state.opened.is_empty() {
+                return Ok(end);
             }
         }
-        Ok(())
+        Ok(state.input.len())
+    }
+
+    /// Pushes a text lexeme into the tree.
+    ///
+    /// The character references are decoded, and the text is split into runs,
+    /// if the options require it. Neither happens inside `<script>` and
+    /// `<style>`, whose content is raw text.
+    fn push_text(&mut self, text: &str, state: &ParserState<'_>) {
+        let options = state.options;
+        let raw = state
+            .opened
+            .last()
+            .is_some_and(|opened| RAW_TEXT_ELEMENTS.contains(&opened.name.as_str()));
+        let decode = |run: &str| {
+            if options.is_decoding_text_entities() && !raw {
+                decode_entities(run, false, options.get_custom_entities())
+            } else {
+                run.to_owned()
+            }
+        };
+        if options.is_splitting_text_runs() {
+            let runs = if raw {
+                vec![text]
+            } else {
+                split_references(text, options.get_custom_entities())
+            };
+            for run in runs {
+                self.push_node(Self::Text(decode(run)));
+            }
+        } else {
+            self.push_str(&decode(text));
+        }
+    }
+}
+
+/// Checks that a `value` doesn't exceed its maximum, if any.
+///
+/// The `limit` describes the value for the error message.
+const fn check_limit(
+    value: usize,
+    max: Option<usize>,
+    limit: &'static str,
+) -> Result<(), ParseError> {
+    match max {
+        Some(max_value) if value > max_value =>
+            Err(ParseError::LimitExceeded { limit, max: max_value }),
+        Some(_) | None => Ok(()),
     }
 }
+
+/// Checks if the self-closing slash of a tag is honoured by default.
+///
+/// This is the case of the void elements, like `<br/>`, and of the tags of
+/// the foreign content, like `<svg><rect/></svg>`. The slash of the other
+/// tags is ignored, as in browsers, so `<div/>` opens a `<div>`.
+pub fn is_self_closable(tag: &Tag) -> bool {
+    tag.as_namespace().is_foreign() || tag.is_one_of(&VOID_ELEMENTS)
+}
+
+/// Checks if a character can be used in a tag name.
+///
+/// The names start with an ASCII letter (`first` is `true` for the first
+/// character), followed by ASCII letters, digits, `-`, `_`, `.` and `:`, as
+/// in the custom elements like `<my-component>` and the XML names like
+/// `<svg:rect>`. The non-ASCII characters are accepted everywhere.
+pub const fn is_tag_name_char(ch: char, first: bool) -> bool {
+    if ch.is_ascii() {
+        ch.is_ascii_alphabetic()
+            || (!first && (ch.is_ascii_digit() || matches!(ch, '-' | '_' | '.' | ':')))
+    } else {
+        true
+    }
+}
+
+/// Checks if the content of a tag is read as text, until its closing tag.
+///
+/// This is the case of the raw text elements, like `<script>`, and of the
+/// escapable raw text elements of the HTML namespace, like `<title>`.
+pub fn is_text_only(tag: &Tag) -> bool {
+    let name = tag.as_name().as_str();
+    RAW_TEXT_ELEMENTS.contains(&name)
+        || (ESCAPABLE_RAW_TEXT_ELEMENTS.contains(&name) && !tag.as_namespace().is_foreign())
+}
@@ -0,0 +1,757 @@
+//! Module to define the options given to the parser.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+/// Handling of the misnested closing tags
+///
+/// A closing tag is misnested when it matches an opened tag that isn't the
+/// innermost one, like `</div>` in `<div><span></div>`. The tags whose
+/// closing tag can be omitted, like `<li>` or `<p>`, are closed implicitly and
+/// don't make a closing tag misnested: `</ul>` in `<ul><li>a</ul>` is fine.
+///
+/// See [`ParseOptions::on_mismatch`].
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MismatchPolicy {
+    /// Closes the tags opened inside the matching tag, with a warning
+    #[default]
+    CloseAncestor,
+    /// Returns an error, even in lenient mode
+    Error,
+    /// Ignores the closing tag, with a warning
+    ///
+    /// The matching tag stays open, as the tags opened inside it.
+    Ignore,
+}
+
+/// Options to customise the behaviour of the parser.
+///
+/// By default, the parser is strict: it returns an error on recoverable
+/// syntax errors (like a closing tag that doesn't match any opened tag). In
+/// lenient mode, these errors are ignored and reported as
+/// [`ParseWarning`](super::warning::ParseWarning)s.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let options = ParseOptions::new().lenient(true);
+/// let (tree, warnings) = Html::parse_with_warnings("<div></span></div>", &options).unwrap();
+/// assert!(tree.to_string() == "<div></div>");
+/// assert!(warnings.len() == 1);
+/// ```
+#[non_exhaustive]
+#[derive(Default, Debug)]
+#[expect(clippy::struct_excessive_bools, reason = "options are independent")]
+pub struct ParseOptions {
+    /// Auto-corrects misnested formatting tags
+    ///
+    /// See [`ParseOptions::adoption_agency`].
+    adoption_agency: bool,
+    /// Closes the open paragraph when a new one is opened
+    ///
+    /// See [`ParseOptions::close_paragraphs`].
+    close_paragraphs: bool,
+    /// Parses the content of the conditional comments
+    ///
+    /// See [`ParseOptions::parse_conditional_comments`].
+    conditional_comments: bool,
+    /// Named character references added to the standard ones
+    ///
+    /// See [`ParseOptions::custom_entities`].
+    custom_entities: BTreeMap<String, String>,
+    /// Decodes the character references in the attribute values
+    ///
+    /// See [`ParseOptions::decode_attr_entities`].
+    decode_attr_entities: bool,
+    /// Decodes the character references in the texts
+    ///
+    /// See [`ParseOptions::decode_text_entities`].
+    decode_text_entities: bool,
+    /// Builds the `<html>`, `<head>` and `<body>` structure of a document
+    ///
+    /// See [`ParseOptions::document_mode`].
+    document_mode: bool,
+    /// Keeps the attribute values as they were written
+    ///
+    /// See [`ParseOptions::keep_raw_attr_values`].
+    keep_raw_attr_values: bool,
+    /// Recovers from syntax errors when possible
+    ///
+    /// The recovered errors are reported as warnings.
+    lenient: bool,
+    /// Converts the tag and attribute names to lowercase
+    ///
+    /// See [`ParseOptions::lowercase_names`].
+    lowercase_names: bool,
+    /// Maximum length of an attribute value, in bytes
+    ///
+    /// See [`ParseOptions::max_attribute_value_len`].
+    max_attribute_value_len: Option<usize>,
+    /// Maximum number of attributes in a tag
+    ///
+    /// See [`ParseOptions::max_attributes_per_tag`].
+    max_attributes_per_tag: Option<usize>,
+    /// Maximum number of nested tags
+    ///
+    /// See [`ParseOptions::max_depth`].
+    max_depth: Option<usize>,
+    /// Maximum number of nodes in the tree
+    ///
+    /// See [`ParseOptions::max_total_nodes`].
+    max_total_nodes: Option<usize>,
+    /// Handling of the misnested closing tags
+    ///
+    /// See [`ParseOptions::on_mismatch`].
+    mismatch_policy: MismatchPolicy,
+    /// Inserts the implicit `<tbody>` of the tables
+    ///
+    /// See [`ParseOptions::normalize_tables`].
+    normalize_tables: bool,
+    /// Keeps the original source of the tags
+    ///
+    /// See [`ParseOptions::preserve_source`].
+    preserve_source: bool,
+    /// Keeps the adjacent texts in separate nodes
+    ///
+    /// See [`ParseOptions::split_text_runs`].
+    split_text_runs: bool,
+    /// Honours the self-closing slash on all the tags
+    ///
+    /// See [`ParseOptions::xml_self_closing`].
+    xml_self_closing: bool,
+}
+
+/// Private methods for [`ParseOptions`]
+impl ParseOptions {
+    /// Returns the custom named character references.
+    pub(crate) const fn get_custom_entities(&self) -> &BTreeMap<String, String> {
+        &self.custom_entities
+    }
+
+    /// Returns the maximum length of an attribute value, if any.
+    pub(crate) const fn get_max_attribute_value_len(&self) -> Option<usize> {
+        self.max_attribute_value_len
+    }
+
+    /// Returns the maximum number of attributes in a tag, if any.
+    pub(crate) const fn get_max_attributes_per_tag(&self) -> Option<usize> {
+        self.max_attributes_per_tag
+    }
+
+    /// Returns the maximum number of nested tags, if any.
+    pub(crate) const fn get_max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Returns the maximum number of nodes in the tree, if any.
+    pub(crate) const fn get_max_total_nodes(&self) -> Option<usize> {
+        self.max_total_nodes
+    }
+
+    /// Returns the handling of the misnested closing tags.
+    pub(crate) const fn get_mismatch_policy(&self) -> MismatchPolicy {
+        self.mismatch_policy
+    }
+
+    /// Checks if the parser must auto-correct misnested formatting tags.
+    pub(crate) const fn is_adoption_agency(&self) -> bool {
+        self.adoption_agency
+    }
+
+    /// Checks if a `<p>` must close the open paragraph.
+    pub(crate) const fn is_closing_paragraphs(&self) -> bool {
+        self.close_paragraphs
+    }
+
+    /// Checks if the parser must decode the character references in the
+    /// attribute values.
+    pub(crate) const fn is_decoding_attr_entities(&self) -> bool {
+        self.decode_attr_entities
+    }
+
+    /// Checks if the parser must decode the character references in the
+    /// texts.
+    pub(crate) const fn is_decoding_text_entities(&self) -> bool {
+        self.decode_text_entities
+    }
+
+    /// Checks if the parser must build the structure of a document.
+    pub(crate) const fn is_document_mode(&self) -> bool {
+        self.document_mode
+    }
+
+    /// Checks if the raw attribute values must be kept.
+    pub(crate) const fn is_keeping_raw_attr_values(&self) -> bool {
+        self.keep_raw_attr_values
+    }
+
+    /// Checks if the parser must recover from syntax errors.
+    pub(crate) const fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Checks if the parser must convert the names to lowercase.
+    pub(crate) const fn is_lowercasing_names(&self) -> bool {
+        self.lowercase_names
+    }
+
+    /// Checks if the parser must insert the implicit `<tbody>` of the tables.
+    pub(crate) const fn is_normalizing_tables(&self) -> bool {
+        self.normalize_tables
+    }
+
+    /// Checks if the parser must parse the content of the conditional
+    /// comments.
+    pub(crate) const fn is_parsing_conditional_comments(&self) -> bool {
+        self.conditional_comments
+    }
+
+    /// Checks if the parser must keep the original source of the tags.
+    pub(crate) const fn is_preserving_source(&self) -> bool {
+        self.preserve_source
+    }
+
+    /// Checks if the parser must keep the adjacent texts in separate nodes.
+    pub(crate) const fn is_splitting_text_runs(&self) -> bool {
+        self.split_text_runs
+    }
+
+    /// Checks if the parser must honour the self-closing slash on all tags.
+    pub(crate) const fn is_xml_self_closing(&self) -> bool {
+        self.xml_self_closing
+    }
+}
+
+/// Public API for [`ParseOptions`]
+impl ParseOptions {
+    /// Sets the adoption agency mode, to auto-correct misnested tags
+    ///
+    /// A closing tag is misnested when it matches an opened tag that isn't the
+    /// innermost one, like `</b>` in `<b><i>x</b></i>`.
+    ///
+    /// - If `adoption_agency` is set to `false` (default), the tags opened
+    ///   inside the closed one are closed with it.
+    /// - If `adoption_agency` is set to `true`, the formatting tags (like
+    ///   `<b>`, `<i>` or `<a>`) opened inside the closed one are also reopened
+    ///   after it, as browsers do. This is a simplified version of the adoption
+    ///   agency algorithm of the HTML specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<b><i>x</b>y</i>";
+    /// assert!(Html::parse(html).is_err());
+    ///
+    /// let options = ParseOptions::new().adoption_agency(true);
+    /// let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == "<b><i>x</i></b><i>y</i>");
+    /// ```
+    #[must_use]
+    pub const fn adoption_agency(mut self, adoption_agency: bool) -> Self {
+        self.adoption_agency = adoption_agency;
+        self
+    }
+
+    /// Sets the implicit closing of the paragraphs
+    ///
+    /// - If `close_paragraphs` is set to `false` (default), a `<p>` opened in a
+    ///   paragraph is nested in it, like any other tag.
+    /// - If `close_paragraphs` is set to `true`, a `<p>` implicitly closes the
+    ///   open `<p>`, as in browsers, unless a tag like `<button>` or `<table>`
+    ///   was opened inside it. The closed paragraph is then marked as
+    ///   [implicitly closed](crate::prelude::Tag::is_implicitly_closed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<p>a<p>b";
+    /// assert!(Html::parse(html).unwrap().to_string() == "<p>a<p>b</p></p>");
+    ///
+    /// let options = ParseOptions::new().close_paragraphs(true);
+    /// let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == "<p>a</p><p>b</p>");
+    /// ```
+    #[must_use]
+    pub const fn close_paragraphs(mut self, close_paragraphs: bool) -> Self {
+        self.close_paragraphs = close_paragraphs;
+        self
+    }
+
+    /// Adds custom named character references
+    ///
+    /// The keys of `entities` are the names of the references, without `&`
+    /// and `;`, and the values are the texts they stand for. They are
+    /// decoded with the standard references, when
+    /// [`ParseOptions::decode_text_entities`] or
+    /// [`ParseOptions::decode_attr_entities`] is set, and take precedence
+    /// over them. Unlike the legacy references, the custom ones must end with
+    /// a semicolon. The unknown references are still kept as they were
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use html_filter::prelude::*;
+    ///
+    /// let entities = BTreeMap::from([("mydash".to_owned(), "\u{2014}".to_owned())]);
+    /// let options = ParseOptions::new()
+    ///     .custom_entities(entities)
+    ///     .decode_text_entities(true);
+    /// let (tree, _) = Html::parse_with_warnings("<p>a &mydash; b &other;</p>", &options).unwrap();
+    /// assert!(tree.text_content(false) == "a \u{2014} b &other;");
+    /// ```
+    #[must_use]
+    pub fn custom_entities(mut self, entities: BTreeMap<String, String>) -> Self {
+        self.custom_entities = entities;
+        self
+    }
+
+    /// Sets the decoding of character references in attribute values
+    ///
+    /// - If `decode_attr_entities` is set to `false` (default), the attribute
+    ///   values are stored as they were written.
+    /// - If `decode_attr_entities` is set to `true`, the character references
+    ///   of the attribute values, like `&amp;` or `&#x41;`, are replaced by the
+    ///   characters they stand for.
+    ///
+    /// The supported named references are `&amp;`, `&apos;`, `&copy;`,
+    /// `&gt;`, `&lt;`, `&nbsp;`, `&not;`, `&notin;`, `&quot;` and `&reg;`. All
+    /// of them but `&apos;` and `&notin;` are legacy references, also decoded
+    /// without their semicolon, unless they are followed by an alphanumeric
+    /// character or `=` in an attribute value. The other references, and the
+    /// `&` that don't start a reference, are kept as they were written. The
+    /// decoded values are not escaped again when the tree is displayed.
+    ///
+    /// This option is independent of [`ParseOptions::decode_text_entities`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().decode_attr_entities(true);
+    /// let (tree, _) = Html::parse_with_warnings("<a href='?a=1&amp;b=2'>x</a>", &options).unwrap();
+    /// assert!(tree.to_string() == "<a href='?a=1&b=2'>x</a>");
+    /// ```
+    #[must_use]
+    pub const fn decode_attr_entities(mut self, decode_attr_entities: bool) -> Self {
+        self.decode_attr_entities = decode_attr_entities;
+        self
+    }
+
+    /// Sets the decoding of character references in texts
+    ///
+    /// - If `decode_text_entities` is set to `false` (default), the texts are
+    ///   stored as they were written.
+    /// - If `decode_text_entities` is set to `true`, the character references
+    ///   of the texts are replaced by the characters they stand for, except in
+    ///   `<script>` and `<style>`, whose content is raw text.
+    ///
+    /// See [`ParseOptions::decode_attr_entities`] for the supported references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().decode_text_entities(true);
+    /// let (tree, _) = Html::parse_with_warnings("<p>1 &lt; 2 &#38; 3</p>", &options).unwrap();
+    /// assert!(tree.text_content(false) == "1 < 2 & 3");
+    /// ```
+    #[must_use]
+    pub const fn decode_text_entities(mut self, decode_text_entities: bool) -> Self {
+        self.decode_text_entities = decode_text_entities;
+        self
+    }
+
+    /// Sets the document mode, to build the structure of a full document
+    ///
+    /// - If `document_mode` is set to `false` (default), the input is parsed as
+    ///   a fragment: the tree only contains the nodes of the input.
+    /// - If `document_mode` is set to `true`, the missing `<html>`, `<head>`
+    ///   and `<body>` tags are inserted, as in browsers. The metadata tags that
+    ///   precede the content, like `<title>`, `<meta>` or `<link>`, are moved
+    ///   into the `<head>`, and the content into the `<body>`. The doctype and
+    ///   the comments before the content stay before `<html>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().document_mode(true);
+    /// let (tree, _) =
+    ///     Html::parse_with_warnings("<!DOCTYPE html><title>Home</title>Hi", &options).unwrap();
+    /// assert!(
+    ///     tree.to_string()
+    ///         == "<!DOCTYPE html><html><head><title>Home</title></head><body>Hi</body></html>"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn document_mode(mut self, document_mode: bool) -> Self {
+        self.document_mode = document_mode;
+        self
+    }
+
+    /// Keeps the attribute values as they were written
+    ///
+    /// - If `keep_raw_attr_values` is set to `false` (default), only the values
+    ///   of the tree are kept.
+    /// - If `keep_raw_attr_values` is set to `true`, the tags also keep the
+    ///   values of their attributes as they were written, before the decoding
+    ///   of [`ParseOptions::decode_attr_entities`]. They are given by
+    ///   [`Tag::get_raw`](crate::prelude::Tag::get_raw).
+    ///
+    /// This is useful to analyse both forms of obfuscated values, like
+    /// `javascript:` URLs written with character references. It is disabled by
+    /// default, as it stores a copy of every attribute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().keep_raw_attr_values(true);
+    /// let (html, _) = Html::parse_with_warnings("<p id='&lt;'>x</p>", &options).unwrap();
+    /// if let Html::Tag { tag, .. } = html {
+    ///     assert!(tag.get_raw("id").unwrap() == "&lt;");
+    /// } else {
+    ///     unreachable!()
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn keep_raw_attr_values(mut self, keep_raw_attr_values: bool) -> Self {
+        self.keep_raw_attr_values = keep_raw_attr_values;
+        self
+    }
+
+    /// Sets the lenient mode
+    ///
+    /// - If `lenient` is set to `false` (default), recoverable syntax errors
+    ///   return an error.
+    /// - If `lenient` is set to `true`, the parser recovers from these errors
+    ///   and reports them as warnings.
+    #[must_use]
+    pub const fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Sets the lowercase mode for tag and attribute names
+    ///
+    /// - If `lowercase_names` is set to `false` (default), the names are stored
+    ///   as they were written.
+    /// - If `lowercase_names` is set to `true`, the names of the HTML tags and
+    ///   of their attributes are converted to ASCII lowercase, so they can be
+    ///   compared without ignoring the case. The names in foreign content
+    ///   (inside `<svg>` or `<math>`) are kept, as they are case-sensitive,
+    ///   like `viewBox`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().lowercase_names(true);
+    /// let (tree, _) = Html::parse_with_warnings("<DIV ID='a'>Text</Div>", &options).unwrap();
+    /// assert!(tree.to_string() == "<div id='a'>Text</div>");
+    /// ```
+    #[must_use]
+    pub const fn lowercase_names(mut self, lowercase_names: bool) -> Self {
+        self.lowercase_names = lowercase_names;
+        self
+    }
+
+    /// Sets the maximum length of an attribute value, in bytes
+    ///
+    /// By default, there is no limit. If a value is longer, the parser returns
+    /// [`ParseError::LimitExceeded`](crate::prelude::ParseError::LimitExceeded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().max_attribute_value_len(4);
+    /// assert!(Html::parse_with_warnings("<a href='/abc'>", &options).is_ok());
+    /// assert!(Html::parse_with_warnings("<a href='/abcd'>", &options).is_err());
+    /// ```
+    #[must_use]
+    pub const fn max_attribute_value_len(mut self, max: usize) -> Self {
+        self.max_attribute_value_len = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of attributes in a tag
+    ///
+    /// By default, there is no limit. If a tag has more attributes, the parser
+    /// returns
+    /// [`ParseError::LimitExceeded`](crate::prelude::ParseError::LimitExceeded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().max_attributes_per_tag(1);
+    /// assert!(Html::parse_with_warnings("<p id='a'>", &options).is_ok());
+    /// assert!(Html::parse_with_warnings("<p id='a' hidden>", &options).is_err());
+    /// ```
+    #[must_use]
+    pub const fn max_attributes_per_tag(mut self, max: usize) -> Self {
+        self.max_attributes_per_tag = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of nested tags
+    ///
+    /// By default, there is no limit. If a tag is opened inside that many
+    /// opened tags, the parser returns
+    /// [`ParseError::LimitExceeded`](crate::prelude::ParseError::LimitExceeded).
+    /// Set it when parsing untrusted inputs: this bounds the recursion depth of
+    /// the functions that walk the tree, so deeply nested inputs can't
+    /// overflow the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().max_depth(2);
+    /// assert!(Html::parse_with_warnings("<p><b>a</b></p><p>b</p>", &options).is_ok());
+    /// assert!(Html::parse_with_warnings("<p><b><i>a</i></b></p>", &options).is_err());
+    ///
+    /// assert!(Html::parse(&"<b>".repeat(1000)).is_ok());
+    /// ```
+    #[must_use]
+    pub const fn max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of nodes in the tree
+    ///
+    /// The nodes are the tags, texts, comments and doctypes. By default, there
+    /// is no limit. If the input has more nodes, the parser returns
+    /// [`ParseError::LimitExceeded`](crate::prelude::ParseError::LimitExceeded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().max_total_nodes(2);
+    /// assert!(Html::parse_with_warnings("<p>text</p>", &options).is_ok());
+    /// assert!(Html::parse_with_warnings("<p>text</p><br>", &options).is_err());
+    /// ```
+    #[must_use]
+    pub const fn max_total_nodes(mut self, max: usize) -> Self {
+        self.max_total_nodes = Some(max);
+        self
+    }
+
+    /// Creates a default [`ParseOptions`]
+    ///
+    /// The default options are the ones used by
+    /// [`Html::parse`](crate::prelude::Html::parse).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the table normalisation
+    ///
+    /// Whatever this option, the table parts are implicitly closed as in
+    /// browsers: a `<td>` or `<th>` closes the open cell of the row, a `<tr>`
+    /// closes the open row, and a `<thead>`, `<tbody>` or `<tfoot>` closes
+    /// the open section.
+    ///
+    /// - If `normalize_tables` is set to `false` (default), the rows are kept
+    ///   where they were written.
+    /// - If `normalize_tables` is set to `true`, the rows written directly in a
+    ///   `<table>` are wrapped in an implicit `<tbody>`, as in the DOM built by
+    ///   browsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<table><tr><td>a<td>b</tr></table>";
+    /// let tree = Html::parse(html).unwrap();
+    /// assert!(tree.to_string() == "<table><tr><td>a</td><td>b</td></tr></table>");
+    ///
+    /// let options = ParseOptions::new().normalize_tables(true);
+    /// let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == "<table><tbody><tr><td>a</td><td>b</td></tr></tbody></table>");
+    /// ```
+    #[must_use]
+    pub const fn normalize_tables(mut self, normalize_tables: bool) -> Self {
+        self.normalize_tables = normalize_tables;
+        self
+    }
+
+    /// Sets the handling of the misnested closing tags
+    ///
+    /// A closing tag is misnested when it matches an opened tag that isn't
+    /// the innermost one, like `</div>` in `<div><span></div>`. By default,
+    /// the tags opened inside the matching one are closed with it
+    /// ([`MismatchPolicy::CloseAncestor`]). In the modes other than
+    /// [`MismatchPolicy::Error`], a
+    /// [`ParseWarning::MisnestedClosingTag`](super::warning::ParseWarning::MisnestedClosingTag)
+    /// is reported.
+    ///
+    /// This doesn't change the handling of the closing tags that don't match
+    /// any opened tag, that depends on [`ParseOptions::lenient`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<div><span>a</div>b";
+    /// let (tree, warnings) = Html::parse_with_warnings(html, &ParseOptions::new()).unwrap();
+    /// assert!(tree.to_string() == "<div><span>a</span></div>b");
+    /// assert!(warnings.len() == 1);
+    ///
+    /// let options = ParseOptions::new().on_mismatch(MismatchPolicy::Ignore);
+    /// let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == "<div><span>ab</span></div>");
+    ///
+    /// let options = ParseOptions::new().on_mismatch(MismatchPolicy::Error);
+    /// assert!(Html::parse_with_warnings(html, &options).is_err());
+    /// ```
+    #[must_use]
+    pub const fn on_mismatch(mut self, policy: MismatchPolicy) -> Self {
+        self.mismatch_policy = policy;
+        self
+    }
+
+    /// Sets the parsing of conditional comments
+    ///
+    /// Conditional comments are used by legacy pages to embed markup for
+    /// old versions of Internet Explorer, like in
+    /// `<!--[if IE]><link rel='stylesheet' href='ie.css'><![endif]-->`.
+    ///
+    /// - If `parse_conditional_comments` is set to `false` (default), they are
+    ///   read as ordinary comments.
+    /// - If `parse_conditional_comments` is set to `true`, the `<!--[if ...]>`
+    ///   and `<![endif]-->` markers are kept as
+    ///   [`Html::Conditional`](crate::prelude::Html::Conditional) nodes, and
+    ///   the markup between them is parsed as if it wasn't in a comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<head><!--[if IE]><script src='ie.js'></script><![endif]--></head>";
+    /// let tree = Html::parse(html).unwrap();
+    /// assert!(tree.to_string() == html);
+    ///
+    /// let options = ParseOptions::new().parse_conditional_comments(true);
+    /// let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == html);
+    /// assert!(
+    ///     tree.find(&Filter::new().tag_name("script")).to_string() == "<script src='ie.js'></script>"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn parse_conditional_comments(mut self, parse_conditional_comments: bool) -> Self {
+        self.conditional_comments = parse_conditional_comments;
+        self
+    }
+
+    /// Sets the source-preserving mode
+    ///
+    /// - If `preserve_source` is set to `false` (default), the tags are
+    ///   normalised when displayed: quotes are kept but spaces are normalised,
+    ///   self-closing tags are displayed as `<div></div>`, etc.
+    /// - If `preserve_source` is set to `true`, the original source of the
+    ///   tags, comments and doctypes is stored, and the
+    ///   [`Display`](core::fmt::Display) implementation outputs it, so the
+    ///   displayed tree is identical to the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<!DOCTYPE  html><div  id='a' ><br/></div >";
+    /// let options = ParseOptions::new().preserve_source(true);
+    /// let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    /// assert!(tree.to_string() == html);
+    ///
+    /// let tree = Html::parse(html).unwrap();
+    /// assert!(tree.to_string() == "<!DOCTYPE html><div id='a'><br></div>");
+    /// ```
+    #[must_use]
+    pub const fn preserve_source(mut self, preserve_source: bool) -> Self {
+        self.preserve_source = preserve_source;
+        self
+    }
+
+    /// Sets the splitting of adjacent texts into separate nodes
+    ///
+    /// - If `split_text_runs` is set to `false` (default), consecutive texts
+    ///   are merged into a single [`Html::Text`](crate::prelude::Html::Text)
+    ///   node.
+    /// - If `split_text_runs` is set to `true`, every contiguous run of text in
+    ///   the input is kept as a separate node. Character references are runs of
+    ///   their own, and texts separated by an ignored token (like an unmatched
+    ///   closing tag in lenient mode) aren't merged either.
+    ///
+    /// This is useful to map the nodes back to their spans in the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new().split_text_runs(true);
+    /// let (tree, _) = Html::parse_with_warnings("<p>a &lt; b</p>", &options).unwrap();
+    /// assert!(tree.children().count() == 3);
+    /// assert!(tree.to_string() == "<p>a &lt; b</p>");
+    /// ```
+    #[must_use]
+    pub const fn split_text_runs(mut self, split_text_runs: bool) -> Self {
+        self.split_text_runs = split_text_runs;
+        self
+    }
+
+    /// Sets the XML self-closing mode
+    ///
+    /// - If `xml_self_closing` is set to `false` (default), the HTML5 rules
+    ///   apply: the self-closing slash is ignored on non-void HTML elements, so
+    ///   `<div/>` is an opened `<div>`. It is honoured on void elements (like
+    ///   `<br/>`) and on foreign elements (inside `<svg>` or `<math>`).
+    /// - If `xml_self_closing` is set to `true`, the XML rules apply and the
+    ///   slash closes all the tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = "<div/>text</div>";
+    /// let tree = Html::parse(html).unwrap();
+    /// assert!(tree.to_string() == "<div>text</div>");
+    ///
+    /// let options = ParseOptions::new().xml_self_closing(true);
+    /// assert!(Html::parse_with_warnings(html, &options).is_err());
+    /// ```
+    #[must_use]
+    pub const fn xml_self_closing(mut self, xml_self_closing: bool) -> Self {
+        self.xml_self_closing = xml_self_closing;
+        self
+    }
+}
@@ -3,16 +3,21 @@
 //! This module is used when a <d is found in a html string. It can also mean an
 //! opening comment.
 
+use alloc::string::{String, ToString as _};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use core::mem::take;
 use core::str::Chars;
 
-use super::AUTO_CLOSING_TAGS;
-use crate::errors::safe_expect;
-use crate::types::tag::{Attribute, Tag, TagBuilder};
+use super::error::ParseError;
+use super::{AUTO_CLOSING_TAGS, is_tag_name_char};
+use crate::errors::{safe_expect, safe_unreachable};
+use crate::types::tag::{Attribute, Namespace, Tag, TagLexeme};
 
 /// State that informs on position of the '/' closing character.
 ///
 /// This is relatively to the name of the tag.
+#[derive(Clone, Copy)]
 enum Close {
     /// The '/' was found after the name.
     ///
@@ -32,14 +37,17 @@ enum Close {
     None,
 }
 
-impl TagBuilder {
+impl TagLexeme {
     /// Parses an opening tag, or an opening comment.
     ///
     /// # Returns
     ///
-    /// A [`TagBuilder`] that indicates the type of the tag/comment that was
+    /// A [`TagLexeme`] that indicates the type of the tag/comment that was
     /// found.
-    pub fn parse(chars: &mut Chars<'_>) -> Result<Self, String> {
+    ///
+    /// The `namespace` is the one of the parent of the tag. See
+    /// [`is_tag_name_char`] for the characters allowed in the names.
+    pub fn parse(chars: &mut Chars<'_>, namespace: Namespace) -> Result<Self, ParseError> {
         let mut state = TagParsingState::default();
         let mut close = Close::None;
         let mut bang = false;
@@ -53,33 +61,50 @@ impl TagBuilder {
                 (TagParsingState::Name, '-') if bang => dash = true,
                 _ if dash => return invalid_err('-', "doctype"),
                 // closing
-                (TagParsingState::Name | TagParsingState::AttributeNone, '>') =>
-                    return Self::return_tag(bang, close, name, attrs),
+                (
+                    TagParsingState::Name
+                    | TagParsingState::AttributeNone
+                    | TagParsingState::AttributeAfterName,
+                    '>',
+                ) => return Self::return_tag(bang, close, name, attrs, namespace),
                 (TagParsingState::AttributeName(attr), '>') => {
                     attrs.push(Attribute::from(take(attr)));
-                    return Self::return_tag(bang, close, name, attrs);
+                    return Self::return_tag(bang, close, name, attrs, namespace);
                 }
                 (TagParsingState::Name, '/') if name.is_empty() => close = Close::Before,
-                (TagParsingState::Name | TagParsingState::AttributeNone, '/') =>
+                (TagParsingState::Name | TagParsingState::AttributeNone, '/')
+                    if !matches!(close, Close::Before) =>
                     close = Close::After,
-                (TagParsingState::AttributeName(attr), '/') => {
+                (TagParsingState::AttributeAfterName, '/') if !matches!(close, Close::Before) => {
+                    state = TagParsingState::AttributeNone;
+                    close = Close::After;
+                }
+                (TagParsingState::AttributeName(attr), '/') if !matches!(close, Close::Before) => {
                     attrs.push(Attribute::from(take(attr)));
+                    state = TagParsingState::AttributeNone;
                     close = Close::After;
                 }
                 // name
-                (TagParsingState::Name, '!') =>
-                    if name.is_empty() {
-                        bang = true;
-                    } else {
-                        return invalid_err(ch, "tag name");
-                    },
-                (TagParsingState::Name, ':') => return invalid_err(ch, "tag name"),
+                (TagParsingState::Name, '!') if name.is_empty() => bang = true,
                 (TagParsingState::Name, _) if ch.is_whitespace() =>
                     state = TagParsingState::AttributeNone,
-                (TagParsingState::Name, _) => name.push(ch),
-                // attribute none: none in progress
-                (TagParsingState::AttributeNone, _) if ch.is_whitespace() => (),
-                (TagParsingState::AttributeNone, _) =>
+                (TagParsingState::Name, _) if is_tag_name_char(ch, name.is_empty()) =>
+                    name.push(ch),
+                (TagParsingState::Name, _) => return invalid_err(ch, "tag name"),
+                // internal subset of a doctype
+                (TagParsingState::AttributeNone | TagParsingState::AttributeAfterName, '[')
+                    if bang =>
+                    return Self::return_doctype(close, name, attrs, Some(read_subset(chars)?)),
+                // attribute none: none in progress, or whitespace after a name or `=`
+                (
+                    TagParsingState::AttributeNone
+                    | TagParsingState::AttributeAfterName
+                    | TagParsingState::AttributeEq,
+                    _,
+                ) if ch.is_whitespace() => (),
+                (TagParsingState::AttributeAfterName, '=') => state = TagParsingState::AttributeEq,
+                (TagParsingState::AttributeNone, '=') => return invalid_err(ch, "attribute name"),
+                (TagParsingState::AttributeNone | TagParsingState::AttributeAfterName, _) =>
                     state = TagParsingState::AttributeName(ch.to_string()),
                 // attribute name
                 (TagParsingState::AttributeName(attr), '=') => {
@@ -88,30 +113,22 @@ impl TagBuilder {
                 }
                 (TagParsingState::AttributeName(attr), _) if ch.is_whitespace() => {
                     attrs.push(Attribute::from(take(attr)));
-                    state = TagParsingState::AttributeNone;
+                    state = TagParsingState::AttributeAfterName;
                 }
                 (TagParsingState::AttributeName(attr), _) => attr.push(ch),
                 // attribute after `=`
-                (TagParsingState::AttributeEq, '"') => {
-                    state = TagParsingState::AttributeDouble;
-                    safe_expect!(
-                        attrs.last_mut(),
-                        "Not AttributeNone so last exists at double quote creation."
-                    )
-                    .add_value(true);
-                }
-                (TagParsingState::AttributeEq, '\'') => {
-                    state = TagParsingState::AttributeSingle;
-                    safe_expect!(
-                        attrs.last_mut(),
-                        "Not AttributeNone so last exists at single quote creation."
-                    )
-                    .add_value(false);
+                (TagParsingState::AttributeEq, '"' | '\'') => {
+                    let double = ch == '"';
+                    state = if double {
+                        TagParsingState::AttributeDouble
+                    } else {
+                        TagParsingState::AttributeSingle
+                    };
+                    safe_expect!(attrs.last_mut(), "Not AttributeNone so last exists at quote.")
+                        .add_value(double);
                 }
                 (TagParsingState::AttributeEq, _) =>
-                    return Err(format!(
-                        "Invalid character '{ch}': expected '\'' or '\"' after '=' sign."
-                    )),
+                    return Err(ParseError::MissingAttributeQuote { character: ch }),
                 // attribute value
                 (TagParsingState::AttributeSingle, '\'')
                 | (TagParsingState::AttributeDouble, '\"') => {
@@ -122,49 +139,135 @@ impl TagBuilder {
                         .push_value(ch),
             }
         }
-        Err("EOF: Missing closing '>'.".to_owned())
+        match state {
+            TagParsingState::AttributeDouble | TagParsingState::AttributeSingle =>
+                match Self::return_tag(bang, close, name, attrs, namespace)? {
+                    Self::Open(tag) | Self::OpenClose(tag) => Ok(Self::UnterminatedAttribute(tag)),
+                    Self::Close(_)
+                    | Self::Doctype { .. }
+                    | Self::OpenComment
+                    | Self::UnterminatedAttribute(_) =>
+                        safe_unreachable("Closing tags and doctypes can't have values."),
+                },
+            TagParsingState::AttributeAfterName
+            | TagParsingState::AttributeEq
+            | TagParsingState::AttributeName(_)
+            | TagParsingState::AttributeNone
+            | TagParsingState::Name => Err(ParseError::UnclosedTag),
+        }
     }
 
-    /// Builds a [`TagBuilder`] with the parsing information from
-    /// [`TagBuilder::parse`].
+    /// Builds a [`TagLexeme::Doctype`] with the parsing information from
+    /// [`TagLexeme::parse`].
+    fn return_doctype(
+        close: Close,
+        name: String,
+        mut attrs: Vec<Attribute>,
+        subset: Option<String>,
+    ) -> Result<Self, ParseError> {
+        match close {
+            Close::After => return invalid_err('/', "doctype"),
+            Close::Before => return invalid_err('!', "closing tag"),
+            Close::None => (),
+        }
+        if attrs.len() >= 2 {
+            return Err(ParseError::DoctypeTooManyAttributes);
+        }
+        let attr = match attrs.pop() {
+            Some(Attribute::NameNoValue(prefix_name)) => Some(prefix_name),
+            Some(Attribute::NameValue { .. }) => return Err(ParseError::DoctypeAttributeValue),
+            None => None,
+        };
+        Ok(Self::Doctype { name, attr, subset })
+    }
+
+    /// Builds a [`TagLexeme`] with the parsing information from
+    /// [`TagLexeme::parse`].
     fn return_tag(
         doctype: bool,
         close: Close,
         name: String,
-        mut attrs: Vec<Attribute>,
-    ) -> Result<Self, String> {
-        Ok(match (doctype, close) {
-            (true, Close::After) => return invalid_err('/', "doctype"),
-            (true, Close::Before) => return invalid_err('!', "closing tag"),
-            (true, Close::None) => {
-                if attrs.len() >= 2 {
-                    return Err("Doctype expected at most one attribute.".to_owned());
-                }
-                let attr = if let Some(attr) = attrs.pop() {
-                    match attr {
-                        Attribute::NameNoValue(prefix_name) => Some(prefix_name),
-                        Attribute::NameValue { .. } =>
-                            return Err("Doctype attribute must not have a value.".to_owned()),
-                    }
-                } else {
-                    None
-                };
-                Self::Doctype { name, attr }
-            }
-            (false, Close::None) if AUTO_CLOSING_TAGS.contains(&name.as_str()) =>
-                Self::OpenClose(Tag::from((name, attrs.into_boxed_slice()))),
-            (false, Close::None) => Self::Open(Tag::from((name, attrs.into_boxed_slice()))),
-            (false, Close::Before) => {
+        attrs: Vec<Attribute>,
+        parent: Namespace,
+    ) -> Result<Self, ParseError> {
+        if doctype {
+            return Self::return_doctype(close, name, attrs, None);
+        }
+        let namespace = Namespace::from_parent(&name, parent);
+        Ok(match close {
+            Close::None if AUTO_CLOSING_TAGS.contains(&name.as_str()) =>
+                Self::OpenClose(Tag::from((name, attrs.into_boxed_slice(), namespace))),
+            Close::None => Self::Open(Tag::from((name, attrs.into_boxed_slice(), namespace))),
+            Close::Before => {
                 if !attrs.is_empty() {
-                    return Err("Closing tags don't support attributes.".to_owned());
+                    return Err(ParseError::ClosingTagAttributes);
                 }
                 Self::Close(name)
             }
-            (false, Close::After) => Self::OpenClose(Tag::from((name, attrs.into_boxed_slice()))),
+            Close::After => Self::OpenClose(Tag::from((name, attrs.into_boxed_slice(), namespace))),
         })
     }
 }
 
+impl Tag {
+    /// Parses a list of attributes, as written in an opening tag
+    ///
+    /// The attributes are read as by
+    /// [`Html::parse`](crate::prelude::Html::parse): the values are between
+    /// single or double quotes, the attributes without values are kept, and
+    /// the whitespace around the names and the `=` is ignored. The
+    /// character references aren't decoded.
+    ///
+    /// This is useful to check or format an attribute string without parsing
+    /// a whole tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list isn't a valid list of attributes, for
+    /// instance if a value isn't quoted or isn't terminated, or if the list
+    /// contains a `>` or a `/` outside of the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let attrs = Tag::parse_attributes(r#" id = "a" hidden class='b c'"#).unwrap();
+    /// let tag = Tag::builder("div")
+    ///     .attr("id", "a")
+    ///     .bool_attr("hidden")
+    ///     .class("b")
+    ///     .class("c")
+    ///     .build();
+    /// assert!(attrs.len() == 3);
+    /// assert!(
+    ///     attrs.iter().map(ToString::to_string).collect::<String>()
+    ///         == r#" id="a" hidden class='b c'"#
+    /// );
+    /// assert!(attrs[0] == tag.as_attrs()[0]);
+    ///
+    /// assert!(Tag::parse_attributes("id=a").is_err());
+    /// ```
+    pub fn parse_attributes(input: &str) -> Result<Vec<Attribute>, ParseError> {
+        let source = format!("div {input}>");
+        let mut chars = source.chars();
+        match TagLexeme::parse(&mut chars, Namespace::Html)? {
+            TagLexeme::Open(tag) if chars.as_str().is_empty() => Ok(tag.into_attrs().into_vec()),
+            TagLexeme::Open(_) => invalid_err('>', "attribute list"),
+            TagLexeme::OpenClose(_) => invalid_err('/', "attribute list"),
+            TagLexeme::UnterminatedAttribute(tag) => Err(ParseError::UnterminatedAttribute {
+                name: tag
+                    .as_attrs()
+                    .last()
+                    .map(|attr| attr.as_name().clone())
+                    .unwrap_or_default(),
+            }),
+            TagLexeme::Close(_) | TagLexeme::Doctype { .. } | TagLexeme::OpenComment =>
+                safe_unreachable("The input starts with a tag name."),
+        }
+    }
+}
+
 /// State of the parsing for the tag.
 ///
 /// The elements of this enum are ordered in chronological order, from reading
@@ -188,9 +291,16 @@ enum TagParsingState {
     /// Waiting for character to continue the name, the end of the tag or a `=`
     /// sign to assign a value to this attribute.
     AttributeName(String),
+    /// Parser read whitespace after the name of an attribute.
+    ///
+    /// Waiting for a `=` sign to assign a value to this attribute, another
+    /// attribute name, or the end of the tag. In the latter cases, the
+    /// attribute doesn't have a value.
+    AttributeAfterName,
     /// Parser read the `=` sign after an attribute name.
     ///
     /// Waiting for a `'` or `"` to assign a value to the last attribute.
+    /// Whitespace before the quote is ignored.
     AttributeEq,
     /// Parser currently reading the value of an attribute.
     ///
@@ -203,6 +313,33 @@ enum TagParsingState {
 }
 
 /// Function to format the errors for an invalid character in a given context.
-fn invalid_err<T>(ch: char, ctx: &str) -> Result<T, String> {
-    Err(format!("Invalid character '{ch}' in {ctx}."))
+const fn invalid_err<T>(ch: char, ctx: &'static str) -> Result<T, ParseError> {
+    Err(ParseError::InvalidCharacter { character: ch, context: ctx })
+}
+
+/// Reads the internal subset of a doctype, after its opening `[`.
+///
+/// The subset ends at the first `]` that isn't between quotes, and must be
+/// followed by the `>` closing the doctype, after optional whitespace.
+fn read_subset(chars: &mut Chars<'_>) -> Result<String, ParseError> {
+    let mut subset = String::new();
+    let mut quote = None;
+    let mut closed = false;
+    while let Some(ch) = chars.next() {
+        match quote {
+            None if ch == ']' => {
+                closed = true;
+                break;
+            }
+            None if matches!(ch, '"' | '\'') => quote = Some(ch),
+            Some(opened) if opened == ch => quote = None,
+            None | Some(_) => (),
+        }
+        subset.push(ch);
+    }
+    match chars.find(|next| !next.is_whitespace()) {
+        Some('>') if closed => Ok(subset),
+        Some(next) if closed => invalid_err(next, "doctype"),
+        Some(_) | None => Err(ParseError::UnclosedTag),
+    }
 }
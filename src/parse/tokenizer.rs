@@ -0,0 +1,462 @@
+//! Module that splits an HTML string into tokens.
+//!
+//! The [`Tokenizer`] is the layer beneath
+//! [`Html::parse`](crate::prelude::Html::parse): the parser builds
+//! the tree from the tokens, but the tokens can also be used directly, without
+//! building any tree.
+
+use alloc::borrow::ToOwned as _;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::Chars;
+
+use super::error::ParseError;
+use super::{is_self_closable, is_tag_name_char, is_text_only};
+use crate::errors::safe_unreachable;
+use crate::types::tag::{Namespace, Tag, TagLexeme};
+
+/// Element of an HTML string, as read by the parser.
+pub enum Lexeme {
+    /// Bogus comment, like `<!foo>`, with its content
+    BogusComment(String),
+    /// Comment, with its content and whether the closing `-->` was found
+    Comment {
+        /// Content of the comment
+        content: String,
+        /// Indicates if the closing `-->` was found
+        full: bool,
+    },
+    /// Marker of a parsed conditional comment, with the condition of the
+    /// opening marker
+    ///
+    /// See [`Html::Conditional`](crate::prelude::Html::Conditional).
+    Conditional(Option<String>),
+    /// Tag, closing tag or doctype
+    ///
+    /// This is never a [`TagLexeme::OpenComment`], as comments are read
+    /// entirely by the tokenizer.
+    Tag(TagLexeme),
+    /// Raw text
+    Text(String),
+}
+
+/// Token of an HTML string
+///
+/// See [`Tokenizer`] for more information.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// Attribute of the last [`Token::TagOpen`]
+    ///
+    /// # Examples
+    ///
+    /// In `<a href="link" enabled>`, `href` has the value `link` and `enabled`
+    /// has no value.
+    #[non_exhaustive]
+    Attribute {
+        /// Name of the attribute
+        name: String,
+        /// Value of the attribute, if any
+        value: Option<String>,
+    },
+    /// Comment block, with its content
+    ///
+    /// # Examples
+    ///
+    /// `<!-- some comment -->` has the content ` some comment `.
+    Comment(String),
+    /// Document tag. See [`Html::Doctype`](crate::prelude::Html::Doctype).
+    #[non_exhaustive]
+    Doctype {
+        /// Name of the tag
+        name: String,
+        /// Attribute of the tag
+        attr: Option<String>,
+        /// Internal subset of the tag, if any
+        subset: Option<String>,
+    },
+    /// Closing tag, with its name
+    ///
+    /// # Examples
+    ///
+    /// `</div>`
+    TagClose(String),
+    /// Opening tag
+    ///
+    /// The attributes of the tag are the [`Token::Attribute`]s that follow.
+    ///
+    /// # Examples
+    ///
+    /// `<div id="blob">` and `<br/>`
+    #[non_exhaustive]
+    TagOpen {
+        /// Name of the tag
+        name: String,
+        /// Indicates if the tag is self-closing
+        ///
+        /// This is the case with a trailing slash, like in `<br/>`, or for
+        /// tags that are always empty, like `<br>`. As in
+        /// [`Html::parse`](crate::prelude::Html::parse), the trailing slash
+        /// is only honoured on void elements and in `<svg>` and `<math>`, so
+        /// `<div/>` opens a `<div>`.
+        self_closing: bool,
+    },
+    /// Raw text
+    Text(String),
+}
+
+/// Iterator over the tokens of an HTML string
+///
+/// The tokenizer reads the string lazily and yields one [`Token`] at a time,
+/// without building any tree. The closing tags are not checked, so the input
+/// doesn't need to be well-formed. The iteration stops after the first error.
+///
+/// The content of `<script>` and `<style>` tags is returned as raw text.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let tokens = Tokenizer::new("<p class='a'>Hi<!--x--></p>")
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert!(matches!(&tokens[0], Token::TagOpen { name, self_closing: false, .. } if name == "p"));
+/// assert!(matches!(&tokens[1], Token::Attribute { name, .. } if name == "class"));
+/// assert!(tokens[2] == Token::Text("Hi".to_owned()));
+/// assert!(tokens[3] == Token::Comment("x".to_owned()));
+/// assert!(tokens[4] == Token::TagClose("p".to_owned()));
+/// assert!(tokens.len() == 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'input> {
+    /// Remaining characters of the input
+    chars: Chars<'input>,
+    /// Number of conditional comments opened and not closed yet
+    ///
+    /// This is only used when the conditional comments are parsed, see
+    /// [`ParseOptions::parse_conditional_comments`](crate::prelude::ParseOptions::parse_conditional_comments).
+    conditionals: Option<usize>,
+    /// End offset of the last lexeme
+    end: usize,
+    /// Input string
+    input: &'input str,
+    /// Opened tags, with the namespace of their children
+    ///
+    /// This is only used by the public [`Iterator`] implementation, as the
+    /// parser knows the namespace from the tree it builds.
+    opened: Vec<(String, Namespace)>,
+    /// Tokens already read but not yet returned
+    pending: VecDeque<Token>,
+    /// Name of the raw text element being read, like `script`
+    raw: Option<String>,
+    /// Start offset of the last lexeme
+    start: usize,
+}
+
+impl<'input> Tokenizer<'input> {
+    /// Skips `len` bytes of the input.
+    fn advance(&mut self, len: usize) {
+        self.chars = self.chars.as_str().get(len..).unwrap_or_default().chars();
+    }
+
+    /// Reads a bogus comment, like `<!foo>`, after the opening `<!`.
+    ///
+    /// The content of the comment is the rest of the tag, until the first
+    /// `>`, or the end of the input.
+    fn bogus_comment(&mut self) -> Lexeme {
+        let rest = self.chars.as_str();
+        let (content_str, len) = rest.find('>').map_or_else(
+            || (rest, rest.len()),
+            #[expect(clippy::arithmetic_side_effects, reason = "idx + 1 <= rest.len()")]
+            |idx| (rest.get(..idx).unwrap_or_default(), idx + 1),
+        );
+        let content = content_str.to_owned();
+        self.advance(len);
+        Lexeme::BogusComment(content)
+    }
+
+    /// Reads the content of a comment, after the opening `<!--`.
+    fn comment(&mut self) -> Lexeme {
+        let rest = self.chars.as_str();
+        let (content_str, full, len) = rest.find("-->").map_or_else(
+            || (rest, false, rest.len()),
+            #[expect(clippy::arithmetic_side_effects, reason = "idx + 3 <= rest.len()")]
+            |idx| (rest.get(..idx).unwrap_or_default(), true, idx + 3),
+        );
+        let content = content_str.to_owned();
+        self.advance(len);
+        Lexeme::Comment { content, full }
+    }
+
+    /// Returns the length and the condition of the conditional comment
+    /// marker at the start of the input, if any, and updates the number of
+    /// opened conditionals.
+    ///
+    /// The markers are the opening `<!--[if ...]>`, with its condition, and the
+    /// closing `<![endif]-->`, without condition. They are only recognised if
+    /// the conditional comments are parsed.
+    fn conditional_marker(&mut self, input: &str) -> Option<(usize, Option<String>)> {
+        let opened = self.conditionals.as_mut()?;
+        if let Some(rest) = input.strip_prefix("<!--[if ") {
+            let end = rest.find("]>")?;
+            *opened = opened.saturating_add(1);
+            let condition = rest.get(..end).unwrap_or_default().to_owned();
+            #[expect(clippy::arithmetic_side_effects, reason = "the marker is in the input")]
+            Some(("<!--[if ".len() + end + "]>".len(), Some(condition)))
+        } else if *opened > 0 && input.starts_with("<![endif]-->") {
+            *opened = opened.saturating_sub(1);
+            Some(("<![endif]-->".len(), None))
+        } else {
+            None
+        }
+    }
+
+    /// Reads the next lexeme, with the `namespace` in which the next tag will
+    /// be.
+    pub(crate) fn lex(&mut self, namespace: Namespace) -> Option<Result<Lexeme, ParseError>> {
+        self.start = self.offset();
+        let rest = self.chars.as_str();
+        if rest.is_empty() {
+            return None;
+        }
+        let lexeme = if let Some(raw) = &self.raw {
+            let len = raw_text_len(rest, raw);
+            self.raw = None;
+            let text = rest.get(..len).unwrap_or_default().to_owned();
+            self.advance(len);
+            if text.is_empty() {
+                return self.lex(namespace);
+            }
+            Ok(Lexeme::Text(text))
+        } else if let Some((len, condition)) = self.conditional_marker(rest) {
+            self.advance(len);
+            Ok(Lexeme::Conditional(condition))
+        } else if rest.starts_with("-->") {
+            Err(ParseError::UnopenedComment)
+        } else if is_bogus_comment(rest) {
+            self.advance(2);
+            Ok(self.bogus_comment())
+        } else if is_stray_open(rest) {
+            let len = text_len(rest.get(1..).unwrap_or_default()).saturating_add(1);
+            let text = rest.get(..len).unwrap_or_default().to_owned();
+            self.advance(len);
+            Ok(Lexeme::Text(text))
+        } else if rest.starts_with('<') {
+            self.chars.next();
+            match TagLexeme::parse(&mut self.chars, namespace) {
+                Ok(TagLexeme::OpenComment) => Ok(self.comment()),
+                Ok(tag_lexeme) => Ok(Lexeme::Tag(tag_lexeme)),
+                Err(err) => Err(err),
+            }
+        } else {
+            let len = text_len(rest);
+            let text = rest.get(..len).unwrap_or_default().to_owned();
+            self.advance(len);
+            Ok(Lexeme::Text(text))
+        };
+        if lexeme.is_err() {
+            self.chars = "".chars();
+        }
+        self.end = self.offset();
+        Some(lexeme)
+    }
+
+    /// Creates a tokenizer on an HTML string
+    #[must_use]
+    pub fn new(input: &'input str) -> Self {
+        Self {
+            chars: input.chars(),
+            conditionals: None,
+            end: 0,
+            input,
+            opened: Vec::new(),
+            pending: VecDeque::new(),
+            raw: None,
+            start: 0,
+        }
+    }
+
+    /// Returns the offset of the remaining characters in the input.
+    fn offset(&self) -> usize {
+        self.input.len().saturating_sub(self.chars.as_str().len())
+    }
+
+    /// Converts a tag into a [`Token::TagOpen`], and stores its attributes to
+    /// be returned next.
+    fn open_tag(&mut self, tag: &Tag, self_closing: bool) -> Token {
+        self.pending
+            .extend(tag.as_attrs().iter().map(|attr| Token::Attribute {
+                name: attr.as_name().to_owned(),
+                value: attr.as_value().cloned(),
+            }));
+        Token::TagOpen { name: tag.as_name().to_owned(), self_closing }
+    }
+
+    /// Parses the content of the conditional comments, instead of reading
+    /// them as comments.
+    ///
+    /// See [`ParseOptions::parse_conditional_comments`](crate::prelude::ParseOptions::parse_conditional_comments).
+    pub(crate) const fn parse_conditional_comments(&mut self) {
+        self.conditionals = Some(0);
+    }
+
+    /// Returns the start and end offsets of the last lexeme.
+    pub(crate) const fn span(&self) -> (usize, usize) {
+        (self.start, self.end)
+    }
+
+    /// Reads the content of the tag `name` as raw text, until its closing tag.
+    ///
+    /// This is used for the tags whose content is text, like `<script>` or
+    /// `<title>`.
+    pub(crate) fn start_raw_text(&mut self, name: &str) {
+        self.raw = Some(name.to_owned());
+    }
+
+    /// Updates the opened tags after reading a tag, for the public API.
+    fn update_opened(&mut self, lexeme: &TagLexeme) {
+        match lexeme {
+            TagLexeme::Open(tag) => {
+                if is_text_only(tag) {
+                    self.start_raw_text(tag.as_name());
+                }
+                self.opened
+                    .push((tag.as_name().to_owned(), tag.children_namespace()));
+            }
+            TagLexeme::Close(name) => {
+                if let Some(idx) = self.opened.iter().rposition(|(opened, _)| opened == name) {
+                    self.opened.truncate(idx);
+                }
+            }
+            TagLexeme::Doctype { .. }
+            | TagLexeme::OpenClose(_)
+            | TagLexeme::OpenComment
+            | TagLexeme::UnterminatedAttribute(_) => (),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.pending.pop_front() {
+            return Some(Ok(token));
+        }
+        let namespace = self
+            .opened
+            .last()
+            .map_or(Namespace::Html, |(_, namespace)| *namespace);
+        Some(self.lex(namespace)?.and_then(|lexeme| match lexeme {
+            Lexeme::BogusComment(content) | Lexeme::Comment { content, .. } =>
+                Ok(Token::Comment(content)),
+            Lexeme::Conditional(_) =>
+                safe_unreachable("Conditional comments are only parsed by the parser."),
+            Lexeme::Text(text) => Ok(Token::Text(text)),
+            Lexeme::Tag(raw_lexeme) => {
+                let tag_lexeme = match raw_lexeme {
+                    TagLexeme::OpenClose(tag) if !is_self_closable(&tag) => TagLexeme::Open(tag),
+                    TagLexeme::Close(_)
+                    | TagLexeme::Doctype { .. }
+                    | TagLexeme::Open(_)
+                    | TagLexeme::OpenClose(_)
+                    | TagLexeme::OpenComment
+                    | TagLexeme::UnterminatedAttribute(_) => raw_lexeme,
+                };
+                self.update_opened(&tag_lexeme);
+                match tag_lexeme {
+                    TagLexeme::Close(name) => Ok(Token::TagClose(name)),
+                    TagLexeme::Doctype { name, attr, subset } =>
+                        Ok(Token::Doctype { name, attr, subset }),
+                    TagLexeme::Open(tag) => Ok(self.open_tag(&tag, false)),
+                    TagLexeme::OpenClose(tag) => Ok(self.open_tag(&tag, true)),
+                    TagLexeme::OpenComment => safe_unreachable("Comments are read entirely."),
+                    TagLexeme::UnterminatedAttribute(tag) => {
+                        self.chars = "".chars();
+                        Err(ParseError::UnterminatedAttribute {
+                            name: tag
+                                .as_attrs()
+                                .last()
+                                .map(|attr| attr.as_name().to_owned())
+                                .unwrap_or_default(),
+                        })
+                    }
+                }
+            }
+        }))
+    }
+}
+
+/// Checks if the input starts with a bogus comment.
+///
+/// A bogus comment is a `<!` construct that is neither a comment (`<!--`) nor
+/// a doctype, like `<!foo>` or the downlevel conditional comment `<![if IE]>`.
+/// The empty `<!>` is read as an empty doctype.
+pub fn is_bogus_comment(input: &str) -> bool {
+    input.strip_prefix("<!").is_some_and(|after| {
+        let name_len = after
+            .find(|ch: char| ch.is_whitespace() || ch == '>' || ch == '/')
+            .unwrap_or(after.len());
+        let name = after.get(..name_len).unwrap_or_default();
+        !after.starts_with("--") && !after.starts_with('>') && !name.eq_ignore_ascii_case("doctype")
+    })
+}
+
+/// Checks if the input starts with a `<` that can't open a tag.
+///
+/// This is the case when the `<` is followed by whitespace, by the end of the
+/// input, or by a character that can't start a tag name, like a digit in
+/// `<1bad>`. Such a `<` is read as text, as in `a < b` or `1 <2`. The `<>`
+/// still opens a tag with an empty name.
+fn is_stray_open(input: &str) -> bool {
+    input.strip_prefix('<').is_some_and(|after| {
+        after
+            .chars()
+            .next()
+            .is_none_or(|ch| !is_tag_name_char(ch, true) && !matches!(ch, '!' | '/' | '>' | '?'))
+    })
+}
+
+/// Returns the length of the raw text before the closing tag `name`.
+///
+/// This is the length of the whole input if the closing tag isn't found.
+fn raw_text_len(input: &str, name: &str) -> usize {
+    let mut offset = 0;
+    while let Some(idx) = input.get(offset..).and_then(|rest| rest.find("</")) {
+        #[expect(clippy::arithmetic_side_effects, reason = "idx is in rest")]
+        let start = offset + idx;
+        let mut chars = input.get(start..).unwrap_or_default().chars();
+        chars.next();
+        if let Ok(TagLexeme::Close(closing)) = TagLexeme::parse(&mut chars, Namespace::Html)
+            && closing == name
+        {
+            return start;
+        }
+        #[expect(clippy::arithmetic_side_effects, reason = "start < input.len()")]
+        {
+            offset = start + 1;
+        }
+    }
+    input.len()
+}
+
+/// Returns the length of the text at the beginning of the input.
+///
+/// The text stops at the first tag or closing comment `-->`.
+fn text_len(input: &str) -> usize {
+    input
+        .char_indices()
+        .find(|(idx, ch)| {
+            *ch == '<'
+                || input
+                    .get(*idx..)
+                    .is_some_and(|rest| rest.starts_with("-->"))
+        })
+        .map_or(input.len(), |(idx, _)| idx)
+}
@@ -0,0 +1,251 @@
+//! Module to define the warnings returned by the parser.
+//!
+//! Warnings are malformed but recoverable situations. They don't prevent the
+//! parser from building a tree.
+
+use alloc::string::String;
+use core::fmt;
+
+/// Position of a character in the input string
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// Column of the character in its line, starting at 1
+    ///
+    /// The column is counted in characters, not in bytes.
+    column: usize,
+    /// Line of the character, starting at 1
+    line: usize,
+    /// Number of bytes before the character in the input string
+    offset: usize,
+}
+
+impl Position {
+    /// Computes the position of the character at the `offset`-th byte of the
+    /// input, from a position before it.
+    ///
+    /// Only the characters between the two positions are read, so the
+    /// positions of increasing offsets are computed in linear time. The
+    /// position is computed from the start of the input if the `offset` is
+    /// before `self`.
+    pub(crate) fn advance(self, input: &str, offset: usize) -> Self {
+        input.get(self.offset..offset).map_or_else(
+            || Self::from_offset(input, offset),
+            |between| {
+                let column = between.rfind('\n').map_or_else(
+                    || self.column.saturating_add(between.chars().count()),
+                    |idx| {
+                        between
+                            .get(idx.saturating_add(1)..)
+                            .map_or(0, |line| line.chars().count())
+                            .saturating_add(1)
+                    },
+                );
+                let line = self.line.saturating_add(between.matches('\n').count());
+                Self { column, line, offset }
+            },
+        )
+    }
+
+    /// Returns the column of the position, starting at 1
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Computes the position of the character at the `offset`-th byte of the
+    /// input.
+    pub(crate) fn from_offset(input: &str, offset: usize) -> Self {
+        let before = input.get(..offset).unwrap_or(input);
+        let line_start = before.rfind('\n').map_or(0, |idx| idx.saturating_add(1));
+        Self {
+            column: before
+                .get(line_start..)
+                .map_or(0, |line| line.chars().count())
+                .saturating_add(1),
+            line: before.matches('\n').count().saturating_add(1),
+            offset,
+        }
+    }
+
+    /// Returns the line of the position, starting at 1
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the number of bytes before the position in the input
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Warnings found while parsing an HTML string
+///
+/// Warnings are returned by
+/// [`Html::parse_with_warnings`](crate::prelude::Html::parse_with_warnings).
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let (_, warnings) =
+///     Html::parse_with_warnings("<a id='x' id='y'>", &ParseOptions::new()).unwrap();
+/// if let [ParseWarning::DuplicateAttribute { name, .. }] = warnings.as_slice() {
+///     assert!(name == "id");
+/// } else {
+///     unreachable!();
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// An attribute was defined twice on the same tag
+    ///
+    /// # Examples
+    ///
+    /// `<a id="x" id="y">`
+    #[non_exhaustive]
+    DuplicateAttribute {
+        /// Name of the duplicated attribute
+        name: String,
+        /// Position of the tag containing the attribute
+        position: Position,
+    },
+    /// An empty closing tag was found, but no tag with an empty name is open
+    ///
+    /// As in browsers, the tag is ignored: it doesn't close any tag. It only
+    /// closes the tags with an empty name, like `<>`.
+    ///
+    /// # Examples
+    ///
+    /// `<div></>`
+    #[non_exhaustive]
+    EmptyClosingTag {
+        /// Position of the closing tag
+        position: Position,
+    },
+    /// A self-closing slash was found on a non-void HTML element
+    ///
+    /// The slash is ignored and the tag is considered open. See
+    /// [`ParseOptions::xml_self_closing`](super::options::ParseOptions::xml_self_closing).
+    ///
+    /// # Examples
+    ///
+    /// `<div/>`
+    #[non_exhaustive]
+    IgnoredSelfClosingSlash {
+        /// Name of the tag
+        name: String,
+        /// Position of the tag
+        position: Position,
+    },
+    /// A closing tag matches an opened tag that isn't the innermost one
+    ///
+    /// The closing tag is handled as set by
+    /// [`ParseOptions::on_mismatch`](super::options::ParseOptions::on_mismatch).
+    ///
+    /// # Examples
+    ///
+    /// `<div><span></div>`
+    #[non_exhaustive]
+    MisnestedClosingTag {
+        /// Name of the closing tag
+        name: String,
+        /// Name of the innermost tag that is still open
+        open: String,
+        /// Position of the closing tag
+        position: Position,
+    },
+    /// A doctype was found after some content, or inside a tag
+    ///
+    /// Only comments and whitespace can be written before the doctype. The
+    /// doctype is still added to the tree.
+    ///
+    /// # Examples
+    ///
+    /// `<p>x</p><!doctype html>`
+    #[non_exhaustive]
+    MisplacedDoctype {
+        /// Position of the doctype
+        position: Position,
+    },
+    /// A closing tag was found but no tag of that name was open
+    ///
+    /// This is only a warning in lenient mode, the closing tag is then
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// `<div></span></div>`
+    #[non_exhaustive]
+    UnmatchedClosingTag {
+        /// Name of the closing tag
+        name: String,
+        /// Position of the closing tag
+        position: Position,
+    },
+    /// The end of the input was reached inside a quoted attribute value
+    ///
+    /// This is only a warning in lenient mode, the value read so far is then
+    /// kept and the tag is considered open.
+    ///
+    /// # Examples
+    ///
+    /// `<a href="page`
+    #[non_exhaustive]
+    UnterminatedAttribute {
+        /// Name of the attribute
+        name: String,
+        /// Position of the tag containing the attribute
+        position: Position,
+    },
+}
+
+impl ParseWarning {
+    /// Returns the position in the input where the warning was raised
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        match self {
+            Self::DuplicateAttribute { position, .. }
+            | Self::EmptyClosingTag { position }
+            | Self::IgnoredSelfClosingSlash { position, .. }
+            | Self::MisnestedClosingTag { position, .. }
+            | Self::MisplacedDoctype { position }
+            | Self::UnmatchedClosingTag { position, .. }
+            | Self::UnterminatedAttribute { position, .. } => *position,
+        }
+    }
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateAttribute { name, position } =>
+                write!(f, "Duplicate attribute '{name}' at {position}."),
+            Self::EmptyClosingTag { position } =>
+                write!(f, "Ignored empty closing tag '</>' at {position}."),
+            Self::IgnoredSelfClosingSlash { name, position } => write!(
+                f,
+                "Ignored self-closing slash on '{name}' at {position} as it isn't a void element."
+            ),
+            Self::MisnestedClosingTag { name, open, position } => write!(
+                f,
+                "Misnested closing tag for '{name}' at {position}: '{open}' is still open."
+            ),
+            Self::MisplacedDoctype { position } =>
+                write!(f, "Misplaced doctype at {position}: it must precede the content."),
+            Self::UnmatchedClosingTag { name, position } =>
+                write!(f, "Ignored closing tag for '{name}' at {position} as it isn't open."),
+            Self::UnterminatedAttribute { name, position } =>
+                write!(f, "Unterminated value for attribute '{name}' of the tag at {position}."),
+        }
+    }
+}
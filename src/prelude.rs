@@ -1,6 +1,28 @@
 //! Main types of the API to export to external users
 #![expect(clippy::pub_use, reason = "API")]
 
+pub use crate::filter::extract::{ExtractError, FromHtml, FromHtmlField, FromHtmlValue};
+pub use crate::filter::selector::{Selector, SelectorError};
 pub use crate::filter::types::Filter;
+pub use crate::from_html;
+pub use crate::json::JsonError;
+pub use crate::parse::encoding::Encoding;
+pub use crate::parse::error::ParseError;
+pub use crate::parse::options::{MismatchPolicy, ParseOptions};
+pub use crate::parse::tokenizer::{Token, Tokenizer};
+pub use crate::parse::warning::{ParseWarning, Position};
+pub use crate::types::category::DisplayCategory;
+pub use crate::types::cursor::Cursor;
+pub use crate::types::equality::EqOptions;
+pub use crate::types::events::Event;
+pub use crate::types::flat::{FlatHtml, FlatNode, NodeData, NodeId};
 pub use crate::types::html::Html;
-pub use crate::types::tag::Tag;
+pub use crate::types::output_map::OutputMap;
+pub use crate::types::patch::{ApplyError, Patch};
+pub use crate::types::pretty::PrettyDisplay;
+pub use crate::types::serialize::{QuoteStyle, SerializePolicy};
+pub use crate::types::stats::TreeStats;
+pub use crate::types::tag::{Attribute, Namespace, Tag, TagBuilder};
+pub use crate::types::tree_builder::TreeBuilder;
+pub use crate::types::validation::ValidationError;
+pub use crate::types::xhtml::XhtmlDisplay;
@@ -0,0 +1,302 @@
+//! Module to classify the HTML elements by their display category.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use super::html::Html;
+use super::tag::Tag;
+use crate::parse::WHITESPACE_SENSITIVE;
+use crate::parse::entities::decode_entities;
+
+/// HTML elements displayed as blocks by default
+const BLOCK_ELEMENTS: [&str; 44] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "legend",
+    "li",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "tfoot",
+    "thead",
+    "ul",
+];
+
+/// HTML elements that aren't displayed
+const HIDDEN_ELEMENTS: [&str; 9] = [
+    "base", "head", "link", "meta", "noscript", "script", "style", "template", "title",
+];
+
+/// Default display category of an HTML element
+///
+/// The category is read from a built-in table of the common HTML elements,
+/// and doesn't take the styles into account. The unknown elements, like the
+/// custom elements, are inline, as in browsers.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// assert!(DisplayCategory::of("div") == DisplayCategory::Block);
+/// assert!(DisplayCategory::of("SPAN") == DisplayCategory::Inline);
+/// assert!(DisplayCategory::of("my-element") == DisplayCategory::Inline);
+/// assert!(DisplayCategory::of("script") == DisplayCategory::Other);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayCategory {
+    /// Block-level element, like `<div>`, `<p>` or `<li>`
+    ///
+    /// These elements start on a new line.
+    Block,
+    /// Inline element, like `<span>`, `<a>` or `<b>`
+    ///
+    /// These elements flow with the surrounding text.
+    Inline,
+    /// Element that isn't displayed, like `<head>`, `<script>` or `<style>`
+    Other,
+}
+
+/// Text being rendered, with its pending whitespace
+///
+/// See [`Html::rendered_text`].
+#[derive(Debug, Default)]
+struct RenderedText {
+    /// Whether a whitespace was read since the last visible character
+    space: bool,
+    /// Text rendered so far
+    text: String,
+}
+
+impl RenderedText {
+    /// Starts a new line, unless the text is empty or already at the start
+    /// of a line.
+    fn block_break(&mut self) {
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+        self.space = false;
+    }
+
+    /// Writes a line break, as for a `<br>`.
+    fn line_break(&mut self) {
+        self.text.push('\n');
+        self.space = false;
+    }
+
+    /// Writes a visible character, after the pending whitespace.
+    fn push(&mut self, ch: char) {
+        if self.space && !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push(' ');
+        }
+        self.space = false;
+        self.text.push(ch);
+    }
+
+    /// Writes a node and its children.
+    ///
+    /// The whitespace is kept as it is if `preformatted`.
+    fn push_node(&mut self, node: &Html, preformatted: bool) {
+        match node {
+            Html::Text(content) =>
+                for ch in decode_entities(content, false, &BTreeMap::new()).chars() {
+                    if preformatted && ch == '\n' {
+                        self.line_break();
+                    } else if ch.is_ascii_whitespace() && !preformatted {
+                        self.space = true;
+                    } else {
+                        self.push(ch);
+                    }
+                },
+            Html::Tag { tag, child } => {
+                let name = tag.as_name().to_ascii_lowercase();
+                let child_preformatted =
+                    preformatted || WHITESPACE_SENSITIVE.contains(&name.as_str());
+                match tag.display_category() {
+                    DisplayCategory::Other => (),
+                    DisplayCategory::Inline if name == "br" => self.line_break(),
+                    DisplayCategory::Inline => self.push_node(child, child_preformatted),
+                    DisplayCategory::Block => {
+                        self.block_break();
+                        self.push_node(child, child_preformatted);
+                        self.block_break();
+                    }
+                }
+            }
+            Html::Vec(vec) =>
+                for child in vec {
+                    self.push_node(child, preformatted);
+                },
+            Html::BogusComment(_)
+            | Html::Comment(_)
+            | Html::Conditional(_)
+            | Html::Doctype { .. }
+            | Html::Empty => (),
+        }
+    }
+}
+
+impl DisplayCategory {
+    /// Finds the display category of the element with the given name
+    ///
+    /// The name is case-insensitive.
+    #[must_use]
+    pub fn of(name: &str) -> Self {
+        let lowercase = name.to_ascii_lowercase();
+        if BLOCK_ELEMENTS.contains(&lowercase.as_str()) {
+            Self::Block
+        } else if HIDDEN_ELEMENTS.contains(&lowercase.as_str()) {
+            Self::Other
+        } else {
+            Self::Inline
+        }
+    }
+}
+
+impl Tag {
+    /// Finds the display category of the tag, from its name
+    ///
+    /// See [`DisplayCategory`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Tag::builder("ul").build().display_category() == DisplayCategory::Block);
+    /// ```
+    #[must_use]
+    pub fn display_category(&self) -> DisplayCategory {
+        DisplayCategory::of(self.as_name())
+    }
+}
+
+impl Html {
+    /// Finds the display category of the node, if it is a tag.
+    fn display_category(&self) -> Option<DisplayCategory> {
+        match self {
+            Self::Tag { tag, .. } => Some(tag.display_category()),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_)
+            | Self::Vec(_) => None,
+        }
+    }
+
+    /// Checks if the node is a block-level element
+    ///
+    /// Only the tags can be block-level elements. See [`DisplayCategory`] for
+    /// more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::parse("<div>a</div>").unwrap().is_block_element());
+    /// assert!(!Html::parse("<span>a</span>").unwrap().is_block_element());
+    /// assert!(!Html::parse("text").unwrap().is_block_element());
+    /// ```
+    #[must_use]
+    pub fn is_block_element(&self) -> bool {
+        self.display_category() == Some(DisplayCategory::Block)
+    }
+
+    /// Checks if the node is an inline element
+    ///
+    /// Only the tags can be inline elements, the texts are not. See
+    /// [`DisplayCategory`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::parse("<span>a</span>").unwrap().is_inline_element());
+    /// assert!(
+    ///     Html::parse("<my-element></my-element>")
+    ///         .unwrap()
+    ///         .is_inline_element()
+    /// );
+    /// assert!(!Html::parse("<p>a</p>").unwrap().is_inline_element());
+    /// ```
+    #[must_use]
+    pub fn is_inline_element(&self) -> bool {
+        self.display_category() == Some(DisplayCategory::Inline)
+    }
+
+    /// Returns the text of the tree as it would be displayed by a browser
+    ///
+    /// This is an approximation of the `innerText` of the DOM, unlike
+    /// [`Html::text_content`] that concatenates the texts as they are:
+    ///
+    /// - the runs of whitespace are collapsed into a single space, except in
+    ///   `<pre>` and `<textarea>`;
+    /// - the block elements (see [`DisplayCategory`]) start on a new line, and
+    ///   `<br>` is a line break;
+    /// - the comments and the hidden elements, like `<script>`, `<style>` or
+    ///   `<head>`, are skipped;
+    /// - the character references are decoded, so the texts must not have been
+    ///   decoded while parsing (see
+    ///   [`ParseOptions::decode_text_entities`](crate::prelude::ParseOptions::decode_text_entities)).
+    ///
+    /// The lines are separated by a single line break, and the text doesn't
+    /// start or end with whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html =
+    ///     Html::parse("<div>\n  <p>Fish &amp;\n  chips</p>Tea<br>Coffee<script>x()</script>\n</div>")
+    ///         .unwrap();
+    /// assert!(html.rendered_text() == "Fish & chips\nTea\nCoffee");
+    /// ```
+    #[must_use]
+    pub fn rendered_text(&self) -> String {
+        let mut rendered = RenderedText::default();
+        rendered.push_node(self, false);
+        let len = rendered.text.trim_end().len();
+        rendered.text.truncate(len);
+        rendered.text
+    }
+}
@@ -0,0 +1,357 @@
+//! Module that defines a [`Cursor`] to navigate in an [`Html`] tree.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{ptr, slice};
+
+use super::html::Html;
+
+/// Cursor to navigate in an [`Html`] tree
+///
+/// The [`Html`] tree only contains links from the parents to their children.
+/// The cursor keeps track of the path from the root to the current node, so
+/// it can also move to the parent and the siblings of a node.
+///
+/// The children of an [`Html::Tag`] are the nodes of its child, and the
+/// children of an [`Html::Vec`] are its elements.
+///
+/// The moving methods return `true` if the cursor moved, and `false` if the
+/// wanted node doesn't exist. In that case, the cursor stays where it was.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let html = Html::parse("<ul><li>First</li><li>Second</li></ul>").unwrap();
+/// let mut cursor = html.cursor();
+/// assert!(cursor.first_child()); // first li
+/// assert!(cursor.next_sibling()); // second li
+/// assert!(cursor.first_child()); // Second
+/// assert!(matches!(cursor.node(), Html::Text(text) if text == "Second"));
+/// assert!(cursor.parent() && cursor.prev_sibling());
+/// assert!(!cursor.prev_sibling());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cursor<'html> {
+    /// Path from the root to the current node
+    ///
+    /// Each level contains the list of siblings and the index of the node
+    /// that is on the path.
+    path: Vec<(&'html [Html], usize)>,
+}
+
+impl<'html> Cursor<'html> {
+    /// Moves the cursor to the `index`-th child of the current node
+    pub fn child(&mut self, index: usize) -> bool {
+        let children = self.node().children_slice();
+        if index < children.len() {
+            self.path.push((children, index));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the depth of the current node, i.e., its number of ancestors
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+
+    /// Moves the cursor to the first child of the current node
+    pub fn first_child(&mut self) -> bool {
+        self.child(0)
+    }
+
+    /// Moves the cursor to the last child of the current node
+    pub fn last_child(&mut self) -> bool {
+        self.node()
+            .children_slice()
+            .len()
+            .checked_sub(1)
+            .is_some_and(|last| self.child(last))
+    }
+
+    /// Moves the cursor to the sibling at the index computed by `update`.
+    fn move_sibling(&mut self, update: impl Fn(usize) -> Option<usize>) -> bool {
+        if self.path.len() <= 1 {
+            return false;
+        }
+        if let Some((siblings, index)) = self.path.last_mut()
+            && let Some(new_index) = update(*index)
+            && new_index < siblings.len()
+        {
+            *index = new_index;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Creates a cursor on the root of a tree
+    pub(crate) fn new(root: &'html Html) -> Self {
+        Self { path: vec![(slice::from_ref(root), 0)] }
+    }
+
+    /// Moves the cursor to the next sibling of the current node
+    pub fn next_sibling(&mut self) -> bool {
+        self.move_sibling(|index| index.checked_add(1))
+    }
+
+    /// Returns the node pointed by the cursor
+    #[must_use]
+    pub fn node(&self) -> &'html Html {
+        self.path
+            .last()
+            .and_then(|(siblings, index)| siblings.get(*index))
+            .unwrap_or(&Html::Empty)
+    }
+
+    /// Moves the cursor to the parent of the current node
+    pub fn parent(&mut self) -> bool {
+        if self.path.len() > 1 {
+            self.path.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the cursor to the previous sibling of the current node
+    pub fn prev_sibling(&mut self) -> bool {
+        self.move_sibling(|index| index.checked_sub(1))
+    }
+}
+
+impl Html {
+    /// Returns the top-level nodes of the tree, as a slice
+    ///
+    /// This is the list of elements of an [`Html::Vec`], nothing for an
+    /// [`Html::Empty`], and the node itself otherwise. This gives the same
+    /// view on all the roots, without copying or normalising the tree, as the
+    /// single node is borrowed as a one-element slice. Use
+    /// [`Html::into_fragment`] to take the nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::Empty.as_fragment().is_empty());
+    ///
+    /// let html = Html::parse("<p>a</p>").unwrap();
+    /// assert!(html.as_fragment() == [html.clone()]);
+    ///
+    /// let html = Html::parse("<p>a</p>b").unwrap();
+    /// assert!(html.as_fragment().len() == 2);
+    /// ```
+    #[must_use]
+    pub fn as_fragment(&self) -> &[Self] {
+        match self {
+            Self::Empty => &[],
+            Self::Vec(vec) => vec,
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Tag { .. }
+            | Self::Text(_) => slice::from_ref(self),
+        }
+    }
+
+    /// Returns the top-level nodes of the tree, as a mutable slice.
+    ///
+    /// See [`Html::as_fragment`].
+    pub(crate) fn as_fragment_mut(&mut self) -> &mut [Self] {
+        match self {
+            Self::Empty => &mut [],
+            Self::Vec(vec) => vec,
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Tag { .. }
+            | Self::Text(_) => slice::from_mut(self),
+        }
+    }
+
+    /// Returns an iterator over the direct children of a node
+    ///
+    /// The children of an [`Html::Tag`] are the nodes of its child, and the
+    /// children of an [`Html::Vec`] are its elements. Other nodes don't have
+    /// any children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<ul><li>a</li><li>b</li></ul>").unwrap();
+    /// let items = html.children().collect::<Vec<_>>();
+    /// assert!(items.len() == 2);
+    /// assert!(items[1].to_string() == "<li>b</li>");
+    /// ```
+    pub fn children(&self) -> slice::Iter<'_, Self> {
+        self.children_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the direct children of a node
+    ///
+    /// See [`Html::children`] for the definition of the children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<p>a<b>b</b></p>").unwrap();
+    /// for child in html.children_mut() {
+    ///     if matches!(child, Html::Text(_)) {
+    ///         *child = Html::text("c");
+    ///     }
+    /// }
+    /// assert!(html.to_string() == "<p>c<b>b</b></p>");
+    /// ```
+    pub fn children_mut(&mut self) -> slice::IterMut<'_, Self> {
+        match self {
+            Self::Tag { child, .. } => child.as_fragment_mut().iter_mut(),
+            Self::Vec(vec) => vec.iter_mut(),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => [].iter_mut(),
+        }
+    }
+
+    /// Returns the children of a node, as a slice.
+    ///
+    /// See [`Cursor`] for the definition of the children.
+    pub(crate) fn children_slice(&self) -> &[Self] {
+        match self {
+            Self::Tag { child, .. } => child.as_fragment(),
+            Self::Vec(vec) => vec,
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => &[],
+        }
+    }
+
+    /// Creates a [`Cursor`] on the root of the tree
+    ///
+    /// See [`Cursor`] for more information.
+    #[must_use]
+    pub fn cursor(&self) -> Cursor<'_> {
+        Cursor::new(self)
+    }
+
+    /// Returns the first child of a node
+    ///
+    /// See [`Html::children`] for the definition of the children. Returns
+    /// `None` if the node doesn't have any children, like texts, comments
+    /// and empty tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>a<b>b</b></p>").unwrap();
+    /// assert!(html.first_child() == Some(&Html::text("a")));
+    /// assert!(Html::text("a").first_child().is_none());
+    /// ```
+    #[must_use]
+    pub fn first_child(&self) -> Option<&Self> {
+        self.children_slice().first()
+    }
+
+    /// Returns the last child of a node
+    ///
+    /// See [`Html::first_child`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>a<b>b</b></p>").unwrap();
+    /// assert!(html.last_child().unwrap().to_string() == "<b>b</b>");
+    /// ```
+    #[must_use]
+    pub fn last_child(&self) -> Option<&Self> {
+        self.children_slice().last()
+    }
+
+    /// Returns the child of a node at the given index, starting at 0
+    ///
+    /// See [`Html::first_child`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let row = Html::parse("<tr><td>a</td><td>b</td></tr>").unwrap();
+    /// assert!(row.nth_child(1).unwrap().to_string() == "<td>b</td>");
+    /// assert!(row.nth_child(2).is_none());
+    /// ```
+    #[must_use]
+    pub fn nth_child(&self, index: usize) -> Option<&Self> {
+        self.children_slice().get(index)
+    }
+
+    /// Returns the path from the root of the tree to the `target` node
+    ///
+    /// The `target` must be a reference to a node of the tree, like the ones
+    /// returned by [`Html::select`]: nodes are compared by identity, not by
+    /// value. The path starts with the root and ends with the `target`, and
+    /// follows the children as defined by [`Html::children`].
+    ///
+    /// # Returns
+    ///
+    /// `None` if the `target` isn't a node of the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<nav><ul><li><a href='/'>Home</a></li></ul></nav>").unwrap();
+    /// let link = html.select("a").unwrap()[0];
+    /// let path = html.path_to(link).unwrap();
+    /// assert!(path.len() == 4);
+    /// assert!(matches!(path[0], Html::Tag { tag, .. } if tag.as_name() == "nav"));
+    /// assert!(html.path_to(&Html::text("Home")).is_none());
+    /// ```
+    #[must_use]
+    pub fn path_to<'html>(&'html self, target: &Self) -> Option<Vec<&'html Self>> {
+        let mut path = vec![];
+        self.push_path_to(target, &mut path).then_some(path)
+    }
+
+    /// Pushes the path to the `target` node into `path`.
+    ///
+    /// See [`Html::path_to`].
+    ///
+    /// # Returns
+    ///
+    /// `true` iff the `target` was found. The `path` is left unchanged
+    /// otherwise.
+    fn push_path_to<'html>(&'html self, target: &Self, path: &mut Vec<&'html Self>) -> bool {
+        path.push(self);
+        if ptr::eq(self, target)
+            || self
+                .children()
+                .any(|child| child.push_path_to(target, path))
+        {
+            return true;
+        }
+        path.pop();
+        false
+    }
+}
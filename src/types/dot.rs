@@ -0,0 +1,110 @@
+//! Module to export an [`Html`] tree to the Graphviz DOT format.
+
+use alloc::string::String;
+use alloc::{format, vec};
+use core::fmt::Write as _;
+
+use super::html::Html;
+use crate::errors::safe_expect;
+
+/// Maximum number of characters of the texts and comments in the labels
+const LABEL_LEN: usize = 20;
+
+impl Html {
+    /// Exports the tree as a Graphviz DOT digraph
+    ///
+    /// Each node of the tree is a node of the graph, labelled by its kind:
+    /// the tags by their name, like `<div>`, and the texts, comments and
+    /// doctypes by a preview of their content, cut after 20 characters. The
+    /// edges go from the tags to their children. The [`Html::Vec`] and
+    /// [`Html::Empty`] nodes aren't drawn, only their content.
+    ///
+    /// The graph can be rendered with `dot -Tsvg`, to inspect the structure of
+    /// a tree when debugging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>Hello <b>world</b></p>").unwrap();
+    /// assert!(
+    ///     html.to_dot()
+    ///         == r#"digraph html {
+    ///     n0 [label="<p>"];
+    ///     n1 [label="\"Hello \""];
+    ///     n0 -> n1;
+    ///     n2 [label="<b>"];
+    ///     n0 -> n2;
+    ///     n3 [label="\"world\""];
+    ///     n2 -> n3;
+    /// }
+    /// "#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph html {\n");
+        let mut stack = vec![(self, None)];
+        let mut next_id: usize = 0;
+        while let Some((node, parent)) = stack.pop() {
+            let label = match node {
+                Self::Empty => continue,
+                Self::Vec(vec) => {
+                    stack.extend(vec.iter().rev().map(|child| (child, parent)));
+                    continue;
+                }
+                Self::BogusComment(content) => format!("<!{}>", preview(content)),
+                Self::Comment(content) => format!("<!--{}-->", preview(content)),
+                Self::Conditional(Some(condition)) => format!("<!--[if {}]>", preview(condition)),
+                Self::Conditional(None) => String::from("<![endif]-->"),
+                Self::Doctype { name, attr: Some(attr), .. } =>
+                    format!("<!{name} {}>", preview(attr)),
+                Self::Doctype { name, attr: None, .. } => format!("<!{name}>"),
+                Self::Tag { tag, child } => {
+                    stack.push((child, Some(next_id)));
+                    format!("<{}>", tag.as_name())
+                }
+                Self::Text(text) => format!("\"{}\"", preview(text)),
+            };
+            let id = next_id;
+            next_id = next_id.saturating_add(1);
+            safe_expect!(write!(dot, "    n{id} [label="), "Writing to a string never fails.");
+            write_label(&label, &mut dot);
+            dot.push_str("];\n");
+            if let Some(parent_id) = parent {
+                safe_expect!(
+                    writeln!(dot, "    n{parent_id} -> n{id};"),
+                    "Writing to a string never fails."
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Cuts a content after [`LABEL_LEN`] characters, with `...` if it was cut.
+fn preview(content: &str) -> String {
+    let mut chars = content.chars();
+    let mut label = chars.by_ref().take(LABEL_LEN).collect::<String>();
+    if chars.next().is_some() {
+        label.push_str("...");
+    }
+    label
+}
+
+/// Writes a label between double quotes, with the DOT escapes.
+fn write_label(label: &str, dot: &mut String) {
+    dot.push('"');
+    for ch in label.chars() {
+        match ch {
+            '"' => dot.push_str("\\\""),
+            '\\' => dot.push_str("\\\\"),
+            '\n' => dot.push_str("\\n"),
+            '\r' => dot.push_str("\\r"),
+            _ => dot.push(ch),
+        }
+    }
+    dot.push('"');
+}
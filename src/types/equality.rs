@@ -0,0 +1,229 @@
+//! Module to compare [`Html`] trees while ignoring insignificant differences.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem;
+
+use super::html::Html;
+use super::tag::Tag;
+
+/// Options of the comparison of [`Html::semantically_eq`]
+///
+/// By default, the tags must have the same names and the same attributes in
+/// the same order, whereas [`PartialEq`] ignores their order, and their case
+/// in HTML. The quotes of the attribute values are ignored. Each option makes
+/// the comparison ignore one more kind of difference.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let options = EqOptions::new()
+///     .ignore_comments(true)
+///     .ignore_whitespace(true);
+/// let left = Html::parse("<ul>\n  <!-- items -->\n  <li>A</li>\n</ul>").unwrap();
+/// let right = Html::parse("<ul><li>A</li></ul>").unwrap();
+/// assert!(left != right);
+/// assert!(left.semantically_eq(&right, options));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EqOptions {
+    /// Ignores the order of the attributes
+    ///
+    /// See [`EqOptions::ignore_attr_order`].
+    ignore_attr_order: bool,
+    /// Ignores the comments
+    ///
+    /// See [`EqOptions::ignore_comments`].
+    ignore_comments: bool,
+    /// Ignores the whitespace-only texts
+    ///
+    /// See [`EqOptions::ignore_whitespace`].
+    ignore_whitespace: bool,
+}
+
+/// Public API for [`EqOptions`]
+impl EqOptions {
+    /// Sets whether the order of the attributes is ignored
+    ///
+    /// - If `ignore_attr_order` is set to `false` (default), the attributes of
+    ///   two tags must be in the same order.
+    /// - If `ignore_attr_order` is set to `true`, the attributes of two tags
+    ///   can be in any order, but must still be the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let left = Html::parse(r#"<a href="/" class="nav">"#).unwrap();
+    /// let right = Html::parse(r#"<a class="nav" href="/">"#).unwrap();
+    /// assert!(!left.semantically_eq(&right, EqOptions::new()));
+    /// assert!(left.semantically_eq(&right, EqOptions::new().ignore_attr_order(true)));
+    /// ```
+    #[must_use]
+    pub const fn ignore_attr_order(mut self, ignore_attr_order: bool) -> Self {
+        self.ignore_attr_order = ignore_attr_order;
+        self
+    }
+
+    /// Sets whether the comments are ignored
+    ///
+    /// - If `ignore_comments` is set to `false` (default), the comments must be
+    ///   the same in both trees.
+    /// - If `ignore_comments` is set to `true`, the comments are removed before
+    ///   the comparison. The texts around a removed comment are then compared
+    ///   as a single text, so `a<!-- x -->b` is equal to `ab`.
+    #[must_use]
+    pub const fn ignore_comments(mut self, ignore_comments: bool) -> Self {
+        self.ignore_comments = ignore_comments;
+        self
+    }
+
+    /// Sets whether the whitespace-only texts are ignored
+    ///
+    /// - If `ignore_whitespace` is set to `false` (default), the texts must be
+    ///   the same in both trees.
+    /// - If `ignore_whitespace` is set to `true`, the texts that only contain
+    ///   ASCII whitespace are removed before the comparison, as with
+    ///   [`Html::trim_whitespace_nodes`]. The other texts, and the content of
+    ///   whitespace-sensitive elements like `<pre>`, are still compared
+    ///   exactly.
+    #[must_use]
+    pub const fn ignore_whitespace(mut self, ignore_whitespace: bool) -> Self {
+        self.ignore_whitespace = ignore_whitespace;
+        self
+    }
+
+    /// Creates a default [`EqOptions`]
+    ///
+    /// With the default options, [`Html::semantically_eq`] only ignores the
+    /// quotes of the attribute values, and the sources stored with
+    /// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ignore_attr_order: false, ignore_comments: false, ignore_whitespace: false }
+    }
+}
+
+impl Html {
+    /// Compares the normalised trees, with the attribute comparison of the
+    /// options.
+    fn eq_with(&self, other: &Self, opts: EqOptions) -> bool {
+        match (self, other) {
+            (Self::Tag { tag, child }, Self::Tag { tag: other_tag, child: other_child }) =>
+                tags_eq(tag, other_tag, opts) && child.eq_with(other_child, opts),
+            (Self::Vec(left), Self::Vec(right)) =>
+                left.len() == right.len()
+                    && left
+                        .iter()
+                        .zip(right.iter())
+                        .all(|(left_node, right_node)| left_node.eq_with(right_node, opts)),
+            (
+                left @ (Self::BogusComment(_)
+                | Self::Comment(_)
+                | Self::Conditional(_)
+                | Self::Doctype { .. }
+                | Self::Empty
+                | Self::Tag { .. }
+                | Self::Text(_)
+                | Self::Vec(_)),
+                right,
+            ) => left == right,
+        }
+    }
+
+    /// Merges the adjacent texts and removes the empty nodes.
+    fn merge_texts(&mut self) {
+        match self {
+            Self::Tag { child, .. } => child.merge_texts(),
+            Self::Vec(vec) => {
+                let mut merged: Vec<Self> = Vec::with_capacity(vec.len());
+                for mut node in mem::take(vec).into_vec() {
+                    node.merge_texts();
+                    match (merged.last_mut(), node) {
+                        (_, Self::Empty) => (),
+                        (Some(Self::Text(last)), Self::Text(text)) => last.push_str(&text),
+                        (_, other) => merged.push(other),
+                    }
+                }
+                *self = Self::from_nodes(merged);
+            }
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => (),
+        }
+    }
+
+    /// Returns a copy of the tree without the nodes ignored by the options.
+    fn normalized(&self, opts: EqOptions) -> Self {
+        let mut tree = self.clone();
+        if opts.ignore_comments {
+            tree.strip_comments();
+        }
+        if opts.ignore_whitespace {
+            tree.trim_whitespace_nodes();
+        }
+        tree.merge_texts();
+        tree
+    }
+
+    /// Checks if two trees are equal, as seen by their contents
+    ///
+    /// This is the comparison most tests want when checking a rendered tree
+    /// against the expected markup: unlike the comparison of the displayed
+    /// strings, it can ignore the comments, the whitespace between the tags
+    /// and the order of the attributes. See [`EqOptions`] for the options.
+    ///
+    /// The quotes around the attribute values are always ignored, as well as
+    /// the way the nodes are grouped: adjacent texts are compared as a single
+    /// text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = EqOptions::new()
+    ///     .ignore_attr_order(true)
+    ///     .ignore_comments(true)
+    ///     .ignore_whitespace(true);
+    /// let rendered =
+    ///     Html::parse("<div class='card' id='a'>\n  <p>Hi<!-- name --></p>\n</div>").unwrap();
+    /// let expected = Html::parse(r#"<div id="a" class="card"><p>Hi</p></div>"#).unwrap();
+    /// assert!(rendered.semantically_eq(&expected, options));
+    /// assert!(!rendered.semantically_eq(&Html::parse("<div></div>").unwrap(), options));
+    /// ```
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self, opts: EqOptions) -> bool {
+        self.normalized(opts).eq_with(&other.normalized(opts), opts)
+    }
+}
+
+/// Returns the names and values of the attributes of a tag, without their
+/// quotes.
+fn attr_pairs(tag: &Tag) -> Vec<(&String, Option<&String>)> {
+    tag.as_attrs()
+        .iter()
+        .map(|attr| (attr.as_name(), attr.as_value()))
+        .collect()
+}
+
+/// Compares the names, namespaces and attributes of two tags.
+fn tags_eq(left: &Tag, right: &Tag, opts: EqOptions) -> bool {
+    if left.as_name() != right.as_name() || left.as_namespace() != right.as_namespace() {
+        return false;
+    }
+    let mut left_attrs = attr_pairs(left);
+    let mut right_attrs = attr_pairs(right);
+    if opts.ignore_attr_order {
+        left_attrs.sort_unstable();
+        right_attrs.sort_unstable();
+    }
+    left_attrs == right_attrs
+}
@@ -0,0 +1,96 @@
+//! Module to replay an [`Html`] tree as a stream of events.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::html::Html;
+use super::tag::Tag;
+
+/// Event of the replay of an [`Html`] tree
+///
+/// See [`Html::events`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Bogus comment, like `<!foo>`, with its content
+    BogusComment(String),
+    /// Comment, with its content
+    Comment(String),
+    /// Marker of a parsed conditional comment. See [`Html::Conditional`].
+    Conditional(Option<String>),
+    /// Document tag. See [`Html::Doctype`].
+    #[non_exhaustive]
+    Doctype {
+        /// Name of the tag
+        name: String,
+        /// Attribute of the tag
+        attr: Option<String>,
+        /// Internal subset of the tag, if any
+        subset: Option<String>,
+    },
+    /// End of a tag, with its name
+    End(String),
+    /// Start of a tag, with its name and attributes
+    Start(Tag),
+    /// Text
+    Text(String),
+}
+
+impl Html {
+    /// Returns the sequence of events that would serialise the tree
+    ///
+    /// This is the inverse of parsing: every tag gives an [`Event::Start`],
+    /// the events of its children, then an [`Event::End`]. The end event is
+    /// emitted even for the void elements, like `<br>`, so that the start and
+    /// end events are always balanced. [`Html::Empty`] doesn't give any
+    /// event.
+    ///
+    /// Comparing event streams is more robust than comparing strings in
+    /// tests, as it doesn't depend on how the tree is displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<p id="a">Hi<br></p>"#).unwrap();
+    /// assert!(
+    ///     html.events()
+    ///         == [
+    ///             Event::Start(Tag::builder("p").attr("id", "a").build()),
+    ///             Event::Text("Hi".to_owned()),
+    ///             Event::Start(Tag::builder("br").build()),
+    ///             Event::End("br".to_owned()),
+    ///             Event::End("p".to_owned()),
+    ///         ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn events(&self) -> Vec<Event> {
+        let mut events = Vec::new();
+        self.push_events(&mut events);
+        events
+    }
+
+    /// Pushes the events of the tree into `events`.
+    fn push_events(&self, events: &mut Vec<Event>) {
+        match self {
+            Self::BogusComment(comment) => events.push(Event::BogusComment(comment.clone())),
+            Self::Comment(comment) => events.push(Event::Comment(comment.clone())),
+            Self::Conditional(condition) => events.push(Event::Conditional(condition.clone())),
+            Self::Doctype { name, attr, subset, .. } => events.push(Event::Doctype {
+                name: name.clone(),
+                attr: attr.clone(),
+                subset: subset.clone(),
+            }),
+            Self::Empty => (),
+            Self::Tag { tag, child } => {
+                events.push(Event::Start(tag.clone()));
+                child.push_events(events);
+                events.push(Event::End(tag.as_name().clone()));
+            }
+            Self::Text(text) => events.push(Event::Text(text.clone())),
+            Self::Vec(vec) => vec.iter().for_each(|node| node.push_events(events)),
+        }
+    }
+}
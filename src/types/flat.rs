@@ -0,0 +1,300 @@
+//! Module that defines a flat representation of an [`Html`] tree.
+//!
+//! All the nodes are stored in a single vector, and the links between the
+//! nodes are indices in this vector. This makes traversals cache-friendly and
+//! avoids recursion.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{fmt, iter};
+
+use super::html::Html;
+use super::serialize::{write_conditional, write_doctype};
+use super::tag::Tag;
+use crate::errors::safe_expect;
+
+/// Flat representation of an [`Html`] tree
+///
+/// The nodes are stored in document order, i.e., the order in which their
+/// opening part appears in the HTML string. The links to the children and
+/// siblings are [`NodeId`]s.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let html = Html::parse("<ul><li>First</li><li>Second</li></ul>").unwrap();
+/// let flat = FlatHtml::from(&html);
+/// assert!(flat.len() == 5);
+///
+/// let ul = flat.roots().next().unwrap();
+/// let names = flat
+///     .children(ul)
+///     .filter_map(|id| match flat.get(id)?.as_data() {
+///         NodeData::Tag(tag) => Some(tag.as_name().as_str()),
+///         _ => None,
+///     })
+///     .collect::<Vec<_>>();
+/// assert!(names == ["li", "li"]);
+/// assert!(flat.to_string() == html.to_string());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FlatHtml {
+    /// Nodes of the tree, in document order
+    nodes: Vec<FlatNode>,
+}
+
+impl FlatHtml {
+    /// Returns an iterator over the direct children of a node
+    ///
+    /// The iterator is empty if the node doesn't exist or doesn't have any
+    /// children.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> {
+        self.siblings(self.get(id).and_then(|node| node.first_child))
+    }
+
+    /// Returns the node with the given identifier, if it exists
+    #[must_use]
+    pub fn get(&self, id: NodeId) -> Option<&FlatNode> {
+        self.nodes.get(id.0)
+    }
+
+    /// Checks if the tree doesn't contain any node
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over all the nodes, in document order
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &FlatNode)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (NodeId(idx), node))
+    }
+
+    /// Returns the number of nodes in the tree
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Pushes a new node, and links it to its parent and previous sibling.
+    fn push(
+        &mut self,
+        data: NodeData,
+        parent: Option<NodeId>,
+        last_child: &mut Vec<Option<NodeId>>,
+    ) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        let parent_idx = parent.map_or(0, |parent_id| parent_id.0.saturating_add(1));
+        let previous = safe_expect!(last_child.get_mut(parent_idx), "Parent was pushed before");
+        match previous.replace(id) {
+            Some(prev) =>
+                safe_expect!(self.nodes.get_mut(prev.0), "Sibling was pushed before").next_sibling =
+                    Some(id),
+            None =>
+                if let Some(parent_id) = parent {
+                    safe_expect!(self.nodes.get_mut(parent_id.0), "Parent was pushed before")
+                        .first_child = Some(id);
+                },
+        }
+        self.nodes
+            .push(FlatNode { data, first_child: None, next_sibling: None, parent });
+        last_child.push(None);
+        id
+    }
+
+    /// Returns an iterator over the nodes at the root of the tree
+    pub fn roots(&self) -> impl Iterator<Item = NodeId> {
+        self.siblings((!self.nodes.is_empty()).then_some(NodeId(0)))
+    }
+
+    /// Returns an iterator over a node and its next siblings
+    fn siblings(&self, first: Option<NodeId>) -> impl Iterator<Item = NodeId> {
+        iter::successors(first, |id| self.get(*id)?.next_sibling)
+    }
+}
+
+impl fmt::Display for FlatHtml {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stack = self.roots().map(Step::Open).collect::<Vec<_>>();
+        stack.reverse();
+        while let Some(step) = stack.pop() {
+            let Some(node) = self.get(step.id()) else {
+                continue;
+            };
+            match (step, &node.data) {
+                (Step::Close(id), NodeData::Tag(tag)) =>
+                    tag.fmt_close(self.children(id).next().is_none(), f)?,
+                (Step::Open(id), NodeData::Tag(tag)) => {
+                    tag.fmt_open(self.children(id).next().is_none(), f)?;
+                    stack.push(Step::Close(id));
+                    let len = stack.len();
+                    stack.extend(self.children(id).map(Step::Open));
+                    stack.get_mut(len..).unwrap_or_default().reverse();
+                }
+                (Step::Open(_), NodeData::Doctype { source: Some(source), .. }) =>
+                    f.write_str(source)?,
+                (Step::Open(_), NodeData::Doctype { name, attr, subset, source: None }) =>
+                    write_doctype(name, attr.as_deref(), subset.as_deref(), f)?,
+                (Step::Open(_), NodeData::BogusComment(content)) => write!(f, "<!{content}>")?,
+                (Step::Open(_), NodeData::Comment(content)) => write!(f, "<!--{content}-->")?,
+                (Step::Open(_), NodeData::Conditional(condition)) =>
+                    write_conditional(condition.as_deref(), f)?,
+                (Step::Open(_), NodeData::Text(text)) => f.write_str(text)?,
+                (Step::Close(_), _) => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&Html> for FlatHtml {
+    fn from(html: &Html) -> Self {
+        let mut flat = Self::default();
+        // `last_child[0]` is the last root, `last_child[id + 1]` the last child
+        // of the node `id`.
+        let mut last_child = vec![None];
+        let mut stack = vec![(html, None)];
+        while let Some((node, parent)) = stack.pop() {
+            let data = match node {
+                Html::Empty => continue,
+                Html::Vec(vec) => {
+                    stack.extend(vec.iter().rev().map(|child| (child, parent)));
+                    continue;
+                }
+                Html::Tag { tag, child } => {
+                    let id = flat.push(NodeData::Tag(tag.clone()), parent, &mut last_child);
+                    stack.push((child, Some(id)));
+                    continue;
+                }
+                Html::BogusComment(content) => NodeData::BogusComment(content.clone()),
+                Html::Comment(content) => NodeData::Comment(content.clone()),
+                Html::Conditional(condition) => NodeData::Conditional(condition.clone()),
+                Html::Doctype { name, attr, subset, source } => NodeData::Doctype {
+                    name: name.clone(),
+                    attr: attr.clone(),
+                    subset: subset.clone(),
+                    source: source.clone(),
+                },
+                Html::Text(text) => NodeData::Text(text.clone()),
+            };
+            flat.push(data, parent, &mut last_child);
+        }
+        flat
+    }
+}
+
+impl Html {
+    /// Converts the tree into its flat representation
+    ///
+    /// See [`FlatHtml`] for more information.
+    #[must_use]
+    pub fn to_flat(&self) -> FlatHtml {
+        FlatHtml::from(self)
+    }
+}
+
+/// Node of a [`FlatHtml`] tree
+///
+/// Contains the data of the node, and the links to its relatives.
+#[derive(Debug, Clone)]
+pub struct FlatNode {
+    /// Data of the node
+    data: NodeData,
+    /// First child of the node, if any
+    first_child: Option<NodeId>,
+    /// Next sibling of the node, if any
+    next_sibling: Option<NodeId>,
+    /// Parent of the node, if any
+    parent: Option<NodeId>,
+}
+
+impl FlatNode {
+    /// Returns the data of the node
+    #[must_use]
+    pub const fn as_data(&self) -> &NodeData {
+        &self.data
+    }
+
+    /// Returns the first child of the node, if any
+    #[must_use]
+    pub const fn first_child(&self) -> Option<NodeId> {
+        self.first_child
+    }
+
+    /// Returns the next sibling of the node, if any
+    #[must_use]
+    pub const fn next_sibling(&self) -> Option<NodeId> {
+        self.next_sibling
+    }
+
+    /// Returns the parent of the node, or `None` for root nodes
+    #[must_use]
+    pub const fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+}
+
+/// Data of a [`FlatNode`]
+///
+/// This is the equivalent of an [`Html`] node without its children.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub enum NodeData {
+    /// Bogus comment. See [`Html::BogusComment`].
+    BogusComment(String),
+    /// Comment block. See [`Html::Comment`].
+    Comment(String),
+    /// Marker of a parsed conditional comment. See [`Html::Conditional`].
+    Conditional(Option<String>),
+    /// Document tag. See [`Html::Doctype`].
+    #[non_exhaustive]
+    Doctype {
+        /// Name of the tag
+        name: String,
+        /// Attribute of the tag
+        attr: Option<String>,
+        /// Internal subset of the tag, if any
+        subset: Option<String>,
+        /// Original source of the tag, if it was stored
+        source: Option<String>,
+    },
+    /// Tag. See [`Html::Tag`].
+    Tag(Tag),
+    /// Raw text. See [`Html::Text`].
+    Text(String),
+}
+
+/// Identifier of a node in a [`FlatHtml`] tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the index of the node in document order
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Step of the iterative serialisation of a [`FlatHtml`]
+#[derive(Clone, Copy)]
+enum Step {
+    /// Closing part of a tag
+    Close(NodeId),
+    /// Node to write, with its opening part
+    Open(NodeId),
+}
+
+impl Step {
+    /// Returns the node of the step
+    const fn id(self) -> NodeId {
+        match self {
+            Self::Close(id) | Self::Open(id) => id,
+        }
+    }
+}
@@ -1,8 +1,15 @@
 //! Module that defines an [`Html`] tree.
 
-use core::fmt;
+use alloc::borrow::ToOwned as _;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::{self, Vec};
+use core::hash::{Hash, Hasher};
+use core::{fmt, mem, slice};
 
-use super::tag::Tag;
+use super::serialize::SerializePolicy;
+use super::tag::{Attribute, Namespace, Tag};
 
 /// Dom tree structure to represent the parsed html.
 ///
@@ -27,17 +34,42 @@ use super::tag::Tag;
 /// .unwrap();
 /// ```
 #[non_exhaustive]
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Clone)]
 pub enum Html {
+    /// Bogus comment
+    ///
+    /// These are the tags with exclamation marks that are neither comments
+    /// nor doctypes, like `<!foo>` or the downlevel conditional comments
+    /// `<![if IE]>`. Browsers read them as comments, but they are displayed
+    /// back with their own delimiters, `<!` and `>`.
+    ///
+    /// # Example
+    ///
+    /// `<!ELEMENT br EMPTY>`, with the content `ELEMENT br EMPTY`.
+    BogusComment(String),
     /// Comment block
     ///
     /// # Example
     ///
     /// `<!-- some comment -->`
     Comment(String),
+    /// Marker of a parsed conditional comment
+    ///
+    /// With [`ParseOptions::parse_conditional_comments`](crate::prelude::ParseOptions::parse_conditional_comments),
+    /// the content of a conditional comment is parsed as the rest of the
+    /// tree, between the markers of the comment. The opening marker holds its
+    /// condition and the closing marker holds [`None`], so that they are
+    /// displayed back as they were written.
+    ///
+    /// # Example
+    ///
+    /// `<!--[if lt IE 9]>`, with the condition `lt IE 9`, and `<![endif]-->`.
+    Conditional(Option<String>),
     /// Document tag.
     ///
-    /// These are tags with exclamation marks
+    /// These are the `<!doctype>` tags, whatever their case. The other tags
+    /// with exclamation marks, like `<!foo>` or `<![if IE]>`, are read as
+    /// [`Html::BogusComment`]s with the content `foo` and `[if IE]`.
     ///
     /// # Examples
     ///
@@ -56,6 +88,21 @@ pub enum Html {
         ///
         /// In the previous example, the attribute is `html`.
         attr: Option<String>,
+        /// Internal subset of the tag, between `[` and `]`
+        ///
+        /// This is the raw content of the brackets, as written in XML
+        /// doctypes.
+        ///
+        /// # Examples
+        ///
+        /// In `<!DOCTYPE note [<!ELEMENT note (#PCDATA)>]>`, the attribute is
+        /// `note` and the subset is `<!ELEMENT note (#PCDATA)>`.
+        subset: Option<String>,
+        /// Original source of the tag
+        ///
+        /// Only stored when parsing with
+        /// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source).
+        source: Option<String>,
     },
     /// Empty html tree
     ///
@@ -68,7 +115,6 @@ pub enum Html {
     ///
     /// - `<div id="blob">content</div>`
     /// - `<div attr />`
-    /// - `</>`
     #[non_exhaustive]
     Tag {
         /// Opening tag
@@ -102,33 +148,791 @@ pub enum Html {
 }
 
 impl Html {
-    /// Checks if an [`Html`] tree is empty
-    pub(crate) const fn is_empty(&self) -> bool {
+    /// Adds a child after the other children of a tag
+    ///
+    /// This is the in-place version of [`Html::with_child`]. An
+    /// [`Html::Empty`] node adds nothing, and the nodes of an [`Html::Vec`]
+    /// are added one by one. Nodes that are not tags are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut list = Html::parse("<ul><li>a</li></ul>").unwrap();
+    /// list.append_child(Html::parse("<li>b</li>").unwrap());
+    /// assert!(list.to_string() == "<ul><li>a</li><li>b</li></ul>");
+    /// ```
+    pub fn append_child(&mut self, node: Self) {
+        if let Self::Tag { child, .. } = self {
+            let mut children = mem::take(&mut **child).into_fragment();
+            children.extend(node.into_fragment());
+            **child = Self::from_nodes(children);
+        }
+    }
+
+    /// Returns the classes of a tag
+    ///
+    /// The classes are the tokens of the `class` attribute, separated by ASCII
+    /// whitespace. The iterator is empty if the node isn't a tag or doesn't
+    /// have a `class` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<div class=" card  active">"#).unwrap();
+    /// assert!(html.classes().collect::<Vec<_>>() == ["card", "active"]);
+    /// ```
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        let classes = match self {
+            Self::Tag { tag, .. } => tag.find_attr_value("class").map_or("", String::as_str),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_)
+            | Self::Vec(_) => "",
+        };
+        classes.split_ascii_whitespace()
+    }
+
+    /// Creates a comment node
+    ///
+    /// The content is displayed between `<!--` and `-->`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::comment(" note ").to_string() == "<!-- note -->");
+    /// ```
+    #[must_use]
+    pub fn comment<T>(content: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::Comment(content.into())
+    }
+
+    /// Returns the `data-*` attributes of a tag
+    ///
+    /// The keys are the names of the attributes without their `data-` prefix,
+    /// so `data-user-id="5"` gives the entry `user-id` → `5`. Attributes
+    /// without a value are given an empty value, and only the first of the
+    /// duplicated attributes is kept. The map is empty if the node isn't a
+    /// tag or doesn't have any `data-*` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<div id="card" data-user-id="5" data-active>"#).unwrap();
+    /// let data = html.data_attributes();
+    /// assert!(data.len() == 2);
+    /// assert!(data["user-id"] == "5");
+    /// assert!(data["active"].is_empty());
+    /// ```
+    #[must_use]
+    pub fn data_attributes(&self) -> BTreeMap<String, String> {
+        let mut data = BTreeMap::new();
+        if let Self::Tag { tag, .. } = self {
+            for attr in tag.as_attrs() {
+                if let Some(key) = attr.as_name().strip_prefix("data-") {
+                    data.entry(key.to_owned())
+                        .or_insert_with(|| attr.as_value().cloned().unwrap_or_default());
+                }
+            }
+        }
+        data
+    }
+
+    /// Splices the nested lists of nodes into their parent list
+    ///
+    /// The nodes of an [`Html::Vec`] found among siblings are moved into the
+    /// list of the siblings, and the [`Html::Empty`] nodes are removed, in
+    /// the whole tree. This restores the flat lists of siblings of a parsed
+    /// tree after editing the nodes in place, for instance with
+    /// [`Html::unwrap_tag`] or [`Html::replace_with`] on the children given
+    /// by [`Html::children_mut`], so that [`Html::children`] and the paths
+    /// of the nodes are correct again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<p>a<span>b<i>c</i></span></p>").unwrap();
+    /// for child in html.children_mut() {
+    ///     child.unwrap_tag();
+    /// }
+    /// assert!(html.children().count() == 2);
+    /// html.flatten();
+    /// assert!(html.children().count() == 3);
+    /// assert!(html.to_string() == "<p>ab<i>c</i></p>");
+    /// ```
+    pub fn flatten(&mut self) {
+        match self {
+            Self::Tag { child, .. } => child.flatten(),
+            Self::Vec(vec) => {
+                let mut nodes = Vec::with_capacity(vec.len());
+                for mut node in mem::take(vec).into_vec() {
+                    node.flatten();
+                    nodes.extend(node.into_fragment());
+                }
+                *self = Self::from_nodes(nodes);
+            }
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_) => (),
+        }
+    }
+
+    /// Writes the tree as an indented outline, with one node per line.
+    ///
+    /// This is the alternate [`Debug`](fmt::Debug) format of [`Html`].
+    fn fmt_outline(&self, depth: usize, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if depth > 0 {
+            formatter.write_str("\n")?;
+        }
+        write!(formatter, "{:indent$}", "", indent = depth.saturating_mul(2))?;
+        match self {
+            Self::BogusComment(content) => {
+                formatter.write_str("BogusComment(")?;
+                fmt::Debug::fmt(content, formatter)?;
+                formatter.write_str(")")
+            }
+            Self::Comment(content) => {
+                formatter.write_str("Comment(")?;
+                fmt::Debug::fmt(content, formatter)?;
+                formatter.write_str(")")
+            }
+            Self::Conditional(condition) => {
+                formatter.write_str("Conditional(")?;
+                fmt::Debug::fmt(condition, formatter)?;
+                formatter.write_str(")")
+            }
+            Self::Doctype { name, attr, subset, .. } => {
+                write!(formatter, "Doctype({name}")?;
+                if let Some(attr_str) = attr {
+                    write!(formatter, " {attr_str}")?;
+                }
+                if let Some(subset_str) = subset {
+                    write!(formatter, " [{subset_str}]")?;
+                }
+                formatter.write_str(")")
+            }
+            Self::Empty => formatter.write_str("Empty"),
+            Self::Tag { tag, child } => {
+                write!(formatter, "Tag({}", tag.as_name())?;
+                for attr in tag.as_attrs() {
+                    write!(formatter, "{attr}")?;
+                }
+                formatter.write_str(")")?;
+                match &**child {
+                    Self::Empty => Ok(()),
+                    Self::Vec(vec) => vec
+                        .iter()
+                        .try_for_each(|node| node.fmt_outline(depth.saturating_add(1), formatter)),
+                    node @ (Self::BogusComment(_)
+                    | Self::Comment(_)
+                    | Self::Conditional(_)
+                    | Self::Doctype { .. }
+                    | Self::Tag { .. }
+                    | Self::Text(_)) => node.fmt_outline(depth.saturating_add(1), formatter),
+                }
+            }
+            Self::Text(text) => {
+                formatter.write_str("Text(")?;
+                fmt::Debug::fmt(text, formatter)?;
+                formatter.write_str(")")
+            }
+            Self::Vec(vec) => {
+                formatter.write_str("Vec")?;
+                vec.iter()
+                    .try_for_each(|node| node.fmt_outline(depth.saturating_add(1), formatter))
+            }
+        }
+    }
+
+    /// Creates a tree from a list of nodes.
+    ///
+    /// This is the inverse of [`Html::into_fragment`]: lists with less than 2
+    /// nodes are flattened.
+    pub(crate) fn from_nodes(mut nodes: Vec<Self>) -> Self {
+        if nodes.len() <= 1 {
+            nodes.pop().unwrap_or_default()
+        } else {
+            Self::Vec(nodes.into_boxed_slice())
+        }
+    }
+
+    /// Checks if a tag has the given class
+    ///
+    /// See [`Html::classes`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<div class="card active">"#).unwrap();
+    /// assert!(html.has_class("active"));
+    /// assert!(!html.has_class("act"));
+    /// ```
+    #[must_use]
+    pub fn has_class(&self, name: &str) -> bool {
+        self.classes().any(|class| class == name)
+    }
+
+    /// Returns the declarations of the inline style of a tag
+    ///
+    /// The `style` attribute is split into `property: value` declarations,
+    /// separated by semicolons. The properties and values are trimmed, and the
+    /// declarations without a colon are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The pairs `(property, value)`, or an empty vector if the node isn't a
+    /// tag or doesn't have a `style` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<p style="color:red; margin: 0 auto;">"#).unwrap();
+    /// let styles = html.inline_styles();
+    /// assert!(styles[0] == ("color".to_owned(), "red".to_owned()));
+    /// assert!(styles[1] == ("margin".to_owned(), "0 auto".to_owned()));
+    /// assert!(styles.len() == 2);
+    /// ```
+    #[must_use]
+    pub fn inline_styles(&self) -> Vec<(String, String)> {
+        let Self::Tag { tag, .. } = self else {
+            return Vec::new();
+        };
+        tag.find_attr_value("style").map_or_else(Vec::new, |style| {
+            style
+                .split(';')
+                .filter_map(|declaration| declaration.split_once(':'))
+                .map(|(property, value)| (property.trim().to_owned(), value.trim().to_owned()))
+                .collect()
+        })
+    }
+
+    /// Converts the tree into the list of its top-level nodes
+    ///
+    /// See [`Html::as_fragment`] for the nodes of each root. The elements of an
+    /// [`Html::Vec`] are moved without being copied, but a single node is
+    /// moved into a new one-element [`Vec`]. Prefer [`Html::as_fragment`] when
+    /// the nodes only need to be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::Empty.into_fragment().is_empty());
+    /// assert!(Html::text("a").into_fragment() == [Html::text("a")]);
+    ///
+    /// let nodes = Html::parse("<!-- a --><br>").unwrap().into_fragment();
+    /// assert!(nodes.len() == 2);
+    /// assert!(nodes[1].to_string() == "<br>");
+    /// ```
+    #[must_use]
+    pub fn into_fragment(self) -> Vec<Self> {
+        match self {
+            Self::Empty => Vec::new(),
+            Self::Vec(vec) => vec.into_vec(),
+            node @ (Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Tag { .. }
+            | Self::Text(_)) => Vec::from([node]),
+        }
+    }
+
+    /// Checks if an [`Html`] tree is empty, i.e., is [`Html::Empty`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::new().is_empty());
+    /// assert!(Html::parse("").unwrap().is_empty());
+    /// assert!(!Html::text("text").is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
         matches!(self, Self::Empty)
     }
 
+    /// Iterates over the top-level nodes of the tree, in document order
+    ///
+    /// This is the iterator of [`Html::as_fragment`], also used by
+    /// `for node in &tree`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let tree = Html::parse("<p>a</p><p>b</p>").unwrap();
+    /// assert!(tree.iter().all(|node| node.matches("p").unwrap()));
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, Self> {
+        self.as_fragment().iter()
+    }
+
+    /// Returns the namespace of a tag
+    ///
+    /// The namespace tells how the names of the tag and of its attributes are
+    /// compared, for instance by [`Tag::find_attr_value`]. It is `None` for
+    /// the nodes that aren't tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<svg viewBox='0 0 1 1'><rect/></svg>").unwrap();
+    /// assert!(html.namespace() == Some(Namespace::Svg));
+    /// assert!(Html::parse("<p>a</p>").unwrap().namespace() == Some(Namespace::Html));
+    /// assert!(Html::text("a").namespace().is_none());
+    /// ```
+    #[must_use]
+    pub const fn namespace(&self) -> Option<Namespace> {
+        match self {
+            Self::Tag { tag, .. } => Some(tag.as_namespace()),
+            Self::BogusComment(_)
+            | Self::Comment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Text(_)
+            | Self::Vec(_) => None,
+        }
+    }
+
     /// Creates an empty [`Html`]
     #[must_use]
     pub const fn new() -> Self {
         Self::Empty
     }
+
+    /// Adds a child before the other children of a tag
+    ///
+    /// An [`Html::Empty`] node adds nothing, and the nodes of an
+    /// [`Html::Vec`] are added in order. Nodes that are not tags are left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut list = Html::parse("<ul><li>b</li></ul>").unwrap();
+    /// list.prepend_child(Html::parse("<li>a</li>").unwrap());
+    /// assert!(list.to_string() == "<ul><li>a</li><li>b</li></ul>");
+    /// ```
+    pub fn prepend_child(&mut self, node: Self) {
+        if let Self::Tag { child, .. } = self {
+            let mut children = node.into_fragment();
+            children.extend(mem::take(&mut **child).into_fragment());
+            **child = Self::from_nodes(children);
+        }
+    }
+
+    /// Replaces the node by another one, and returns the old node
+    ///
+    /// This is [`mem::replace`] on a node of the tree, like one of the
+    /// children given by [`Html::children_mut`]. Replacing a node by
+    /// [`Html::Empty`] removes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<p>Hello <b>name</b></p>").unwrap();
+    /// let name = html.children_mut().nth(1).unwrap();
+    /// let old = name.replace_with(Html::text("world"));
+    /// assert!(old.to_string() == "<b>name</b>");
+    /// assert!(html.to_string() == "<p>Hello world</p>");
+    /// ```
+    #[must_use]
+    pub const fn replace_with(&mut self, node: Self) -> Self {
+        mem::replace(self, node)
+    }
+
+    /// Creates a tag without children
+    ///
+    /// The attributes are given as pairs of names and optional values. The
+    /// values are displayed between double quotes. Use [`Html::with_child`] to
+    /// add children to the tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let link = Html::tag("a", &[("href", Some("/home")), ("hidden", None)]);
+    /// assert!(link.to_string() == r#"<a href="/home" hidden></a>"#);
+    /// ```
+    #[must_use]
+    pub fn tag<T>(name: T, attrs: &[(&str, Option<&str>)]) -> Self
+    where
+        T: Into<String>,
+    {
+        let name_str = name.into();
+        let namespace = Namespace::from_parent(&name_str, Namespace::Html);
+        let attributes = attrs
+            .iter()
+            .map(|(attr_name, value)| {
+                value.map_or_else(
+                    || Attribute::NameNoValue((*attr_name).to_owned()),
+                    |value_str| Attribute::NameValue {
+                        double_quote: true,
+                        name: (*attr_name).to_owned(),
+                        value: value_str.to_owned(),
+                    },
+                )
+            })
+            .collect();
+        Self::from(Tag::from((name_str, attributes, namespace)))
+    }
+
+    /// Creates a text node
+    ///
+    /// The text is displayed as is, without escaping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// assert!(Html::text("Hello").to_string() == "Hello");
+    /// ```
+    #[must_use]
+    pub fn text<T>(text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::Text(text.into())
+    }
+
+    /// Replaces a tag by its children
+    ///
+    /// Nodes that are not tags are left unchanged. A tag without children,
+    /// like `<br>`, becomes an [`Html::Empty`], and a tag with several
+    /// children becomes an [`Html::Vec`] of them.
+    ///
+    /// The node doesn't know its parent, so a tag unwrapped among its
+    /// siblings, like one of the children given by [`Html::children_mut`],
+    /// leaves a nested [`Html::Vec`] or an [`Html::Empty`] in the list of its
+    /// siblings. Call [`Html::flatten`] on the parent to splice its children
+    /// into that list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::parse("<span>a<b>b</b></span>").unwrap();
+    /// html.unwrap_tag();
+    /// assert!(html.to_string() == "a<b>b</b>");
+    /// ```
+    pub fn unwrap_tag(&mut self) {
+        if let Self::Tag { child, .. } = self {
+            *self = mem::take(&mut **child);
+        }
+    }
+
+    /// Adds a child after the other children of a tag
+    ///
+    /// Nodes that are not tags are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let list = Html::tag("ul", &[])
+    ///     .with_child(Html::tag("li", &[]).with_child(Html::text("First")))
+    ///     .with_child(Html::tag("li", &[]).with_child(Html::text("Second")));
+    /// assert!(list.to_string() == "<ul><li>First</li><li>Second</li></ul>");
+    /// ```
+    #[must_use]
+    pub fn with_child(mut self, node: Self) -> Self {
+        self.append_child(node);
+        self
+    }
+
+    /// Wraps the node in a tag, as its only child
+    ///
+    /// If the node is an [`Html::Vec`], all its nodes become the children of
+    /// the tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut html = Html::text("bold");
+    /// html.wrap(Tag::builder("b").build());
+    /// assert!(html.to_string() == "<b>bold</b>");
+    /// ```
+    pub fn wrap(&mut self, tag: Tag) {
+        let child = Box::new(mem::take(self));
+        *self = Self::Tag { tag, child };
+    }
 }
 
-#[expect(clippy::min_ident_chars, reason = "keep trait naming")]
-impl fmt::Display for Html {
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Eq for Html {}
+
+/// Creates a tag without children.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let link =
+///     Html::from(Tag::builder("a").attr("href", "/").build()).with_child(Html::text("Home"));
+/// assert!(link.to_string() == r#"<a href="/">Home</a>"#);
+/// ```
+impl From<Tag> for Html {
+    fn from(tag: Tag) -> Self {
+        Self::Tag { tag, child: Box::new(Self::Empty) }
+    }
+}
+
+/// Iterates over the top-level nodes of the tree, in document order.
+///
+/// This yields the elements of an [`Html::Vec`], nothing for an
+/// [`Html::Empty`], and the node itself otherwise, as
+/// [`Html::into_fragment`].
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let tree = Html::parse("<p>a</p>b").unwrap();
+/// let nodes = tree
+///     .into_iter()
+///     .map(|node| node.to_string())
+///     .collect::<Vec<_>>();
+/// assert!(nodes == ["<p>a</p>", "b"]);
+/// ```
+impl IntoIterator for Html {
+    type IntoIter = vec::IntoIter<Self>;
+    type Item = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_fragment().into_iter()
+    }
+}
+
+/// Iterates over references to the top-level nodes of the tree, in document
+/// order.
+///
+/// This yields the elements of [`Html::as_fragment`], without copying the
+/// tree.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let tree = Html::parse("<li>a</li><li>b</li>").unwrap();
+/// let mut count = 0;
+/// for node in &tree {
+///     assert!(node.matches("li").unwrap());
+///     count += 1;
+/// }
+/// assert!(count == 2);
+/// ```
+impl<'html> IntoIterator for &'html Html {
+    type IntoIter = slice::Iter<'html, Html>;
+    type Item = &'html Html;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Hashes the trees node by node.
+///
+/// The tags are hashed with the [`Hash`] implementation of [`Tag`], so the
+/// trees that only differ by the order of their attributes have the same
+/// hash. The original sources stored with
+/// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source)
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use html_filter::prelude::*;
+///
+/// let mut cache = HashMap::new();
+/// let tree = Html::parse(r#"<p id="a" class="b">text</p>"#).unwrap();
+/// cache.insert(tree.clone(), tree.text_content(false));
+/// assert!(cache.get(&tree).is_some_and(|text| text == "text"));
+/// ```
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Hash for Html {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        mem::discriminant(self).hash(state);
+        match self {
+            Self::BogusComment(text) | Self::Comment(text) | Self::Text(text) => text.hash(state),
+            Self::Conditional(condition) => condition.hash(state),
+            Self::Doctype { name, attr, subset, .. } => {
+                name.hash(state);
+                attr.hash(state);
+                subset.hash(state);
+            }
+            Self::Empty => (),
+            Self::Tag { tag, child } => {
+                tag.hash(state);
+                child.hash(state);
+            }
+            Self::Vec(vec) => vec.hash(state),
+        }
+    }
+}
+
+/// Compares the trees node by node.
+///
+/// The original sources stored with
+/// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source)
+/// are ignored.
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl PartialEq for Html {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::BogusComment(left), Self::BogusComment(right))
+            | (Self::Comment(left), Self::Comment(right))
+            | (Self::Text(left), Self::Text(right)) => left == right,
+            (Self::Conditional(left), Self::Conditional(right)) => left == right,
+            (
+                Self::Doctype { name, attr, subset, .. },
+                Self::Doctype { name: other_name, attr: other_attr, subset: other_subset, .. },
+            ) => name == other_name && attr == other_attr && subset == other_subset,
+            (Self::Empty, Self::Empty) => true,
+            (Self::Tag { tag, child }, Self::Tag { tag: other_tag, child: other_child }) =>
+                tag == other_tag && child == other_child,
+            (Self::Vec(left), Self::Vec(right)) => left == right,
+            (
+                Self::BogusComment(_)
+                | Self::Comment(_)
+                | Self::Conditional(_)
+                | Self::Doctype { .. }
+                | Self::Empty
+                | Self::Tag { .. }
+                | Self::Text(_)
+                | Self::Vec(_),
+                _,
+            ) => false,
+        }
+    }
+}
+
+/// Formats the tree for debugging.
+///
+/// The default format, `{:?}`, is the one of a derived implementation. The
+/// alternate format, `{:#?}`, is an indented outline of the tree, with one
+/// node per line: the children of a tag are indented below it, and the
+/// attributes of the tags are written as in HTML.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let html = Html::parse(r#"<div id="a">a<br>b</div>"#).unwrap();
+/// assert!(
+///     format!("{html:#?}")
+///         == r#"Tag(div id="a")
+///   Text("a")
+///   Tag(br)
+///   Text("b")"#
+/// );
+/// assert!(format!("{:?}", Html::text("a")) == r#"Text("a")"#);
+/// ```
+impl fmt::Debug for Html {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.fmt_outline(0, f);
+        }
         match self {
-            Self::Empty => "".fmt(f),
-            Self::Tag { tag, child } if tag.as_name() == "br" => write!(f, "<br>{child}"),
-            Self::Tag { tag, child } => write!(f, "<{tag}>{child}</{}>", tag.as_name()),
-            Self::Doctype { name, attr } => match (name, attr) {
-                (name_str, Some(attr_str)) => write!(f, "<!{name_str} {attr_str}>"),
-                (name_str, None) if name_str.is_empty() => write!(f, "<!>"),
-                (name_str, None) => write!(f, "<!{name_str} >"),
-            },
-            Self::Text(text) => text.fmt(f),
-            Self::Vec(vec) => vec.iter().try_for_each(|html| html.fmt(f)),
-            Self::Comment(content) => write!(f, "<!--{content}-->"),
+            Self::BogusComment(content) => f.debug_tuple("BogusComment").field(content).finish(),
+            Self::Comment(content) => f.debug_tuple("Comment").field(content).finish(),
+            Self::Conditional(condition) => f.debug_tuple("Conditional").field(condition).finish(),
+            Self::Doctype { name, attr, subset, source } => f
+                .debug_struct("Doctype")
+                .field("name", name)
+                .field("attr", attr)
+                .field("subset", subset)
+                .field("source", source)
+                .finish(),
+            Self::Empty => f.write_str("Empty"),
+            Self::Tag { tag, child } => f
+                .debug_struct("Tag")
+                .field("tag", tag)
+                .field("child", child)
+                .finish(),
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Vec(vec) => f.debug_tuple("Vec").field(vec).finish(),
         }
     }
 }
+
+/// Displays the tree as HTML, with the default [`SerializePolicy`].
+///
+/// The tags are displayed with their original source if it was preserved
+/// (see [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source)),
+/// and generated otherwise. A self-closing tag, like `<br>` or a preserved
+/// `<img/>`, that was given children after the parsing is displayed with
+/// an explicit closing tag, so that its children stay inside it.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let br = Html::parse("<br>").unwrap();
+/// assert!(br.to_string() == "<br>");
+/// assert!(br.with_child(Html::text("a")).to_string() == "<br>a</br>");
+/// ```
+#[expect(clippy::min_ident_chars, reason = "keep trait naming")]
+impl fmt::Display for Html {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(&SerializePolicy::new(), f)
+    }
+}
@@ -1,9 +1,15 @@
 //! Module that defines a builder for the [`Html`] tree.
 
+use alloc::borrow::ToOwned as _;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::fmt;
 use core::mem::take;
 
 use super::html::Html;
+use super::serialize::{write_conditional, write_doctype};
 use super::tag::{Tag, TagType};
 use crate::errors::{safe_expect, safe_unreachable};
 
@@ -18,6 +24,10 @@ pub struct CommentFull(bool);
 #[non_exhaustive]
 #[derive(Debug, Default)]
 pub enum HtmlBuilder {
+    /// Bogus comment, like `<!foo>`
+    ///
+    /// See [`Html::BogusComment`].
+    BogusComment(String),
     /// Comment block
     ///
     /// # Example
@@ -40,6 +50,10 @@ pub enum HtmlBuilder {
         /// In the previous example, the content is `some content`.
         full: CommentFull,
     },
+    /// Marker of a parsed conditional comment
+    ///
+    /// See [`Html::Conditional`].
+    Conditional(Option<String>),
     /// Document tag.
     ///
     /// These are tags with exclamation marks
@@ -61,6 +75,10 @@ pub enum HtmlBuilder {
         ///
         /// In the previous example, the attribute is `HtmlBuilder`.
         attr: Option<String>,
+        /// Internal subset of the tag, between `[` and `]`
+        subset: Option<String>,
+        /// Original source of the tag, if it was stored
+        source: Option<String>,
     },
     /// Empty html tree
     ///
@@ -73,7 +91,6 @@ pub enum HtmlBuilder {
     ///
     /// - `<div id="blob">content</div>`
     /// - `<div attr />`
-    /// - `</>`
     #[non_exhaustive]
     Tag {
         /// Opening tag
@@ -112,51 +129,75 @@ pub enum HtmlBuilder {
 }
 
 impl HtmlBuilder {
-    /// Pushes a block comment into the [`HtmlBuilder`] tree
+    /// Closes the comment that is still open at the end of the tree, if any.
+    ///
+    /// # Returns
+    ///
+    /// `true` iff a comment was closed.
     pub fn close_comment(&mut self) -> bool {
         match self {
-            Self::Comment { full, .. } =>
-                if full.0 {
-                    false
-                } else {
-                    full.0 = true;
-                    true
-                },
-            Self::Text(_) | Self::Empty | Self::Doctype { .. } => false,
-            Self::Tag { full, child, .. } => full.is_open() && child.close_comment(),
-            Self::Vec(vec) =>
-                safe_expect!(vec.last_mut(), "Html vec built with one.").close_comment(),
+            Self::Comment { full, .. } if !full.0 => {
+                full.0 = true;
+                true
+            }
+            Self::Tag { full: TagType::Opened, child, .. } => child.close_comment(),
+            Self::Vec(vec) => vec.last_mut().is_some_and(Self::close_comment),
+            Self::BogusComment(_)
+            | Self::Comment { .. }
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Tag { .. }
+            | Self::Text(_) => false,
         }
     }
 
-    /// Method to find to close that last opened tag.
-    ///
-    /// This method finds the opened tag the closest to the leaves.
-    pub fn close_tag(&mut self, name: &str) -> Result<(), String> {
-        if self.close_tag_aux(name) {
-            Ok(())
-        } else {
-            Err(format!(
-                "Invalid closing tag: Found closing tag for '{name}' but it isn't open."
-            ))
+    /// Closes all the opened tags of the tree implicitly, without closing
+    /// source.
+    pub fn close_opened(&mut self) {
+        match self {
+            Self::Tag { full: full @ TagType::Opened, child, .. } => {
+                *full = TagType::Closed { implicit: true };
+                child.close_opened();
+            }
+            Self::Vec(vec) =>
+                if let Some(last) = vec.last_mut() {
+                    last.close_opened();
+                },
+            Self::BogusComment(_)
+            | Self::Comment { .. }
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Empty
+            | Self::Tag { .. }
+            | Self::Text(_) => (),
         }
     }
 
-    /// Wrapper for [`Self::close_tag`].
+    /// Closes the innermost opened tag `name`.
+    ///
+    /// The `source` of the closing tag is stored in the tag if the source of
+    /// its opening part was.
+    ///
+    /// The tags opened inside the closed tag are closed implicitly with it.
+    /// The tag itself is `implicit`ly closed if no closing tag was read for
+    /// it, like a `<td>` closed by the next one.
     ///
     /// # Returns
     ///
     /// `true` iff the tag was successfully closed.
-    pub fn close_tag_aux(&mut self, name: &str) -> bool {
+    pub fn close_tag_aux(&mut self, name: &str, source: Option<&str>, implicit: bool) -> bool {
         if let Self::Tag { tag, full: full @ TagType::Opened, child } = self {
-            child.close_tag_aux(name)
+            child.close_tag_aux(name, source, implicit)
                 || (tag.as_name() == name && {
-                    *full = TagType::Closed;
+                    *full = TagType::Closed { implicit };
+                    tag.set_close_source(source);
+                    child.close_opened();
                     true
                 })
         } else if let Self::Vec(vec) = self {
             vec.last_mut()
-                .is_some_and(|child| child.close_tag_aux(name))
+                .is_some_and(|child| child.close_tag_aux(name, source, implicit))
         } else {
             false
         }
@@ -167,20 +208,42 @@ impl HtmlBuilder {
         Box::new(Self::default())
     }
 
-    /// Creates a tree for a character.
-    pub fn from_char(ch: char) -> Self {
-        Self::Text(ch.to_string())
-    }
-
     /// Exports an [`HtmlBuilder`] into an [`Html`]
+    ///
+    /// The tags that are still opened are implicitly closed. The nested lists
+    /// of nodes are flattened, so the siblings are always in a single
+    /// [`Html::Vec`].
     pub fn into_html(self) -> Html {
         match self {
+            Self::BogusComment(content) => Html::BogusComment(content),
             Self::Comment { content, .. } => Html::Comment(content),
-            Self::Doctype { name, attr } => Html::Doctype { name, attr },
+            Self::Conditional(condition) => Html::Conditional(condition),
+            Self::Doctype { name, attr, subset, source } =>
+                Html::Doctype { name, attr, subset, source },
             Self::Empty => Html::Empty,
-            Self::Tag { tag, child, .. } => Html::Tag { tag, child: Box::new(child.into_html()) },
+            Self::Tag { mut tag, child, full } => {
+                if matches!(full, TagType::Closed { implicit: true } | TagType::Opened) {
+                    tag.set_implicitly_closed();
+                }
+                Html::Tag { tag, child: Box::new(child.into_html()) }
+            }
             Self::Text(text) => Html::Text(text),
-            Self::Vec(vec) => Html::Vec(vec.into_iter().map(Self::into_html).collect()),
+            Self::Vec(vec) => {
+                let mut nodes = Vec::with_capacity(vec.len());
+                for node in vec {
+                    match node.into_html() {
+                        Html::Empty => (),
+                        Html::Vec(nested) => nodes.extend(nested),
+                        html @ (Html::BogusComment(_)
+                        | Html::Comment(_)
+                        | Html::Conditional(_)
+                        | Html::Doctype { .. }
+                        | Html::Tag { .. }
+                        | Html::Text(_)) => nodes.push(html),
+                    }
+                }
+                Html::Vec(nodes.into_boxed_slice())
+            }
         }
     }
 
@@ -188,48 +251,61 @@ impl HtmlBuilder {
     ///
     /// This is to check if a new node needs to be created for the next data.
     ///
-    /// This method is different if the input is a char or not.
+    /// This method is different if the input is a text or not.
     #[coverage(off)]
-    pub fn is_pushable(&self, is_char: bool) -> bool {
+    pub fn is_pushable(&self, is_text: bool) -> bool {
         match self {
             Self::Empty | Self::Vec(_) => safe_unreachable("Vec or Empty can't be in vec"),
             Self::Tag { full, .. } => full.is_open(),
-            Self::Doctype { .. } => false,
-            Self::Text(_) => is_char,
+            Self::BogusComment(_) | Self::Conditional(_) | Self::Doctype { .. } => false,
+            Self::Text(_) => is_text,
             Self::Comment { full, .. } => !full.0,
         }
     }
 
-    /// Pushes one character into an [`HtmlBuilder`] tree.
-    pub fn push_char(&mut self, ch: char) {
-        match self {
-            Self::Empty => *self = Self::from_char(ch),
-            Self::Tag { child, full: TagType::Opened, .. } => child.push_char(ch),
-            Self::Doctype { .. }
-            | Self::Tag { full: TagType::Closed | TagType::SelfClosing, .. } =>
-                *self = Self::Vec(vec![take(self), Self::from_char(ch)]),
-            Self::Text(text) => text.push(ch),
-            Self::Vec(vec) => {
-                let last = safe_expect!(vec.last_mut(), "Initialised with one element.");
-                if last.is_pushable(true) {
-                    return last.push_char(ch);
-                }
-                vec.push(Self::from_char(ch));
-            }
-            Self::Comment { content, full } => {
-                if full.0 {
-                    // This means the comment is at the root
-                    *self = Self::Vec(vec![take(self), Self::from_char(ch)]);
-                } else {
-                    content.push(ch);
+    /// Returns the opened tags, from the outermost to the innermost.
+    pub fn opened_tags(&self) -> Vec<&Tag> {
+        let mut tags = Vec::new();
+        let mut node = self;
+        loop {
+            match node {
+                Self::Tag { tag, full: TagType::Opened, child } => {
+                    tags.push(tag);
+                    node = child;
                 }
+                Self::Vec(vec) => match vec.last() {
+                    Some(last) => node = last,
+                    None => return tags,
+                },
+                Self::BogusComment(_)
+                | Self::Comment { .. }
+                | Self::Conditional(_)
+                | Self::Doctype { .. }
+                | Self::Empty
+                | Self::Tag { .. }
+                | Self::Text(_) => return tags,
             }
         }
     }
 
+    /// Pushes a bogus comment, like `<!foo>`, into the [`HtmlBuilder`] tree
+    pub fn push_bogus_comment(&mut self, content: String) {
+        self.push_node(Self::BogusComment(content));
+    }
+
     /// Pushes a block comment into the [`HtmlBuilder`] tree
-    pub fn push_comment(&mut self) {
-        self.push_node(Self::Comment { content: String::new(), full: CommentFull(false) });
+    ///
+    /// `full` indicates if the closing `-->` of the comment was found.
+    pub fn push_comment(&mut self, content: String, full: bool) {
+        self.push_node(Self::Comment { content, full: CommentFull(full) });
+    }
+
+    /// Pushes the marker of a parsed conditional comment into the
+    /// [`HtmlBuilder`] tree
+    ///
+    /// See [`Html::Conditional`].
+    pub fn push_conditional(&mut self, condition: Option<String>) {
+        self.push_node(Self::Conditional(condition));
     }
 
     /// Pushes an [`HtmlBuilder`] tree into another one.
@@ -240,9 +316,13 @@ impl HtmlBuilder {
         match self {
             Self::Empty => *self = node,
             Self::Tag { child, full: TagType::Opened, .. } => child.push_node(node),
-            Self::Text(_)
+            // A full comment can only be found here at the root
+            Self::BogusComment(_)
+            | Self::Conditional(_)
+            | Self::Text(_)
             | Self::Doctype { .. }
-            | Self::Tag { full: TagType::Closed | TagType::SelfClosing, .. } =>
+            | Self::Comment { full: CommentFull(true), .. }
+            | Self::Tag { full: TagType::Closed { .. } | TagType::SelfClosing, .. } =>
                 *self = Self::Vec(vec![take(self), node]),
             Self::Vec(vec) => {
                 let last = safe_expect!(vec.last_mut(), "Initialised with one element.");
@@ -255,6 +335,42 @@ impl HtmlBuilder {
         }
     }
 
+    /// Pushes a text into an [`HtmlBuilder`] tree.
+    ///
+    /// The text is appended to the last text or opened comment if possible,
+    /// so a run of text is pushed in one go, with a single descent into the
+    /// opened tags. Empty texts are ignored.
+    pub fn push_str(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        match self {
+            Self::Empty => *self = Self::Text(text.to_owned()),
+            Self::Tag { child, full: TagType::Opened, .. } => child.push_str(text),
+            Self::BogusComment(_)
+            | Self::Conditional(_)
+            | Self::Doctype { .. }
+            | Self::Tag { full: TagType::Closed { .. } | TagType::SelfClosing, .. } =>
+                *self = Self::Vec(vec![take(self), Self::Text(text.to_owned())]),
+            Self::Text(old) => old.push_str(text),
+            Self::Vec(vec) => {
+                let last = safe_expect!(vec.last_mut(), "Initialised with one element.");
+                if last.is_pushable(true) {
+                    return last.push_str(text);
+                }
+                vec.push(Self::Text(text.to_owned()));
+            }
+            Self::Comment { content, full } => {
+                if full.0 {
+                    // This means the comment is at the root
+                    *self = Self::Vec(vec![take(self), Self::Text(text.to_owned())]);
+                } else {
+                    content.push_str(text);
+                }
+            }
+        }
+    }
+
     /// Pushes a tag into an [`HtmlBuilder`] tree.
     pub fn push_tag(&mut self, tag: Tag, inline: bool) {
         self.push_node(Self::Tag {
@@ -274,17 +390,17 @@ impl HtmlBuilder {
 impl fmt::Display for HtmlBuilder {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::BogusComment(content) => write!(f, "<!{content}>"),
+            Self::Conditional(condition) => write_conditional(condition.as_deref(), f),
             Self::Empty => "".fmt(f),
             Self::Tag { tag, full, child } => match full {
-                TagType::Closed => write!(f, "<{tag}>{child}</{}>", tag.as_name()),
+                TagType::SelfClosing if matches!(**child, Self::Empty) => write!(f, "<{tag} />"),
+                TagType::Closed { .. } | TagType::SelfClosing =>
+                    write!(f, "<{tag}>{child}</{}>", tag.as_name()),
                 TagType::Opened => write!(f, "<{tag}>{child}"),
-                TagType::SelfClosing => write!(f, "<{tag} />"),
-            },
-            Self::Doctype { name, attr } => match (name, attr) {
-                (name_str, Some(attr_str)) => write!(f, "<!{name_str} {attr_str}>"),
-                (name_str, None) if name_str.is_empty() => write!(f, "<!>"),
-                (name_str, None) => write!(f, "<!{name_str} >"),
             },
+            Self::Doctype { name, attr, subset, .. } =>
+                write_doctype(name, attr.as_deref(), subset.as_deref(), f),
             Self::Text(text) => text.fmt(f),
             Self::Vec(vec) => vec.iter().try_for_each(|html| html.fmt(f)),
             Self::Comment { content, full } => f
@@ -1,5 +1,19 @@
 //! Module to define the types needed to make an Html Dom tree.
 
+pub mod category;
+pub mod cursor;
+pub mod dot;
+pub mod equality;
+pub mod events;
+pub mod flat;
 pub mod html;
 pub mod html_builder;
+pub mod output_map;
+pub mod patch;
+pub mod pretty;
+pub mod serialize;
+pub mod stats;
 pub mod tag;
+pub mod tree_builder;
+pub mod validation;
+pub mod xhtml;
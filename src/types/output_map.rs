@@ -0,0 +1,118 @@
+//! Module to map the serialised output of an [`Html`] tree back to its nodes.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Write as _};
+use core::ops::Range;
+
+use super::html::Html;
+
+/// Byte ranges of the nodes in a serialised output, with their paths
+///
+/// This is returned by [`Html::write_with_map`].
+pub type OutputMap = Vec<(Range<usize>, Vec<usize>)>;
+
+/// Writer that counts the bytes written into another writer
+struct CountingWriter<'writer, W: ?Sized> {
+    /// Number of bytes written so far
+    len: usize,
+    /// Writer receiving the output
+    writer: &'writer mut W,
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl<W> fmt::Write for CountingWriter<'_, W>
+where
+    W: fmt::Write + ?Sized,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_str(s)?;
+        self.len = self.len.saturating_add(s.len());
+        Ok(())
+    }
+}
+
+impl Html {
+    /// Writes the tree into a writer, and maps the output back to the nodes
+    ///
+    /// The output is the same as the one of the [`Display`](fmt::Display)
+    /// implementation. For each node, the map contains the byte range that
+    /// the node occupies in the output, and its path, as in
+    /// [`Html::at_path`]. The nodes are listed in document order, each tag
+    /// before its children, so the ranges of the top-level nodes cover the
+    /// output one after the other, and the ranges of the children of a tag
+    /// are inside the range of the tag, between its opening and closing tags.
+    ///
+    /// This allows finding the node under a position of the output, like the
+    /// cursor of an editor: it is the last node whose range contains the
+    /// position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>Hi <b>you</b></p>").unwrap();
+    /// let mut output = String::new();
+    /// let map = html.write_with_map(&mut output).unwrap();
+    /// assert!(output == "<p>Hi <b>you</b></p>");
+    /// assert!(map[..3] == [(0..20, vec![0]), (3..6, vec![0, 0]), (6..16, vec![0, 1])]);
+    /// assert!(map[3] == (9..12, vec![0, 1, 0]));
+    ///
+    /// let cursor = 7;
+    /// let (_, path) = map
+    ///     .iter()
+    ///     .rfind(|(range, _)| range.contains(&cursor))
+    ///     .unwrap();
+    /// assert!(html.at_path(path).unwrap().to_string() == "<b>you</b>");
+    /// ```
+    pub fn write_with_map<W>(&self, writer: &mut W) -> Result<OutputMap, fmt::Error>
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let mut counter = CountingWriter { len: 0, writer };
+        let mut map = Vec::new();
+        write_mapped(self.as_fragment(), &mut Vec::new(), &mut counter, &mut map)?;
+        Ok(map)
+    }
+}
+
+/// Writes the given sibling nodes and their children, and pushes their
+/// ranges into the map.
+///
+/// The `path` is the one of the parent.
+fn write_mapped<W>(
+    nodes: &[Html],
+    path: &mut Vec<usize>,
+    counter: &mut CountingWriter<'_, W>,
+    map: &mut OutputMap,
+) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    for (idx, node) in nodes.iter().enumerate() {
+        path.push(idx);
+        let start = counter.len;
+        let entry = map.len();
+        map.push((start..start, path.clone()));
+        if let Html::Tag { tag, child } = node {
+            let empty = child.is_empty();
+            write!(counter, "{}", fmt::from_fn(|formatter| tag.fmt_open(empty, formatter)))?;
+            write_mapped(child.as_fragment(), path, counter, map)?;
+            write!(counter, "{}", fmt::from_fn(|formatter| tag.fmt_close(empty, formatter)))?;
+        } else {
+            write!(counter, "{node}")?;
+        }
+        if let Some((range, _)) = map.get_mut(entry) {
+            range.end = counter.len;
+        }
+        path.pop();
+    }
+    Ok(())
+}
@@ -0,0 +1,515 @@
+//! Module to compute the differences between two [`Html`] trees.
+
+use alloc::borrow::ToOwned as _;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::{fmt, mem};
+
+use super::html::Html;
+use super::tag::{Attribute, Tag};
+
+/// Errors returned when a [`Patch`] couldn't be applied
+///
+/// See [`Html::apply`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplyError {
+    /// The path doesn't lead to a node of the tree
+    #[non_exhaustive]
+    InvalidPath {
+        /// Path of the patch
+        path: Vec<usize>,
+    },
+    /// The attribute to remove wasn't found on the tag
+    #[non_exhaustive]
+    MissingAttribute {
+        /// Name of the attribute
+        name: String,
+        /// Path of the tag
+        path: Vec<usize>,
+    },
+    /// The node at the path isn't of the kind expected by the patch
+    ///
+    /// # Examples
+    ///
+    /// A [`Patch::SetText`] on a tag.
+    #[non_exhaustive]
+    UnexpectedNode {
+        /// Kind of node expected by the patch, like `tag`
+        expected: &'static str,
+        /// Path of the node
+        path: Vec<usize>,
+    },
+}
+
+impl fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPath { path } => {
+                f.write_str("Path ")?;
+                fmt_path(f, path)?;
+                f.write_str(" doesn't lead to a node.")
+            }
+            Self::MissingAttribute { name, path } => {
+                f.write_str("Tag at ")?;
+                fmt_path(f, path)?;
+                write!(f, " doesn't have an attribute '{name}'.")
+            }
+            Self::UnexpectedNode { expected, path } => {
+                f.write_str("Node at ")?;
+                fmt_path(f, path)?;
+                write!(f, " isn't a {expected}.")
+            }
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Error for ApplyError {}
+
+/// Edit to apply on an [`Html`] tree
+///
+/// Patches are returned by [`Html::diff`] and applied by [`Html::apply`]. Each
+/// patch addresses a node with a path from the root: the first index is the
+/// position of the node among the top-level nodes (the elements of a root
+/// [`Html::Vec`], or the root itself), and each following index is the position
+/// among the children of a tag.
+///
+/// The paths refer to the tree as it is when the patch is applied, i.e., after
+/// the previous patches of the list were applied.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Patch {
+    /// Inserts a node, so that it ends up at the given path
+    Insert {
+        /// Node to insert
+        node: Html,
+        /// Path of the inserted node
+        path: Vec<usize>,
+    },
+    /// Removes the node at the given path
+    Remove {
+        /// Path of the node to remove
+        path: Vec<usize>,
+    },
+    /// Removes an attribute of the tag at the given path
+    RemoveAttribute {
+        /// Name of the attribute to remove
+        name: String,
+        /// Path of the tag
+        path: Vec<usize>,
+    },
+    /// Replaces the node at the given path
+    ///
+    /// This is used when the nodes are too different to be patched, like a
+    /// tag replaced by a text, or a tag renamed.
+    Replace {
+        /// New node
+        node: Html,
+        /// Path of the node to replace
+        path: Vec<usize>,
+    },
+    /// Sets an attribute of the tag at the given path
+    ///
+    /// The attribute replaces the first attribute of the tag with the same
+    /// name, or is added after the other attributes if there is none.
+    SetAttribute {
+        /// New attribute
+        attr: Attribute,
+        /// Path of the tag
+        path: Vec<usize>,
+    },
+    /// Sets the content of the text node at the given path
+    SetText {
+        /// Path of the text node
+        path: Vec<usize>,
+        /// New content
+        text: String,
+    },
+}
+
+impl Patch {
+    /// Returns the path of the node the patch applies to
+    #[must_use]
+    pub fn as_path(&self) -> &[usize] {
+        match self {
+            Self::Insert { path, .. }
+            | Self::Remove { path }
+            | Self::RemoveAttribute { path, .. }
+            | Self::Replace { path, .. }
+            | Self::SetAttribute { path, .. }
+            | Self::SetText { path, .. } => path,
+        }
+    }
+}
+
+impl Html {
+    /// Applies patches to the tree, in order
+    ///
+    /// Applying the patches returned by [`Html::diff`] on the old tree gives a
+    /// tree equal to the new one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApplyError`] if the path of a patch doesn't lead to a node
+    /// of the right kind. The patches before the failing one stay applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let old = Html::parse("<p class='a'>Old</p>").unwrap();
+    /// let new = Html::parse("<p class='b'>New</p><br>").unwrap();
+    /// let mut tree = old.clone();
+    /// tree.apply(&Html::diff(&old, &new)).unwrap();
+    /// assert!(tree == new);
+    ///
+    /// let err = tree
+    ///     .apply(&[Patch::Remove { path: vec![0, 3] }])
+    ///     .unwrap_err();
+    /// assert!(matches!(err, ApplyError::InvalidPath { .. }));
+    /// assert!(err.to_string() == "Path [0, 3] doesn't lead to a node.");
+    /// ```
+    pub fn apply(&mut self, patches: &[Patch]) -> Result<(), ApplyError> {
+        patches.iter().try_for_each(|patch| {
+            self.with_nodes(|nodes| apply_patch(nodes, patch.as_path(), patch))
+        })
+    }
+
+    /// Returns the node at the given path, if any
+    ///
+    /// See [`Patch`] for the meaning of the paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let tree = Html::parse("<ul><li>A</li><li>B</li></ul><p>C</p>").unwrap();
+    /// assert!(tree.at_path(&[0, 1]).unwrap().to_string() == "<li>B</li>");
+    /// assert!(tree.at_path(&[1, 0]).unwrap().to_string() == "C");
+    /// assert!(tree.at_path(&[1, 0, 0]).is_none());
+    /// assert!(tree.at_path(&[]).is_none());
+    /// ```
+    #[must_use]
+    pub fn at_path(&self, path: &[usize]) -> Option<&Self> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.as_fragment().get(*first)?, |node, idx| node.children_slice().get(*idx))
+    }
+
+    /// Computes the patches to transform the tree `old` into the tree `new`
+    ///
+    /// The children are compared by position: the common nodes at the
+    /// beginning and at the end of the lists are skipped, and the remaining
+    /// nodes are compared one by one. Tags with the same name are patched
+    /// (attributes and children), text nodes are updated, and the other nodes
+    /// are replaced.
+    ///
+    /// See [`Patch`] for the meaning of the paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let old = Html::parse("<ul><li>First</li><li>Third</li></ul>").unwrap();
+    /// let new = Html::parse("<ul><li>First</li><li>Second</li><li>Third</li></ul>").unwrap();
+    /// let patches = Html::diff(&old, &new);
+    /// assert!(patches.len() == 1);
+    /// assert!(matches!(&patches[0], Patch::Insert { path, .. } if path == &[0, 1]));
+    /// ```
+    #[must_use]
+    pub fn diff(old: &Self, new: &Self) -> Vec<Patch> {
+        let mut patches = Vec::new();
+        diff_nodes(old.as_fragment(), new.as_fragment(), &[], &mut patches);
+        patches
+    }
+
+    /// Calls a function on the list of nodes represented by the tree, and
+    /// stores the modified list back in the tree.
+    ///
+    /// See [`Html::as_fragment`] for the list of nodes of a tree.
+    fn with_nodes<T>(&mut self, update: impl FnOnce(&mut Vec<Self>) -> T) -> T {
+        let mut nodes = mem::take(self).into_fragment();
+        let result = update(&mut nodes);
+        *self = Self::from_nodes(nodes);
+        result
+    }
+
+    /// Writes the subtree at the given path
+    ///
+    /// The output is the same as the [`Display`](fmt::Display) of the node
+    /// returned by [`Html::at_path`], but the rest of the tree isn't
+    /// serialised. This is useful to send only the fragments changed by
+    /// [`Html::apply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path doesn't lead to a node, or if the writer
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let tree = Html::parse("<div><p>Old</p><p>Same</p></div>").unwrap();
+    /// let new = Html::parse("<div><p>New</p><p>Same</p></div>").unwrap();
+    /// let patches = Html::diff(&tree, &new);
+    ///
+    /// let mut fragment = String::new();
+    /// new.write_path(&patches[0].as_path()[..2], &mut fragment)
+    ///     .unwrap();
+    /// assert!(fragment == "<p>New</p>");
+    /// assert!(new.write_path(&[3], &mut fragment).is_err());
+    /// ```
+    pub fn write_path<W>(&self, path: &[usize], writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        write!(writer, "{}", self.at_path(path).ok_or(fmt::Error)?)
+    }
+}
+
+/// Applies a patch on a list of siblings.
+///
+/// `relative` is the part of the patch's path that is relative to the
+/// siblings.
+fn apply_patch(nodes: &mut Vec<Html>, relative: &[usize], patch: &Patch) -> Result<(), ApplyError> {
+    let invalid_path = || ApplyError::InvalidPath { path: patch.as_path().to_vec() };
+    let unexpected_node =
+        |expected| ApplyError::UnexpectedNode { expected, path: patch.as_path().to_vec() };
+    let (&index, rest) = relative.split_first().ok_or_else(invalid_path)?;
+    if !rest.is_empty() {
+        return match nodes.get_mut(index) {
+            Some(Html::Tag { child, .. }) =>
+                child.with_nodes(|children| apply_patch(children, rest, patch)),
+            Some(_) => Err(unexpected_node("tag")),
+            None => Err(invalid_path()),
+        };
+    }
+    match patch {
+        Patch::Insert { node, .. } if index <= nodes.len() => nodes.insert(index, node.clone()),
+        Patch::Remove { .. } if index < nodes.len() => {
+            nodes.remove(index);
+        }
+        Patch::Insert { .. } | Patch::Remove { .. } => return Err(invalid_path()),
+        Patch::RemoveAttribute { name, .. } => match nodes.get_mut(index) {
+            Some(Html::Tag { tag, .. }) =>
+                if !tag.remove_attr(name) {
+                    return Err(ApplyError::MissingAttribute {
+                        name: name.to_owned(),
+                        path: patch.as_path().to_vec(),
+                    });
+                },
+            Some(_) => return Err(unexpected_node("tag")),
+            None => return Err(invalid_path()),
+        },
+        Patch::Replace { node, .. } =>
+            *nodes.get_mut(index).ok_or_else(invalid_path)? = node.clone(),
+        Patch::SetAttribute { attr, .. } => match nodes.get_mut(index) {
+            Some(Html::Tag { tag, .. }) => tag.set_attr(attr.clone()),
+            Some(_) => return Err(unexpected_node("tag")),
+            None => return Err(invalid_path()),
+        },
+        Patch::SetText { text, .. } => match nodes.get_mut(index) {
+            Some(Html::Text(old)) => old.clone_from(text),
+            Some(_) => return Err(unexpected_node("text")),
+            None => return Err(invalid_path()),
+        },
+    }
+    Ok(())
+}
+
+/// Returns the path of the `index`-th child of the node at `path`.
+fn child_path(path: &[usize], index: usize) -> Vec<usize> {
+    let mut child = path.to_vec();
+    child.push(index);
+    child
+}
+
+/// Computes the patches to transform the attributes of a tag into those of
+/// another tag.
+///
+/// Returns `None` if the attributes can't be patched, e.g. when they were
+/// reordered.
+fn diff_attrs(old: &Tag, new: &Tag, path: &[usize]) -> Option<Vec<Patch>> {
+    if has_duplicate_names(old.as_attrs()) || has_duplicate_names(new.as_attrs()) {
+        return None;
+    }
+    let mut patches = Vec::new();
+    let mut names = Vec::new();
+    for attr in old.as_attrs() {
+        if new
+            .as_attrs()
+            .iter()
+            .any(|new_attr| new_attr.as_name() == attr.as_name())
+        {
+            names.push(attr.as_name());
+        } else {
+            patches.push(Patch::RemoveAttribute {
+                name: attr.as_name().to_owned(),
+                path: path.to_vec(),
+            });
+        }
+    }
+    for attr in new.as_attrs() {
+        match old
+            .as_attrs()
+            .iter()
+            .find(|old_attr| old_attr.as_name() == attr.as_name())
+        {
+            Some(old_attr) if is_same_attr(old_attr, attr) => (),
+            Some(_) =>
+                patches.push(Patch::SetAttribute { attr: attr.clone(), path: path.to_vec() }),
+            None => {
+                names.push(attr.as_name());
+                patches.push(Patch::SetAttribute { attr: attr.clone(), path: path.to_vec() });
+            }
+        }
+    }
+    names
+        .into_iter()
+        .eq(new.as_attrs().iter().map(Attribute::as_name))
+        .then_some(patches)
+}
+
+/// Computes the patches to transform the node at `path` into `new`.
+fn diff_node(old: &Html, new: &Html, path: &[usize], patches: &mut Vec<Patch>) {
+    if is_same_node(old, new) {
+        return;
+    }
+    match (old, new) {
+        (Html::Text(_), Html::Text(text)) =>
+            patches.push(Patch::SetText { path: path.to_vec(), text: text.to_owned() }),
+        (Html::Tag { tag, child }, Html::Tag { tag: new_tag, child: new_child })
+            if tag.as_name() == new_tag.as_name()
+                && tag.as_namespace() == new_tag.as_namespace() =>
+            if let Some(attr_patches) = diff_attrs(tag, new_tag, path) {
+                patches.extend(attr_patches);
+                diff_nodes(child.as_fragment(), new_child.as_fragment(), path, patches);
+            } else {
+                patches.push(Patch::Replace { node: new.clone(), path: path.to_vec() });
+            },
+        _ => patches.push(Patch::Replace { node: new.clone(), path: path.to_vec() }),
+    }
+}
+
+/// Computes the patches to transform the children of the node at `path`.
+///
+/// The paired nodes are patched first, then the extra old nodes are removed
+/// from the last to the first, and the extra new nodes are inserted, so that
+/// each path is valid when its patch is applied.
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "indices and lengths are bounded by the slice lengths"
+)]
+fn diff_nodes(old: &[Html], new: &[Html], path: &[usize], patches: &mut Vec<Patch>) {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old_node, new_node)| is_same_node(old_node, new_node))
+        .count();
+    let old_rest = old.get(prefix..).unwrap_or_default();
+    let new_rest = new.get(prefix..).unwrap_or_default();
+    let suffix = old_rest
+        .iter()
+        .rev()
+        .zip(new_rest.iter().rev())
+        .take_while(|(old_node, new_node)| is_same_node(old_node, new_node))
+        .count();
+    let old_mid = old_rest.get(..old_rest.len() - suffix).unwrap_or_default();
+    let new_mid = new_rest.get(..new_rest.len() - suffix).unwrap_or_default();
+    for (idx, (old_node, new_node)) in old_mid.iter().zip(new_mid).enumerate() {
+        diff_node(old_node, new_node, &child_path(path, prefix + idx), patches);
+    }
+    let paired = old_mid.len().min(new_mid.len());
+    for idx in (paired..old_mid.len()).rev() {
+        patches.push(Patch::Remove { path: child_path(path, prefix + idx) });
+    }
+    for (idx, node) in new_mid.iter().enumerate().skip(paired) {
+        patches.push(Patch::Insert { node: node.clone(), path: child_path(path, prefix + idx) });
+    }
+}
+
+/// Writes a path as a list of indices, like `[0, 2]`.
+pub fn fmt_path(formatter: &mut fmt::Formatter<'_>, path: &[usize]) -> fmt::Result {
+    formatter.write_str("[")?;
+    for (idx, index) in path.iter().enumerate() {
+        if idx > 0 {
+            formatter.write_str(", ")?;
+        }
+        write!(formatter, "{index}")?;
+    }
+    formatter.write_str("]")
+}
+
+/// Checks if two attributes of the list have the same name.
+fn has_duplicate_names(attrs: &[Attribute]) -> bool {
+    attrs.iter().enumerate().any(|(idx, attr)| {
+        attrs
+            .iter()
+            .skip(idx.saturating_add(1))
+            .any(|other| other.as_name() == attr.as_name())
+    })
+}
+
+/// Checks if two attributes are written identically, quotes included.
+fn is_same_attr(left: &Attribute, right: &Attribute) -> bool {
+    match (left, right) {
+        (Attribute::NameNoValue(left_name), Attribute::NameNoValue(right_name)) =>
+            left_name == right_name,
+        (
+            Attribute::NameValue { double_quote, name, value },
+            Attribute::NameValue {
+                double_quote: other_double_quote,
+                name: other_name,
+                value: other_value,
+            },
+        ) => double_quote == other_double_quote && name == other_name && value == other_value,
+        (Attribute::NameNoValue(_) | Attribute::NameValue { .. }, _) => false,
+    }
+}
+
+/// Checks if two nodes are written identically.
+///
+/// Unlike [`PartialEq`], the order of the attributes, the case of the names
+/// and the quotes of the values are compared, so the patched tree is written
+/// as the new one.
+fn is_same_node(left: &Html, right: &Html) -> bool {
+    match (left, right) {
+        (Html::Tag { tag, child }, Html::Tag { tag: other_tag, child: other_child }) =>
+            tag.as_name() == other_tag.as_name()
+                && tag.as_namespace() == other_tag.as_namespace()
+                && tag.as_attrs().len() == other_tag.as_attrs().len()
+                && tag
+                    .as_attrs()
+                    .iter()
+                    .zip(other_tag.as_attrs())
+                    .all(|(attr, other_attr)| is_same_attr(attr, other_attr))
+                && is_same_node(child, other_child),
+        (Html::Vec(nodes), Html::Vec(other_nodes)) =>
+            nodes.len() == other_nodes.len()
+                && nodes
+                    .iter()
+                    .zip(other_nodes)
+                    .all(|(node, other_node)| is_same_node(node, other_node)),
+        (
+            node @ (Html::BogusComment(_)
+            | Html::Comment(_)
+            | Html::Conditional(_)
+            | Html::Doctype { .. }
+            | Html::Empty
+            | Html::Tag { .. }
+            | Html::Text(_)
+            | Html::Vec(_)),
+            other_node,
+        ) => node == other_node,
+    }
+}
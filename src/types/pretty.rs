@@ -0,0 +1,162 @@
+//! Module to display an [`Html`] tree with one node per line.
+
+use alloc::string::{String, ToString as _};
+use core::fmt;
+
+use super::html::Html;
+use crate::parse::WHITESPACE_SENSITIVE;
+
+/// Wrapper to display an [`Html`] tree with one node per line
+///
+/// This is returned by [`Html::display_pretty`]. Each tag, text, comment and
+/// doctype is written on its own line, indented by `indent` spaces per level
+/// of depth:
+///
+/// - the texts are trimmed, and the whitespace-only texts are skipped;
+/// - the tags without children, or with a single text without line breaks, are
+///   written on a single line;
+/// - the whitespace-sensitive tags, like `<pre>` or `<script>`, are written on
+///   a single line as they are, as their content can't be indented.
+///
+/// The output is streamed into the formatter, without building the
+/// intermediate strings of the children.
+#[derive(Debug, Clone, Copy)]
+pub struct PrettyDisplay<'html> {
+    /// Tree to display
+    html: &'html Html,
+    /// Number of spaces per level of depth
+    indent: usize,
+}
+
+/// Private methods for [`PrettyDisplay`]
+impl PrettyDisplay<'_> {
+    /// Writes the given line at the given depth, followed by a line break.
+    fn write_line<T>(
+        &self,
+        line: &T,
+        depth: usize,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result
+    where
+        T: fmt::Display + ?Sized,
+    {
+        let width = self.indent.saturating_mul(depth);
+        writeln!(formatter, "{:width$}{line}", "")
+    }
+
+    /// Writes a node and its children at the given depth.
+    fn write_node(
+        &self,
+        node: &Html,
+        depth: usize,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match node {
+            Html::Empty => Ok(()),
+            Html::Text(text) if text.trim().is_empty() => Ok(()),
+            Html::Text(text) => self.write_line(text.trim(), depth, formatter),
+            Html::BogusComment(_)
+            | Html::Comment(_)
+            | Html::Conditional(_)
+            | Html::Doctype { .. } => self.write_line(node, depth, formatter),
+            Html::Tag { tag, child } => {
+                let inline = WHITESPACE_SENSITIVE
+                    .contains(&tag.as_name().to_ascii_lowercase().as_str())
+                    || match &**child {
+                        Html::Empty => true,
+                        Html::Text(text) => !text.contains('\n'),
+                        Html::BogusComment(_)
+                        | Html::Comment(_)
+                        | Html::Conditional(_)
+                        | Html::Doctype { .. }
+                        | Html::Tag { .. }
+                        | Html::Vec(_) => false,
+                    };
+                if inline {
+                    return self.write_line(node, depth, formatter);
+                }
+                let width = self.indent.saturating_mul(depth);
+                write!(formatter, "{:width$}", "")?;
+                tag.fmt_open(false, formatter)?;
+                formatter.write_str("\n")?;
+                self.write_node(child, depth.saturating_add(1), formatter)?;
+                write!(formatter, "{:width$}", "")?;
+                tag.fmt_close(false, formatter)?;
+                formatter.write_str("\n")
+            }
+            Html::Vec(vec) => vec
+                .iter()
+                .try_for_each(|child| self.write_node(child, depth, formatter)),
+        }
+    }
+}
+
+impl fmt::Display for PrettyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_node(self.html, 0, f)
+    }
+}
+
+impl Html {
+    /// Displays the tree with one node per line
+    ///
+    /// See [`PrettyDisplay`] for the layout of the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<ul><li>a</li><li>b</li></ul>").unwrap();
+    /// assert!(html.display_pretty(2).to_string() == "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>\n");
+    /// ```
+    #[must_use]
+    pub const fn display_pretty(&self, indent: usize) -> PrettyDisplay<'_> {
+        PrettyDisplay { html: self, indent }
+    }
+
+    /// Converts the tree into a string with one node per line
+    ///
+    /// See [`PrettyDisplay`] for the layout of the output, and
+    /// [`Html::write_pretty`] to write it without building the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<div>\n  <!-- note -->\n  <pre> a\n b</pre>\n</div>").unwrap();
+    /// assert!(html.to_pretty_string(1) == "<div>\n <!-- note -->\n <pre> a\n b</pre>\n</div>\n");
+    /// ```
+    #[must_use]
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        self.display_pretty(indent).to_string()
+    }
+
+    /// Writes the tree with one node per line into a writer
+    ///
+    /// The nodes are streamed into the writer, so no intermediate string is
+    /// built, even for large trees. The output is the same as the one of
+    /// [`Html::to_pretty_string`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<div><p>text</p><br></div>").unwrap();
+    /// let mut output = String::new();
+    /// html.write_pretty(&mut output, 4).unwrap();
+    /// assert!(output == "<div>\n    <p>text</p>\n    <br>\n</div>\n");
+    /// ```
+    pub fn write_pretty<W>(&self, writer: &mut W, indent: usize) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        write!(writer, "{}", self.display_pretty(indent))
+    }
+}
@@ -0,0 +1,521 @@
+//! Module to serialise an [`Html`] tree with a configurable policy.
+
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString as _};
+use core::fmt;
+
+use super::html::Html;
+use super::tag::{Attribute, Tag};
+use crate::parse::{VOID_ELEMENTS, WHITESPACE_SENSITIVE};
+
+/// Quotes of the attribute values
+///
+/// See [`SerializePolicy::quotes`].
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Writes all the values between double quotes, like `id="a"`
+    Double,
+    /// Keeps the quotes with which the values were written
+    #[default]
+    Keep,
+    /// Writes all the values between single quotes, like `id='a'`
+    Single,
+}
+
+/// Policy to serialise an [`Html`] tree
+///
+/// This is given to [`Html::outer_html_with`]. The default policy is the one
+/// of the [`Display`](fmt::Display) implementation of [`Html`], and each
+/// option changes one aspect of the output.
+///
+/// The original sources stored with
+/// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source)
+/// are only written when the policy doesn't change the syntax of the tags,
+/// that is without [`SerializePolicy::escape`], [`SerializePolicy::xhtml`],
+/// [`SerializePolicy::self_closing_slash`] and with [`QuoteStyle::Keep`].
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let html = Html::parse("<p class='a'>\n  <br>x &amp; y\n</p>").unwrap();
+/// let policy = SerializePolicy::new()
+///     .quotes(QuoteStyle::Double)
+///     .trim_whitespace(true)
+///     .xhtml(true);
+/// assert!(html.outer_html_with(&policy) == "<p class=\"a\"><br />x &amp; y\n</p>");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+#[expect(clippy::struct_excessive_bools, reason = "options are independent")]
+pub struct SerializePolicy {
+    /// Content of the doctype written before a tree that doesn't have one
+    ///
+    /// See [`SerializePolicy::ensure_doctype`].
+    ensure_doctype: Option<String>,
+    /// Escapes the special characters
+    ///
+    /// See [`SerializePolicy::escape`].
+    escape: bool,
+    /// Quotes of the attribute values
+    ///
+    /// See [`SerializePolicy::quotes`].
+    quotes: QuoteStyle,
+    /// Names of the empty tags written with a self-closing slash
+    ///
+    /// See [`SerializePolicy::self_closing_slash`].
+    self_closing_slash: BTreeSet<String>,
+    /// Ends the output with a line break
+    ///
+    /// See [`SerializePolicy::trailing_newline`].
+    trailing_newline: bool,
+    /// Skips the whitespace-only texts
+    ///
+    /// See [`SerializePolicy::trim_whitespace`].
+    trim_whitespace: bool,
+    /// Writes the tags with the XHTML syntax
+    ///
+    /// See [`SerializePolicy::xhtml`].
+    xhtml: bool,
+}
+
+/// Private methods for [`SerializePolicy`]
+impl SerializePolicy {
+    /// Checks if the preserved sources of the tags can be written.
+    fn keeps_sources(&self) -> bool {
+        !self.escape
+            && !self.xhtml
+            && matches!(self.quotes, QuoteStyle::Keep)
+            && self.self_closing_slash.is_empty()
+    }
+
+    /// Writes a name of a tag, in lowercase in XHTML if the tag is in the
+    /// HTML namespace.
+    fn write_name(&self, tag: &Tag, name: &str, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.xhtml && !tag.as_namespace().is_foreign() {
+            name.chars()
+                .try_for_each(|ch| fmt::Write::write_char(formatter, ch.to_ascii_lowercase()))
+        } else {
+            formatter.write_str(name)
+        }
+    }
+
+    /// Writes a node of the tree, in the given `context`.
+    fn write_node(
+        &self,
+        node: &Html,
+        context: Context,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match node {
+            Html::Tag { tag, child } => self.write_tag(tag, child, context, formatter),
+            Html::Text(text)
+                if self.trim_whitespace
+                    && !context.preformatted
+                    && text.bytes().all(|byte| byte.is_ascii_whitespace()) =>
+                Ok(()),
+            Html::Text(text) if self.escape && !context.raw => write_escaped(text, None, formatter),
+            Html::Vec(vec) => vec
+                .iter()
+                .try_for_each(|child| self.write_node(child, context, formatter)),
+            Html::BogusComment(comment) if !self.xhtml => write!(formatter, "<!{comment}>"),
+            Html::BogusComment(comment) | Html::Comment(comment) =>
+                write!(formatter, "<!--{comment}-->"),
+            Html::Conditional(condition) => write_conditional(condition.as_deref(), formatter),
+            Html::Doctype { source: Some(source), .. } => formatter.write_str(source),
+            Html::Doctype { name, attr, subset, source: None } =>
+                write_doctype(name, attr.as_deref(), subset.as_deref(), formatter),
+            Html::Empty => Ok(()),
+            Html::Text(text) => formatter.write_str(text),
+        }
+    }
+
+    /// Writes the opening part of a tag, without the closing `>`.
+    fn write_open(&self, tag: &Tag, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("<")?;
+        self.write_name(tag, tag.as_name(), formatter)?;
+        for attr in tag.as_attrs() {
+            formatter.write_str(" ")?;
+            self.write_name(tag, attr.as_name(), formatter)?;
+            match attr {
+                Attribute::NameNoValue(name) if self.xhtml => {
+                    formatter.write_str("=\"")?;
+                    self.write_name(tag, name, formatter)?;
+                    formatter.write_str("\"")?;
+                }
+                Attribute::NameNoValue(_) => (),
+                Attribute::NameValue { double_quote, value, .. } => {
+                    let quote = match self.quotes {
+                        QuoteStyle::Double => '"',
+                        QuoteStyle::Keep if *double_quote => '"',
+                        QuoteStyle::Keep | QuoteStyle::Single => '\'',
+                    };
+                    write!(formatter, "={quote}")?;
+                    if self.escape {
+                        write_escaped(value, Some(quote), formatter)?;
+                    } else {
+                        formatter.write_str(value)?;
+                    }
+                    write!(formatter, "{quote}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a tag and its children.
+    fn write_tag(
+        &self,
+        tag: &Tag,
+        child: &Html,
+        context: Context,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let name = tag.as_name().to_ascii_lowercase();
+        let html = !tag.as_namespace().is_foreign();
+        let child_context = Context {
+            preformatted: context.preformatted
+                || (html && WHITESPACE_SENSITIVE.contains(&name.as_str())),
+            raw: html && matches!(name.as_str(), "script" | "style"),
+        };
+        if self.keeps_sources() {
+            tag.fmt_open(child.is_empty(), formatter)?;
+            self.write_node(child, child_context, formatter)?;
+            return tag.fmt_close(child.is_empty(), formatter);
+        }
+        self.write_open(tag, formatter)?;
+        if self.xhtml && child.is_empty() && (!html || VOID_ELEMENTS.contains(&name.as_str())) {
+            return formatter.write_str(" />");
+        }
+        if child.is_empty()
+            && self
+                .self_closing_slash
+                .iter()
+                .any(|slashed| tag.as_namespace().eq_names(slashed, tag.as_name()))
+        {
+            return formatter.write_str("/>");
+        }
+        formatter.write_str(">")?;
+        self.write_node(child, child_context, formatter)?;
+        if !self.xhtml && child.is_empty() && tag.as_name() == "br" {
+            return Ok(());
+        }
+        formatter.write_str("</")?;
+        self.write_name(tag, tag.as_name(), formatter)?;
+        formatter.write_str(">")
+    }
+}
+
+/// Public API for [`SerializePolicy`]
+impl SerializePolicy {
+    /// Sets the doctype to write before the trees that don't have one
+    ///
+    /// - If `doctype` is `None` (default), the tree is written as it is.
+    /// - If `doctype` is `Some(content)`, `<!DOCTYPE content>` is written
+    ///   before the tree, unless one of its top-level nodes is already a
+    ///   doctype.
+    ///
+    /// This is useful to write complete documents to files, with
+    /// [`SerializePolicy::trailing_newline`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let policy = SerializePolicy::new().ensure_doctype(Some("html".to_owned()));
+    /// let html = Html::parse("<p>a</p>").unwrap();
+    /// assert!(html.outer_html_with(&policy) == "<!DOCTYPE html><p>a</p>");
+    ///
+    /// let html = Html::parse("<!doctype html><p>a</p>").unwrap();
+    /// assert!(html.outer_html_with(&policy) == "<!doctype html><p>a</p>");
+    /// ```
+    #[must_use]
+    pub fn ensure_doctype(mut self, doctype: Option<String>) -> Self {
+        self.ensure_doctype = doctype;
+        self
+    }
+
+    /// Sets the escaping of the special characters
+    ///
+    /// - If `escape` is set to `false` (default), the texts and attribute
+    ///   values are written as they are stored.
+    /// - If `escape` is set to `true`, `&`, `<` and `>` are escaped in the
+    ///   texts and attribute values, as well as the quote delimiting the
+    ///   attribute values. The content of `<script>` and `<style>` isn't
+    ///   escaped.
+    ///
+    /// This is useful for the trees built with
+    /// [`ParseOptions::decode_text_entities`](crate::prelude::ParseOptions::decode_text_entities)
+    /// or by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::from(Tag::builder("p").attr("title", "\"a\"").build())
+    ///     .with_child(Html::text("1 < 2 & 3"));
+    /// let policy = SerializePolicy::new().escape(true);
+    /// assert!(html.outer_html_with(&policy) == r#"<p title="&quot;a&quot;">1 &lt; 2 &amp; 3</p>"#);
+    /// ```
+    #[must_use]
+    pub const fn escape(mut self, escape: bool) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Creates a default [`SerializePolicy`]
+    ///
+    /// The default policy is the one of the [`Display`](fmt::Display)
+    /// implementation of [`Html`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ensure_doctype: None,
+            escape: false,
+            quotes: QuoteStyle::Keep,
+            self_closing_slash: BTreeSet::new(),
+            trailing_newline: false,
+            trim_whitespace: false,
+            xhtml: false,
+        }
+    }
+
+    /// Sets the quotes of the attribute values
+    ///
+    /// By default, the values keep the quotes with which they were written.
+    /// See [`QuoteStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<a href='/' title="home">x</a>"#).unwrap();
+    /// let policy = SerializePolicy::new().quotes(QuoteStyle::Single);
+    /// assert!(html.outer_html_with(&policy) == "<a href='/' title='home'>x</a>");
+    /// ```
+    #[must_use]
+    pub const fn quotes(mut self, quotes: QuoteStyle) -> Self {
+        self.quotes = quotes;
+        self
+    }
+
+    /// Sets the empty tags written with a self-closing slash
+    ///
+    /// By default, no tag is written with a slash in the HTML syntax. The
+    /// tags named in `names` are written with a slash, like `<br/>`, when they
+    /// don't have any children, whereas the other tags are written as usual.
+    /// This gives a finer control than [`SerializePolicy::xhtml`], which
+    /// writes all the empty void elements as `<br />`.
+    ///
+    /// The names are compared case-insensitively, except in `<svg>` and
+    /// `<math>`, and replace the previously given ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<p>a<br>b<span></span><img src="x"/></p>"#).unwrap();
+    /// let policy = SerializePolicy::new().self_closing_slash(["br", "span"]);
+    /// assert!(html.outer_html_with(&policy) == r#"<p>a<br/>b<span/><img src="x"></img></p>"#);
+    /// ```
+    #[must_use]
+    pub fn self_closing_slash<I, T>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.self_closing_slash = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the line break at the end of the output
+    ///
+    /// - If `trailing_newline` is set to `false` (default), the output ends
+    ///   with the last node of the tree.
+    /// - If `trailing_newline` is set to `true`, a line break is written after
+    ///   the tree, even if it already ends with one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<p>a</p>").unwrap();
+    /// let policy = SerializePolicy::new().trailing_newline(true);
+    /// assert!(html.outer_html_with(&policy) == "<p>a</p>\n");
+    /// ```
+    #[must_use]
+    pub const fn trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Sets the trimming of the whitespace-only texts
+    ///
+    /// - If `trim_whitespace` is set to `false` (default), all the texts are
+    ///   written.
+    /// - If `trim_whitespace` is set to `true`, the texts that only contain
+    ///   ASCII whitespace are skipped, except in `<pre>`, `<textarea>`,
+    ///   `<script>` and `<style>`. This is the output of
+    ///   [`Html::trim_whitespace_nodes`], without modifying the tree.
+    #[must_use]
+    pub const fn trim_whitespace(mut self, trim_whitespace: bool) -> Self {
+        self.trim_whitespace = trim_whitespace;
+        self
+    }
+
+    /// Sets the XHTML syntax
+    ///
+    /// - If `xhtml` is set to `false` (default), the tags are written with the
+    ///   HTML syntax.
+    /// - If `xhtml` is set to `true`, the tags are written with the XHTML
+    ///   syntax, as with [`Html::display_xhtml`]: see
+    ///   [`XhtmlDisplay`](super::xhtml::XhtmlDisplay).
+    #[must_use]
+    pub const fn xhtml(mut self, xhtml: bool) -> Self {
+        self.xhtml = xhtml;
+        self
+    }
+}
+
+/// Context in which a node is written
+#[derive(Debug, Default, Clone, Copy)]
+struct Context {
+    /// Whether the node is in an element where whitespace is significant
+    preformatted: bool,
+    /// Whether the node is in an element with raw text content
+    raw: bool,
+}
+
+impl Html {
+    /// Writes the tree with the given policy.
+    ///
+    /// This is used by the [`Display`](fmt::Display) implementations of
+    /// [`Html`] and [`XhtmlDisplay`](super::xhtml::XhtmlDisplay).
+    pub(crate) fn fmt_with(
+        &self,
+        policy: &SerializePolicy,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if let Some(doctype) = &policy.ensure_doctype
+            && !self
+                .as_fragment()
+                .iter()
+                .any(|node| matches!(node, Self::Doctype { .. }))
+        {
+            write_doctype("DOCTYPE", Some(doctype), None, formatter)?;
+        }
+        policy.write_node(self, Context::default(), formatter)?;
+        if policy.trailing_newline {
+            formatter.write_str("\n")?;
+        }
+        Ok(())
+    }
+
+    /// Serialises the tree into an HTML string
+    ///
+    /// This is the same as
+    /// [`ToString::to_string`](alloc::string::ToString::to_string), with the
+    /// default [`SerializePolicy`].
+    #[must_use]
+    pub fn outer_html(&self) -> String {
+        self.outer_html_with(&SerializePolicy::new())
+    }
+
+    /// Serialises the tree into an HTML string, with the given policy
+    ///
+    /// See [`SerializePolicy`] for the available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<DIV hidden><input disabled></DIV>").unwrap();
+    /// assert!(html.outer_html() == html.to_string());
+    ///
+    /// let policy = SerializePolicy::new().xhtml(true);
+    /// assert!(
+    ///     html.outer_html_with(&policy)
+    ///         == r#"<div hidden="hidden"><input disabled="disabled" /></div>"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn outer_html_with(&self, policy: &SerializePolicy) -> String {
+        PolicyDisplay { html: self, policy }.to_string()
+    }
+}
+
+/// Wrapper to display an [`Html`] tree with a [`SerializePolicy`]
+struct PolicyDisplay<'html> {
+    /// Tree to display
+    html: &'html Html,
+    /// Policy to display the tree with
+    policy: &'html SerializePolicy,
+}
+
+impl fmt::Display for PolicyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.html.fmt_with(self.policy, f)
+    }
+}
+
+/// Writes the marker of a parsed conditional comment.
+///
+/// This is `<!--[if condition]>` for the opening marker, and `<![endif]-->` for
+/// the closing one.
+pub fn write_conditional(
+    condition: Option<&str>,
+    formatter: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    match condition {
+        Some(condition_str) => write!(formatter, "<!--[if {condition_str}]>"),
+        None => formatter.write_str("<![endif]-->"),
+    }
+}
+
+/// Writes a doctype from its name, attribute and internal subset.
+///
+/// This is `<!name attr [subset]>`, without the missing parts.
+pub fn write_doctype(
+    name: &str,
+    attr: Option<&str>,
+    subset: Option<&str>,
+    formatter: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    write!(formatter, "<!{name}")?;
+    if let Some(attr_str) = attr {
+        write!(formatter, " {attr_str}")?;
+    }
+    if let Some(subset_str) = subset {
+        write!(formatter, " [{subset_str}]")?;
+    }
+    formatter.write_str(">")
+}
+
+/// Writes a text with the special characters escaped.
+///
+/// The `quote` delimiting an attribute value is also escaped.
+fn write_escaped(
+    text: &str,
+    quote: Option<char>,
+    formatter: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    for ch in text.chars() {
+        match ch {
+            '&' => formatter.write_str("&amp;")?,
+            '<' => formatter.write_str("&lt;")?,
+            '>' => formatter.write_str("&gt;")?,
+            '"' if quote == Some('"') => formatter.write_str("&quot;")?,
+            '\'' if quote == Some('\'') => formatter.write_str("&#39;")?,
+            _ => fmt::Write::write_char(formatter, ch)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Module to compute statistics on an [`Html`] tree.
+
+use alloc::borrow::ToOwned as _;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+
+use super::html::Html;
+
+/// Statistics on the nodes of an [`Html`] tree
+///
+/// See [`Html::stats`].
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TreeStats {
+    /// Number of attributes, on all the tags
+    attributes: usize,
+    /// Number of comments
+    comments: usize,
+    /// Number of nodes on the longest path from the root to a leaf
+    max_depth: usize,
+    /// Number of tags, by name
+    tags: BTreeMap<String, usize>,
+    /// Number of text nodes
+    texts: usize,
+}
+
+impl TreeStats {
+    /// Returns the total number of attributes, on all the tags
+    #[must_use]
+    pub const fn attribute_count(&self) -> usize {
+        self.attributes
+    }
+
+    /// Returns the number of comments
+    #[must_use]
+    pub const fn comment_count(&self) -> usize {
+        self.comments
+    }
+
+    /// Returns the number of nodes on the longest path from the root to a
+    /// leaf
+    ///
+    /// A tree with only a text node has a depth of 1, and an empty tree has a
+    /// depth of 0. [`Html::Vec`] don't count as a level.
+    #[must_use]
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the number of tags named `name`
+    #[must_use]
+    pub fn tag_count(&self, name: &str) -> usize {
+        self.tags.get(name).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of tags for each tag name
+    #[must_use]
+    pub const fn tag_counts(&self) -> &BTreeMap<String, usize> {
+        &self.tags
+    }
+
+    /// Returns the number of text nodes
+    #[must_use]
+    pub const fn text_count(&self) -> usize {
+        self.texts
+    }
+
+    /// Returns the total number of tags
+    #[must_use]
+    pub fn total_tag_count(&self) -> usize {
+        self.tags.values().sum()
+    }
+}
+
+impl Html {
+    /// Computes statistics on the nodes of the tree, in a single pass
+    ///
+    /// See [`TreeStats`] for the available statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<ul class='menu'><li>A</li><li>B<!-- b --></li></ul>").unwrap();
+    /// let stats = html.stats();
+    /// assert!(stats.tag_count("li") == 2);
+    /// assert!(stats.total_tag_count() == 3);
+    /// assert!(stats.text_count() == 2);
+    /// assert!(stats.comment_count() == 1);
+    /// assert!(stats.attribute_count() == 1);
+    /// assert!(stats.max_depth() == 3);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> TreeStats {
+        let mut stats = TreeStats::default();
+        let mut stack = vec![(self, 1)];
+        while let Some((node, depth)) = stack.pop() {
+            if !matches!(node, Self::Empty | Self::Vec(_)) {
+                stats.max_depth = stats.max_depth.max(depth);
+            }
+            match node {
+                Self::BogusComment(_) | Self::Comment(_) =>
+                    stats.comments = stats.comments.saturating_add(1),
+                Self::Conditional(_) | Self::Doctype { .. } | Self::Empty => (),
+                Self::Tag { tag, child } => {
+                    stats.attributes = stats.attributes.saturating_add(tag.as_attrs().len());
+                    let count = stats.tags.entry(tag.as_name().to_owned()).or_default();
+                    *count = count.saturating_add(1);
+                    stack.push((child, depth.saturating_add(1)));
+                }
+                Self::Text(_) => stats.texts = stats.texts.saturating_add(1),
+                Self::Vec(vec) => stack.extend(vec.iter().map(|child| (child, depth))),
+            }
+        }
+        stats
+    }
+}
@@ -1,23 +1,24 @@
 //! Module to define the tag data structure.
 
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
-use core::hash::Hash;
+use core::hash::{Hash, Hasher};
 use core::mem::take;
 
 use crate::errors::safe_unreachable;
+use crate::parse::entities::decode_entities;
 
 /// Name and optionally a value for an attribute of a tag.
 ///
 /// Attributes provide information about a tag. They can consist in a simple
 /// name, or also have a value, after an `=` sign. The values are always
 /// surrounded either by single or double quotes.
-#[allow(
-    clippy::allow_attributes,
-    clippy::derived_hash_with_manual_eq,
-    reason = "hash on enum doesn't depend of variant data"
-)]
-#[derive(Debug, Hash, Clone)]
-
+#[non_exhaustive]
+#[derive(Debug, Clone)]
 pub enum Attribute {
     /// Name of the attribute, when it doesn't have a value
     ///
@@ -73,6 +74,7 @@ impl Attribute {
     }
 
     /// Returns the name of an attribute
+    #[must_use]
     pub const fn as_name(&self) -> &String {
         match self {
             Self::NameNoValue(name) | Self::NameValue { name, .. } => name,
@@ -80,6 +82,7 @@ impl Attribute {
     }
 
     /// Returns the value of an attribute
+    #[must_use]
     pub const fn as_value(&self) -> Option<&String> {
         match self {
             Self::NameNoValue(_) => None,
@@ -87,6 +90,13 @@ impl Attribute {
         }
     }
 
+    /// Decodes the character references of the attribute's value
+    pub(crate) fn decode_value(&mut self, custom: &BTreeMap<String, String>) {
+        if let Self::NameValue { value, .. } = self {
+            *value = decode_entities(value, true, custom);
+        }
+    }
+
     /// Returns the value of an attribute
     fn into_value(self) -> Option<String> {
         match self {
@@ -95,6 +105,13 @@ impl Attribute {
         }
     }
 
+    /// Converts the name of the attribute to ASCII lowercase
+    pub(crate) fn lowercase_name(&mut self) {
+        match self {
+            Self::NameNoValue(name) | Self::NameValue { name, .. } => name.make_ascii_lowercase(),
+        }
+    }
+
     /// Pushes a character into the attribute's value
     #[coverage(off)]
     pub(crate) fn push_value(&mut self, ch: char) {
@@ -112,6 +129,46 @@ impl From<String> for Attribute {
     }
 }
 
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Eq for Attribute {}
+
+/// Hashes the name and the value of the attribute.
+///
+/// The quotes are ignored, to be consistent with the [`PartialEq`]
+/// implementation.
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Hash for Attribute {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_name().hash(state);
+        self.as_value().hash(state);
+    }
+}
+
+/// Compares the names and the values of the attributes.
+///
+/// The quotes delimiting the values are ignored, so `id='a'` equals `id="a"`.
+/// The names are compared as written, as an attribute doesn't know the
+/// namespace of its tag: the tags compare the names of their attributes
+/// case-insensitively in HTML only.
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl PartialEq for Attribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_name() == other.as_name() && self.as_value() == other.as_value()
+    }
+}
+
 #[expect(clippy::min_ident_chars, reason = "keep trait naming")]
 impl fmt::Display for Attribute {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -125,6 +182,64 @@ impl fmt::Display for Attribute {
     }
 }
 
+/// Namespace of a tag
+///
+/// Tags are in the [`Namespace::Html`] namespace, except when inside an
+/// `<svg>` or `<math>` tag.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Namespace {
+    /// Default namespace for HTML tags
+    #[default]
+    Html,
+    /// Namespace of the `<math>` tag and its children
+    MathMl,
+    /// Namespace of the `<svg>` tag and its children
+    Svg,
+}
+
+impl Namespace {
+    /// Compares two names of elements or attributes in this namespace.
+    ///
+    /// The names are case-insensitive in HTML, and case-sensitive in the
+    /// foreign content, like the `viewBox` attribute of `<svg>`.
+    pub(crate) fn eq_names(self, left: &str, right: &str) -> bool {
+        if self.is_foreign() {
+            left == right
+        } else {
+            left.eq_ignore_ascii_case(right)
+        }
+    }
+
+    /// Folds the case of a name of element or attribute in this namespace.
+    ///
+    /// The names are converted to lowercase in HTML, and left unchanged in
+    /// the foreign content, so that the folded names are equal iff
+    /// [`Namespace::eq_names`] is `true`.
+    pub(crate) fn fold_name(self, name: &str) -> String {
+        if self.is_foreign() {
+            name.to_owned()
+        } else {
+            name.to_ascii_lowercase()
+        }
+    }
+
+    /// Computes the namespace of a tag from its name and the namespace of its
+    /// parent.
+    pub(crate) fn from_parent(name: &str, parent: Self) -> Self {
+        match name {
+            "svg" => Self::Svg,
+            "math" => Self::MathMl,
+            _ => parent,
+        }
+    }
+
+    /// Checks if a namespace follows the XML rules, i.e., isn't HTML.
+    pub(crate) const fn is_foreign(self) -> bool {
+        !matches!(self, Self::Html)
+    }
+}
+
 /// Tag structure, with its name and attributes
 ///
 /// # Examples
@@ -151,13 +266,28 @@ impl fmt::Display for Attribute {
 pub struct Tag {
     /// Attributes of the tag. See [`Attribute`].
     attrs: Box<[Attribute]>,
+    /// Whether the tag was closed without a closing tag
+    ///
+    /// See [`Tag::is_implicitly_closed`].
+    implicitly_closed: bool,
     /// Name of the tag.
     ///
     /// # Examples
     ///
     /// - `<div id="blob">` as name `div`
-    /// - `<>` as an empty name
+    /// - `<>` as an empty name, closed by `</>`
     name: String,
+    /// Namespace of the tag. See [`Namespace`].
+    namespace: Namespace,
+    /// Values of the attributes as they were written, before the decoding of
+    /// their character references
+    ///
+    /// This is empty unless the tag was parsed with
+    /// [`ParseOptions::keep_raw_attr_values`](crate::prelude::ParseOptions::keep_raw_attr_values).
+    /// See [`Tag::get_raw`].
+    raw_values: Box<[(String, String)]>,
+    /// Original source of the tag, if it was stored. See [`TagSource`].
+    source: Option<TagSource>,
 }
 
 impl Tag {
@@ -202,8 +332,58 @@ impl Tag {
         &self.name
     }
 
+    /// Returns the namespace of the tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<svg viewBox='0 0 10 10'><rect/></svg>").unwrap();
+    /// if let Html::Tag { tag, .. } = html {
+    ///     assert!(tag.as_namespace() == Namespace::Svg);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn as_namespace(&self) -> Namespace {
+        self.namespace
+    }
+
+    /// Creates a [`TagBuilder`] for a tag named `name`
+    ///
+    /// See [`TagBuilder`] for more information.
+    #[must_use]
+    pub fn builder(name: &str) -> TagBuilder {
+        TagBuilder { attrs: Vec::new(), name: name.to_owned() }
+    }
+
+    /// Returns the namespace of the children of the tag
+    ///
+    /// This is the namespace of the tag, except for the SVG `<foreignObject>`
+    /// tag that contains HTML.
+    pub(crate) fn children_namespace(&self) -> Namespace {
+        if self.namespace == Namespace::Svg && self.name == "foreignObject" {
+            Namespace::Html
+        } else {
+            self.namespace
+        }
+    }
+
+    /// Decodes the character references of the attribute values
+    pub(crate) fn decode_attr_values(&mut self, custom: &BTreeMap<String, String>) {
+        self.attrs
+            .iter_mut()
+            .for_each(|attr| attr.decode_value(custom));
+    }
+
     /// Finds the value of the attribute of the given name
     ///
+    /// The name is case-insensitive for the HTML elements, and case-sensitive
+    /// for the elements of the foreign content, in `<svg>` and `<math>`. See
+    /// [`Tag::as_namespace`].
+    ///
     /// # Returns
     ///
     /// - `Some(value)` if `name = value` is present in the [`Tag`]
@@ -231,12 +411,228 @@ impl Tag {
     pub fn find_attr_value<T: AsRef<str>>(&self, name: T) -> Option<&String> {
         self.attrs
             .iter()
-            .find(|attr| attr.as_name() == name.as_ref())
+            .find(|attr| self.namespace.eq_names(attr.as_name(), name.as_ref()))
             .and_then(|attr| attr.as_value())
     }
 
+    /// Writes the closing part of the tag
+    ///
+    /// If the source was preserved, it is the closing tag that was read, if
+    /// any. Otherwise, the closing tag is generated, except for `<br>`.
+    ///
+    /// A self-closing tag that isn't `empty` (because children were added to
+    /// it after the parsing) is closed explicitly, so the children stay
+    /// inside it.
+    pub(crate) fn fmt_close(&self, empty: bool, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(TagSource { close: Some(close), .. }) => formatter.write_str(close),
+            Some(TagSource { close: None, .. }) | None
+                if !empty && self.is_self_closing_source() =>
+                write!(formatter, "</{}>", self.name),
+            Some(TagSource { close: None, .. }) => Ok(()),
+            None if self.name == "br" => Ok(()),
+            None => write!(formatter, "</{}>", self.name),
+        }
+    }
+
+    /// Writes the opening part of the tag
+    ///
+    /// If the source was preserved, it is the opening tag that was read.
+    /// Otherwise, the opening tag is generated from the name and attributes.
+    ///
+    /// The self-closing source of a tag that isn't `empty`, like `<br/>`, is
+    /// replaced by a generated opening tag, as it is closed explicitly.
+    pub(crate) fn fmt_open(&self, empty: bool, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(source) if empty || !self.is_self_closing_source() =>
+                formatter.write_str(&source.open),
+            Some(_) | None => write!(formatter, "<{self}>"),
+        }
+    }
+
+    /// Forgets the raw value of the attributes named `name`, as they don't
+    /// match the decoded values anymore.
+    fn forget_raw_value(&mut self, name: &str) {
+        let namespace = self.namespace;
+        if self
+            .raw_values
+            .iter()
+            .any(|(raw_name, _)| namespace.eq_names(raw_name, name))
+        {
+            let mut raw_values = take(&mut self.raw_values).into_vec();
+            raw_values.retain(|(raw_name, _)| !namespace.eq_names(raw_name, name));
+            self.raw_values = raw_values.into_boxed_slice();
+        }
+    }
+
+    /// Reads the attribute of the given name as a boolean
+    ///
+    /// The name is compared as in [`Tag::find_attr_value`].
+    ///
+    /// # Returns
+    ///
+    /// - `Some(true)` if the attribute is present without a value, like
+    ///   `disabled`, or if its value is empty, `true` or the name of the
+    ///   attribute, like `checked="checked"`
+    /// - `Some(false)` if its value is `false`
+    /// - `None` if the attribute doesn't exist, or if its value is something
+    ///   else
+    ///
+    /// The values are compared case-insensitively, without the surrounding
+    /// whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<input disabled draggable="false" spellcheck="maybe">"#).unwrap();
+    /// if let Html::Tag { tag, .. } = html {
+    ///     assert!(tag.get_bool("disabled") == Some(true));
+    ///     assert!(tag.get_bool("draggable") == Some(false));
+    ///     assert!(tag.get_bool("spellcheck").is_none());
+    ///     assert!(tag.get_bool("hidden").is_none());
+    /// } else {
+    ///     unreachable!()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_bool<T>(&self, name: T) -> Option<bool>
+    where
+        T: AsRef<str>,
+    {
+        let attr = self
+            .attrs
+            .iter()
+            .find(|attr| self.namespace.eq_names(attr.as_name(), name.as_ref()))?;
+        let Some(value) = attr.as_value() else {
+            return Some(true);
+        };
+        let trimmed = value.trim_ascii();
+        if trimmed.is_empty()
+            || trimmed.eq_ignore_ascii_case("true")
+            || trimmed.eq_ignore_ascii_case(attr.as_name())
+        {
+            Some(true)
+        } else if trimmed.eq_ignore_ascii_case("false") {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Reads the value of the attribute of the given name as a float
+    ///
+    /// The name is compared as in [`Tag::find_attr_value`], and the value is
+    /// parsed without its surrounding whitespace.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(value)` if `name = value` is present in the [`Tag`] and the
+    ///   value is a float
+    /// - `None` if the attribute doesn't exist, doesn't have a value, or if its
+    ///   value isn't a float
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<meter value=" 0.6 " max="one">"#).unwrap();
+    /// if let Html::Tag { tag, .. } = html {
+    ///     assert!(tag.get_float("value") == Some(0.6));
+    ///     assert!(tag.get_float("max").is_none());
+    /// } else {
+    ///     unreachable!()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_float<T>(&self, name: T) -> Option<f64>
+    where
+        T: AsRef<str>,
+    {
+        self.find_attr_value(name)?.trim_ascii().parse().ok()
+    }
+
+    /// Reads the value of the attribute of the given name as an integer
+    ///
+    /// The name is compared as in [`Tag::find_attr_value`], and the value is
+    /// parsed without its surrounding whitespace.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(value)` if `name = value` is present in the [`Tag`] and the
+    ///   value is an integer
+    /// - `None` if the attribute doesn't exist, doesn't have a value, or if its
+    ///   value isn't an integer, like `2.5` or `10px`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse(r#"<td colspan="2" width="10px">"#).unwrap();
+    /// if let Html::Tag { tag, .. } = html {
+    ///     assert!(tag.get_int("colspan") == Some(2));
+    ///     assert!(tag.get_int("width").is_none());
+    ///     assert!(tag.get_int("rowspan").is_none());
+    /// } else {
+    ///     unreachable!()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_int<T>(&self, name: T) -> Option<i64>
+    where
+        T: AsRef<str>,
+    {
+        self.find_attr_value(name)?.trim_ascii().parse().ok()
+    }
+
+    /// Finds the value of the attribute of the given name, as it was written
+    ///
+    /// The raw values are only kept when the tag is parsed with
+    /// [`ParseOptions::keep_raw_attr_values`](crate::prelude::ParseOptions::keep_raw_attr_values),
+    /// so the values given by [`Tag::find_attr_value`] can be compared to
+    /// their source, for instance to detect the obfuscated URLs. The raw value
+    /// of an attribute is forgotten when the attribute is modified.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(value)` if `name = value` was read with its raw value kept
+    /// - `None` if the attribute doesn't exist, doesn't have a value, or if its
+    ///   raw value wasn't kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let options = ParseOptions::new()
+    ///     .decode_attr_entities(true)
+    ///     .keep_raw_attr_values(true);
+    /// let (html, _) = Html::parse_with_warnings("<a href='&#106;s'>x</a>", &options).unwrap();
+    /// if let Html::Tag { tag, .. } = html {
+    ///     assert!(tag.find_attr_value("href").unwrap() == "js");
+    ///     assert!(tag.get_raw("href").unwrap() == "&#106;s");
+    /// } else {
+    ///     unreachable!()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_raw<T>(&self, name: T) -> Option<&String>
+    where
+        T: AsRef<str>,
+    {
+        self.raw_values
+            .iter()
+            .find(|(raw_name, _)| self.namespace.eq_names(raw_name, name.as_ref()))
+            .map(|(_, value)| value)
+    }
+
     /// Finds the value of the attribute of the given name
     ///
+    /// The name is compared as in [`Tag::find_attr_value`].
+    ///
     /// # Returns
     ///
     /// - `Some(value)` if `name = value` is present in the [`Tag`]
@@ -268,19 +664,312 @@ impl Tag {
     /// ```
     #[must_use]
     pub fn into_attr_value<T: AsRef<str>>(self, name: T) -> Option<String> {
+        let namespace = self.namespace;
         self.attrs
             .into_iter()
-            .find(|attr| attr.as_name() == name.as_ref())?
+            .find(|attr| namespace.eq_names(attr.as_name(), name.as_ref()))?
             .into_value()
     }
+
+    /// Returns the attributes of the tag, consuming it
+    pub(crate) fn into_attrs(self) -> Box<[Attribute]> {
+        self.attrs
+    }
+
+    /// Checks if the tag was closed without a closing tag
+    ///
+    /// This is the case of the parsed tags that were closed implicitly, like
+    /// a `<td>` closed by the next cell, a tag closed with its parent or by
+    /// the end of the input, or the first `<p>` of `<p>a<p>b` with
+    /// [`ParseOptions::close_paragraphs`](crate::prelude::ParseOptions::close_paragraphs). Linters can use
+    /// this to report the missing closing tags. This is always `false` for
+    /// the self-closing tags (like `<br>` or `<img/>`) and for the tags built
+    /// with [`Tag::builder`].
+    ///
+    /// The displayed tree is not affected: the implicitly closed tags are
+    /// still displayed with their closing tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<tr><td>a<td>b</td></tr>").unwrap();
+    /// let closed = html
+    ///     .children()
+    ///     .map(|child| match child {
+    ///         Html::Tag { tag, .. } => tag.is_implicitly_closed(),
+    ///         _ => unreachable!(),
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert!(closed == [true, false]);
+    /// ```
+    #[must_use]
+    pub const fn is_implicitly_closed(&self) -> bool {
+        self.implicitly_closed
+    }
+
+    /// Checks if the name of the tag is one of `names`.
+    ///
+    /// The names are compared case-insensitively, except in `<svg>` and
+    /// `<math>`. See [`Namespace::eq_names`].
+    pub(crate) fn is_one_of(&self, names: &[&str]) -> bool {
+        names
+            .iter()
+            .any(|name| self.namespace.eq_names(&self.name, name))
+    }
+
+    /// Checks if the tag is displayed without a closing tag when empty.
+    ///
+    /// This is the case of the `<br>` tags and of the tags with a preserved
+    /// self-closing source, like `<img/>`.
+    fn is_self_closing_source(&self) -> bool {
+        match &self.source {
+            Some(TagSource { close: Some(_), .. }) => false,
+            Some(TagSource { close: None, open }) => self.name == "br" || open.ends_with("/>"),
+            None => self.name == "br",
+        }
+    }
+
+    /// Converts the names of the tag and of its attributes to ASCII lowercase
+    pub(crate) fn lowercase_names(&mut self) {
+        self.name.make_ascii_lowercase();
+        self.attrs.iter_mut().for_each(Attribute::lowercase_name);
+    }
+
+    /// Normalises the `class` attribute of the tag
+    ///
+    /// The classes are separated by single spaces, and the duplicates are
+    /// removed, keeping the first occurrence of each class. The tag is left
+    /// unchanged if it doesn't have a `class` attribute, whose name is
+    /// compared as in [`Tag::find_attr_value`]. If the attribute is
+    /// modified, the stored source is dropped, as it doesn't match the tag
+    /// anymore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut tag = Tag::builder("p").attr("class", " b  a b ").build();
+    /// tag.normalize_classes();
+    /// assert!(tag.find_attr_value("class").unwrap() == "b a");
+    /// ```
+    pub fn normalize_classes(&mut self) {
+        let namespace = self.namespace;
+        let Some(Attribute::NameValue { value, .. }) = self
+            .attrs
+            .iter_mut()
+            .find(|attr| namespace.eq_names(attr.as_name(), "class"))
+        else {
+            return;
+        };
+        let mut classes: Vec<&str> = Vec::new();
+        for class in value.split_ascii_whitespace() {
+            if !classes.contains(&class) {
+                classes.push(class);
+            }
+        }
+        let normalized = classes.join(" ");
+        if *value != normalized {
+            *value = normalized;
+            self.source = None;
+            self.forget_raw_value("class");
+        }
+    }
+
+    /// Returns the attributes sorted by name, without duplicates
+    ///
+    /// The names are converted to lowercase, except in `<svg>` and `<math>`
+    /// (see [`Namespace::fold_name`]). Only the first of the attributes with
+    /// the same name is kept, as it is the one browsers keep. This is the view
+    /// of the attributes compared and hashed for the tags.
+    fn normalized_attrs(&self) -> Vec<(String, Option<&String>)> {
+        let mut attrs = self
+            .attrs
+            .iter()
+            .map(|attr| (self.namespace.fold_name(attr.as_name()), attr.as_value()))
+            .collect::<Vec<_>>();
+        attrs.sort_by(|left, right| left.0.cmp(&right.0));
+        attrs.dedup_by(|right, left| left.0 == right.0);
+        attrs
+    }
+
+    /// Removes the first attribute named `name`
+    ///
+    /// The name is compared as in [`Tag::find_attr_value`]. Returns `false` if
+    /// the tag doesn't have such an attribute. The stored source is dropped,
+    /// as it doesn't match the tag anymore.
+    pub(crate) fn remove_attr(&mut self, name: &str) -> bool {
+        self.source = None;
+        self.forget_raw_value(name);
+        let mut attrs = take(&mut self.attrs).into_vec();
+        let found = attrs
+            .iter()
+            .position(|attr| self.namespace.eq_names(attr.as_name(), name))
+            .map(|idx| attrs.remove(idx))
+            .is_some();
+        self.attrs = attrs.into_boxed_slice();
+        found
+    }
+
+    /// Replaces the first attribute with the same name as `attr`, or adds it
+    /// after the other attributes if there is none.
+    ///
+    /// The names are compared as in [`Tag::find_attr_value`]. The stored
+    /// source is dropped, as it doesn't match the tag anymore.
+    pub(crate) fn set_attr(&mut self, attr: Attribute) {
+        self.source = None;
+        self.forget_raw_value(attr.as_name());
+        let namespace = self.namespace;
+        if let Some(old) = self
+            .attrs
+            .iter_mut()
+            .find(|old| namespace.eq_names(old.as_name(), attr.as_name()))
+        {
+            *old = attr;
+        } else {
+            let mut attrs = take(&mut self.attrs).into_vec();
+            attrs.push(attr);
+            self.attrs = attrs.into_boxed_slice();
+        }
+    }
+
+    /// Stores the source of the closing part of the tag
+    ///
+    /// The source is only stored if the source of the opening part was.
+    pub(crate) fn set_close_source(&mut self, close: Option<&str>) {
+        if let Some(source) = &mut self.source {
+            source.close = close.map(ToOwned::to_owned);
+        }
+    }
+
+    /// Marks the tag as closed without a closing tag
+    pub(crate) const fn set_implicitly_closed(&mut self) {
+        self.implicitly_closed = true;
+    }
+
+    /// Stores the source of the opening part of the tag
+    pub(crate) fn set_open_source(&mut self, open: Option<&str>) {
+        self.source = open.map(|open_str| TagSource { close: None, open: open_str.to_owned() });
+    }
+
+    /// Stores the values of the attributes as they were written
+    ///
+    /// This must be called before the values are decoded.
+    pub(crate) fn store_raw_values(&mut self) {
+        self.raw_values = self
+            .attrs
+            .iter()
+            .filter_map(|attr| Some((attr.as_name().clone(), attr.as_value()?.clone())))
+            .collect();
+    }
+}
+
+impl From<(String, Box<[Attribute]>, Namespace)> for Tag {
+    fn from((name, attrs, namespace): (String, Box<[Attribute]>, Namespace)) -> Self {
+        Self {
+            attrs,
+            implicitly_closed: false,
+            name,
+            namespace,
+            raw_values: Box::default(),
+            source: None,
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Eq for Tag {}
+
+/// Compares the names, attributes and namespaces of the tags.
+///
+/// The comparison doesn't depend on the order of the attributes, nor on the
+/// case of the names in HTML. The names are case-sensitive in `<svg>` and
+/// `<math>`, like `viewBox`. The attributes are compared sorted by name,
+/// and only the first of the attributes with the same name is compared, as it
+/// is the one browsers keep. The quotes of the values and the original source
+/// stored with
+/// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source)
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let first = Html::parse(r#"<div a="1" b='2'>x</div>"#).unwrap();
+/// let second = Html::parse(r#"<DIV B="2" a="1" a="3">x</DIV>"#).unwrap();
+/// assert!(first == second);
+/// ```
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        self.namespace == other.namespace
+            && self.namespace.eq_names(&self.name, &other.name)
+            && self.normalized_attrs() == other.normalized_attrs()
+    }
 }
 
-impl From<(String, Box<[Attribute]>)> for Tag {
-    fn from((name, attrs): (String, Box<[Attribute]>)) -> Self {
-        Self { attrs, name }
+/// Hashes the names, attributes and namespaces of the tags.
+///
+/// The hash is consistent with the [`PartialEq`] implementation: it hashes the
+/// same view of the tag, with the names converted to lowercase in HTML only,
+/// and the attributes sorted by name and without duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use std::hash::{BuildHasher as _, RandomState};
+///
+/// use html_filter::prelude::*;
+///
+/// let first = Tag::builder("a")
+///     .attr("href", "/")
+///     .attr("id", "home")
+///     .build();
+/// let second = Tag::builder("A")
+///     .attr("ID", "home")
+///     .attr("href", "/")
+///     .build();
+/// let state = RandomState::new();
+/// assert!(state.hash_one(&first) == state.hash_one(&second));
+/// ```
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Hash for Tag {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.namespace.fold_name(&self.name).hash(state);
+        self.namespace.hash(state);
+        self.normalized_attrs().hash(state);
     }
 }
 
+/// Writes the name and the attributes of the tag, without the brackets.
+///
+/// The attributes are preceded by a space, like in `div id="a"`. The name of a
+/// tag can be empty, so the tag is written `<>` in a tree and closed by `</>`,
+/// as read by the parser, and `< id="a">` with attributes.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let html = Html::from(Tag::builder("").build()).with_child(Html::text("content"));
+/// assert!(html.to_string() == "<>content</>");
+/// ```
 #[expect(clippy::min_ident_chars, reason = "keep trait naming")]
 impl fmt::Display for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -289,8 +978,119 @@ impl fmt::Display for Tag {
     }
 }
 
-/// Builder returns by the parser when run on a tag.
-pub enum TagBuilder {
+/// Builder to create a [`Tag`]
+///
+/// The attributes are displayed in the order in which they were added.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let tag = Tag::builder("input")
+///     .attr("type", "checkbox")
+///     .bool_attr("checked")
+///     .class("big")
+///     .class("red")
+///     .build();
+/// assert!(tag.to_string() == r#"input type="checkbox" checked class="big red""#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TagBuilder {
+    /// Attributes of the tag, in insertion order
+    attrs: Vec<Attribute>,
+    /// Name of the tag
+    name: String,
+}
+
+impl TagBuilder {
+    /// Adds an attribute with a value
+    ///
+    /// If the attribute was already added, its value is replaced, but it
+    /// keeps its position.
+    #[must_use]
+    pub fn attr(self, name: &str, value: &str) -> Self {
+        self.set(Attribute::NameValue {
+            double_quote: true,
+            name: name.to_owned(),
+            value: value.to_owned(),
+        })
+    }
+
+    /// Adds an attribute without value, like `hidden`
+    ///
+    /// If the attribute was already added, its value is removed, but it keeps
+    /// its position.
+    #[must_use]
+    pub fn bool_attr(self, name: &str) -> Self {
+        self.set(Attribute::NameNoValue(name.to_owned()))
+    }
+
+    /// Builds the [`Tag`]
+    ///
+    /// The namespace is [`Namespace::Svg`] for an `svg` tag,
+    /// [`Namespace::MathMl`] for a `math` tag, and [`Namespace::Html`]
+    /// otherwise.
+    #[must_use]
+    pub fn build(self) -> Tag {
+        let namespace = Namespace::from_parent(&self.name, Namespace::Html);
+        Tag::from((self.name, self.attrs.into_boxed_slice(), namespace))
+    }
+
+    /// Adds a class to the `class` attribute
+    ///
+    /// The class is appended to the existing classes, separated by a space.
+    #[must_use]
+    pub fn class(mut self, class: &str) -> Self {
+        if let Some(Attribute::NameValue { value, .. }) =
+            self.attrs.iter_mut().find(|attr| attr.as_name() == "class")
+            && !value.is_empty()
+        {
+            value.push(' ');
+            value.push_str(class);
+            self
+        } else {
+            self.attr("class", class)
+        }
+    }
+
+    /// Replaces the attribute with the same name, or adds it at the end.
+    fn set(mut self, attr: Attribute) -> Self {
+        if let Some(old) = self
+            .attrs
+            .iter_mut()
+            .find(|old| old.as_name() == attr.as_name())
+        {
+            *old = attr;
+        } else {
+            self.attrs.push(attr);
+        }
+        self
+    }
+}
+
+/// Original source of a [`Tag`]
+///
+/// This is only stored when parsing with
+/// [`ParseOptions::preserve_source`](crate::prelude::ParseOptions::preserve_source).
+#[derive(Debug, Clone)]
+struct TagSource {
+    /// Closing part of the tag, if one was found
+    ///
+    /// # Examples
+    ///
+    /// `</div >`
+    close: Option<String>,
+    /// Opening part of the tag
+    ///
+    /// # Examples
+    ///
+    /// `<div  id='blob'/>`
+    open: String,
+}
+
+/// Lexeme returned by the parser when run on a tag.
+pub enum TagLexeme {
     /// Closing tag
     ///
     /// # Examples
@@ -315,6 +1115,13 @@ pub enum TagBuilder {
         ///
         /// From the example above, the name is `html`.
         attr: Option<String>,
+        /// Internal subset of the document tag, between `[` and `]`.
+        ///
+        /// # Examples
+        ///
+        /// In `<!DOCTYPE note [<!ENTITY a "b">]>`, the subset is
+        /// `<!ENTITY a "b">`.
+        subset: Option<String>,
     },
     /// Opening tag
     ///
@@ -338,6 +1145,13 @@ pub enum TagBuilder {
     ///
     /// `<!--`
     OpenComment,
+    /// Opening tag that was interrupted by the end of the input, inside the
+    /// value of its last attribute
+    ///
+    /// # Examples
+    ///
+    /// `<a href="page`
+    UnterminatedAttribute(Tag),
 }
 
 /// Closing type of the tag.
@@ -345,12 +1159,16 @@ pub enum TagBuilder {
 pub enum TagType {
     /// Closed tag
     ///
-    /// This means the closing part of the tag was found.
+    /// This means the closing part of the tag was found, or that the tag was
+    /// closed `implicit`ly, like a `<p>` closed by the next one.
     ///
     /// # Examples
     ///
     /// `</div>` was read after `<div>`
-    Closed,
+    Closed {
+        /// Whether the tag was closed without reading its closing tag
+        implicit: bool,
+    },
     /// Opened tag
     ///
     /// This means the closing part of the tag was not yet found.
@@ -365,7 +1183,7 @@ pub enum TagType {
     ///
     /// # Examples
     ///
-    /// `<div id="blob" />` and `</>`
+    /// `<div id="blob" />`
     SelfClosing,
 }
 
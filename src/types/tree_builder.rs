@@ -0,0 +1,169 @@
+//! Module to build an [`Html`] tree incrementally.
+
+use alloc::borrow::ToOwned as _;
+use alloc::string::{String, ToString as _};
+use alloc::vec::Vec;
+
+use super::html::Html;
+use super::html_builder::HtmlBuilder;
+use super::tag::Tag;
+use crate::prelude::{ParseError, Position};
+
+/// Low-level API to build an [`Html`] tree incrementally
+///
+/// This is the API used by the parser: nodes are pushed one after the other
+/// into the innermost opened tag, and tags are closed explicitly. It is meant
+/// for tools that drive the construction of a tree themselves, like a custom
+/// parser. To build a tree by hand, [`Html::from`] and [`Html::with_child`]
+/// are easier to use.
+///
+/// The tree is only available once built with [`TreeBuilder::build`], as the
+/// opened tags and comments can't be represented in an [`Html`] tree.
+///
+/// # Examples
+///
+/// ```
+/// use html_filter::prelude::*;
+///
+/// let mut builder = TreeBuilder::new();
+/// builder.push_tag(Tag::builder("ul").build(), false);
+/// builder.push_tag(Tag::builder("li").build(), false);
+/// builder.push_text("First");
+/// builder.close_tag("li").unwrap();
+/// builder.push_comment(" end ");
+/// assert!(builder.close_tag("p").is_err());
+/// builder.close_tag("ul").unwrap();
+/// assert!(builder.build().to_string() == "<ul><li>First</li><!-- end --></ul>");
+/// ```
+#[derive(Debug, Default)]
+pub struct TreeBuilder {
+    /// Tree being built
+    tree: HtmlBuilder,
+}
+
+impl TreeBuilder {
+    /// Builds the [`Html`] tree
+    ///
+    /// The tags that are still opened are implicitly closed, and so is an
+    /// opened comment.
+    #[must_use]
+    pub fn build(self) -> Html {
+        self.tree.into_html()
+    }
+
+    /// Closes the comment opened with [`TreeBuilder::open_comment`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::UnopenedComment`] if no comment is open.
+    pub fn close_comment(&mut self) -> Result<(), ParseError> {
+        if self.tree.close_comment() {
+            Ok(())
+        } else {
+            Err(ParseError::UnopenedComment)
+        }
+    }
+
+    /// Closes the innermost opened tag with the given name
+    ///
+    /// The tags opened inside it are implicitly closed with it. An opened
+    /// comment is closed first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::UnmatchedClosingTag`] if no tag with this name is
+    /// open. As there is no input, the position of the error is the one the
+    /// closing tag would have at the end of the displayed tree built so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut builder = TreeBuilder::new();
+    /// builder.push_tag(Tag::builder("p").build(), false);
+    /// builder.push_text("a\nb");
+    /// let err = builder.close_tag("div").unwrap_err();
+    /// assert!(
+    ///     err.to_string()
+    ///         == "Invalid closing tag: Found closing tag for 'div' but it isn't open at line 2, column 2."
+    /// );
+    /// ```
+    pub fn close_tag(&mut self, name: &str) -> Result<(), ParseError> {
+        self.tree.close_comment();
+        if self.tree.close_tag_aux(name, None, false) {
+            Ok(())
+        } else {
+            let written = self.tree.to_string();
+            Err(ParseError::UnmatchedClosingTag {
+                name: name.to_owned(),
+                position: Position::from_offset(&written, written.len()),
+            })
+        }
+    }
+
+    /// Creates an empty [`TreeBuilder`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a comment
+    ///
+    /// The texts pushed with [`TreeBuilder::push_text`] are then appended to
+    /// the comment, until it is closed with [`TreeBuilder::close_comment`].
+    /// An opened comment is closed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let mut builder = TreeBuilder::new();
+    /// builder.open_comment();
+    /// builder.push_text(" a");
+    /// builder.push_text("b ");
+    /// builder.close_comment().unwrap();
+    /// builder.push_text("c");
+    /// assert!(builder.build().to_string() == "<!-- ab -->c");
+    /// ```
+    pub fn open_comment(&mut self) {
+        self.tree.close_comment();
+        self.tree.push_comment(String::new(), false);
+    }
+
+    /// Returns the opened tags, from the outermost to the innermost
+    #[must_use]
+    pub fn opened_tags(&self) -> Vec<&Tag> {
+        self.tree.opened_tags()
+    }
+
+    /// Pushes a complete comment into the innermost opened tag
+    ///
+    /// An opened comment is closed first.
+    pub fn push_comment(&mut self, content: &str) {
+        self.tree.close_comment();
+        self.tree.push_comment(content.to_owned(), true);
+    }
+
+    /// Pushes a tag into the innermost opened tag
+    ///
+    /// - If `self_closing` is set to `false`, the tag is opened: the next nodes
+    ///   are pushed into it, until it is closed with
+    ///   [`TreeBuilder::close_tag`].
+    /// - If `self_closing` is set to `true`, the tag is complete and doesn't
+    ///   have any children, like `<br>`.
+    ///
+    /// An opened comment is closed first.
+    pub fn push_tag(&mut self, tag: Tag, self_closing: bool) {
+        self.tree.close_comment();
+        self.tree.push_tag(tag, self_closing);
+    }
+
+    /// Pushes a text into the innermost opened tag, or into the opened comment
+    ///
+    /// Consecutive texts are merged into a single [`Html::Text`].
+    pub fn push_text(&mut self, text: &str) {
+        self.tree.push_str(text);
+    }
+}
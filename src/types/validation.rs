@@ -0,0 +1,219 @@
+//! Module to check the structure of an [`Html`] tree built by hand.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use super::html::Html;
+use super::patch::fmt_path;
+use super::tag::Tag;
+use crate::parse::tokenizer::is_bogus_comment;
+use crate::parse::{VOID_ELEMENTS, is_tag_name_char};
+
+/// Structural issue of an [`Html`] tree
+///
+/// These issues can't happen in a parsed tree, but can be introduced when a
+/// tree is built or edited by hand. They are reported by [`Html::validate`],
+/// with the path of the faulty node, as in [`Patch`](super::patch::Patch)es.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The name of an attribute can't be read back
+    ///
+    /// The attribute names can't be empty, nor contain whitespace, `/`, `>`
+    /// or `=`.
+    #[non_exhaustive]
+    InvalidAttributeName {
+        /// Name of the attribute
+        name: String,
+        /// Path of the tag
+        path: Vec<usize>,
+    },
+    /// The content of a bogus comment can't be read back
+    ///
+    /// The content of a bogus comment can't contain `>`, and must not be read
+    /// as a comment or a doctype, as `-- x --` or `doctype html`.
+    #[non_exhaustive]
+    InvalidBogusComment {
+        /// Path of the bogus comment
+        path: Vec<usize>,
+    },
+    /// The content of a comment would end it early
+    ///
+    /// The content of a comment can't contain `-->`.
+    #[non_exhaustive]
+    InvalidComment {
+        /// Path of the comment
+        path: Vec<usize>,
+    },
+    /// The name of a tag can't be read back
+    ///
+    /// The tag names start with an ASCII letter, followed by ASCII letters,
+    /// digits, `-`, `_`, `.` and `:`. The non-ASCII characters are accepted,
+    /// and the name can also be empty, as in `<>`.
+    #[non_exhaustive]
+    InvalidTagName {
+        /// Name of the tag
+        name: String,
+        /// Path of the tag
+        path: Vec<usize>,
+    },
+    /// A doctype is inside a tag
+    #[non_exhaustive]
+    NestedDoctype {
+        /// Path of the doctype
+        path: Vec<usize>,
+    },
+    /// A void element, like `<br>` or `<img>`, has children
+    #[non_exhaustive]
+    VoidElementChildren {
+        /// Name of the tag
+        name: String,
+        /// Path of the tag
+        path: Vec<usize>,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAttributeName { name, path } => {
+                f.write_str("Tag at ")?;
+                fmt_path(f, path)?;
+                write!(f, " has an invalid attribute name '{name}'.")
+            }
+            Self::InvalidBogusComment { path } => {
+                f.write_str("Bogus comment at ")?;
+                fmt_path(f, path)?;
+                f.write_str(" can't be read back.")
+            }
+            Self::InvalidComment { path } => {
+                f.write_str("Comment at ")?;
+                fmt_path(f, path)?;
+                f.write_str(" contains '-->'.")
+            }
+            Self::InvalidTagName { name, path } => {
+                f.write_str("Tag at ")?;
+                fmt_path(f, path)?;
+                write!(f, " has an invalid name '{name}'.")
+            }
+            Self::NestedDoctype { path } => {
+                f.write_str("Doctype at ")?;
+                fmt_path(f, path)?;
+                f.write_str(" is inside a tag.")
+            }
+            Self::VoidElementChildren { name, path } => {
+                f.write_str("Void element at ")?;
+                fmt_path(f, path)?;
+                write!(f, " has children, but <{name}> can't have any.")
+            }
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "default implementations are enough"
+)]
+impl Error for ValidationError {}
+
+impl Html {
+    /// Checks the structure of the tree
+    ///
+    /// This is useful before serialising a tree built or edited by hand, as
+    /// the parser only produces valid trees. See [`ValidationError`] for the
+    /// reported issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns all the issues found in the tree, in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::from(Tag::builder("br").build()).with_child(Html::text("x"));
+    /// let errors = html.validate().unwrap_err();
+    /// assert!(errors.len() == 1);
+    /// assert!(errors[0].to_string() == "Void element at [0] has children, but <br> can't have any.");
+    ///
+    /// assert!(Html::parse("<p>a<br>b</p>").unwrap().validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        validate_nodes(self.as_fragment(), &mut Vec::new(), false, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Checks that a tag and its attributes have valid names.
+fn validate_names(tag: &Tag, path: &[usize], errors: &mut Vec<ValidationError>) {
+    let name = tag.as_name();
+    if name
+        .chars()
+        .enumerate()
+        .any(|(idx, ch)| !is_tag_name_char(ch, idx == 0))
+    {
+        errors.push(ValidationError::InvalidTagName { name: name.clone(), path: path.to_vec() });
+    }
+    for attr in tag.as_attrs() {
+        let attr_name = attr.as_name();
+        if attr_name.is_empty()
+            || attr_name.contains(|ch: char| ch.is_whitespace() || matches!(ch, '/' | '>' | '='))
+        {
+            errors.push(ValidationError::InvalidAttributeName {
+                name: attr_name.clone(),
+                path: path.to_vec(),
+            });
+        }
+    }
+}
+
+/// Checks the given sibling nodes and their children.
+///
+/// The `path` is the one of the parent, and `nested` is `true` if the nodes
+/// are inside a tag.
+fn validate_nodes(
+    nodes: &[Html],
+    path: &mut Vec<usize>,
+    nested: bool,
+    errors: &mut Vec<ValidationError>,
+) {
+    for (idx, node) in nodes.iter().enumerate() {
+        path.push(idx);
+        match node {
+            Html::BogusComment(content)
+                if content.contains('>') || !is_bogus_comment(&format!("<!{content}>")) =>
+                errors.push(ValidationError::InvalidBogusComment { path: path.clone() }),
+            Html::Comment(content) if content.contains("-->") =>
+                errors.push(ValidationError::InvalidComment { path: path.clone() }),
+            Html::Doctype { .. } if nested =>
+                errors.push(ValidationError::NestedDoctype { path: path.clone() }),
+            Html::Tag { tag, child } => {
+                validate_names(tag, path, errors);
+                if !child.is_empty() && VOID_ELEMENTS.contains(&tag.as_name().as_str()) {
+                    errors.push(ValidationError::VoidElementChildren {
+                        name: tag.as_name().clone(),
+                        path: path.clone(),
+                    });
+                }
+                validate_nodes(child.as_fragment(), path, true, errors);
+            }
+            Html::Vec(vec) => validate_nodes(vec, path, nested, errors),
+            Html::BogusComment(_)
+            | Html::Comment(_)
+            | Html::Conditional(_)
+            | Html::Doctype { .. }
+            | Html::Empty
+            | Html::Text(_) => (),
+        }
+        path.pop();
+    }
+}
@@ -0,0 +1,53 @@
+//! Module to display an [`Html`] tree with the XHTML syntax.
+
+use core::fmt;
+
+use super::html::Html;
+use super::serialize::SerializePolicy;
+
+/// Wrapper to display an [`Html`] tree with the XHTML syntax
+///
+/// This is returned by [`Html::display_xhtml`]. Unlike the default
+/// [`Display`](fmt::Display) implementation of [`Html`]:
+///
+/// - all the tags are explicitly closed, and the empty void elements are
+///   written as self-closing tags, like `<br />`;
+/// - the names of the HTML tags and attributes are written in lowercase,
+///   whereas the names in `<svg>` and `<math>` are kept as they are;
+/// - attributes without values are given their name as value, like
+///   `checked="checked"`;
+/// - the bogus comments are written as comments, like `<!--foo-->` for
+///   `<!foo>`;
+/// - the preserved sources are ignored, as they follow the HTML syntax.
+///
+/// This is the output of [`Html::outer_html_with`] with
+/// [`SerializePolicy::xhtml`].
+#[derive(Debug, Clone, Copy)]
+pub struct XhtmlDisplay<'html>(&'html Html);
+
+impl fmt::Display for XhtmlDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_with(&SerializePolicy::new().xhtml(true), f)
+    }
+}
+
+impl Html {
+    /// Displays the tree with the XHTML syntax
+    ///
+    /// See [`XhtmlDisplay`] for the differences with the default
+    /// [`Display`](fmt::Display) implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use html_filter::prelude::*;
+    ///
+    /// let html = Html::parse("<P>a<br><input disabled/></P>").unwrap();
+    /// assert!(html.to_string() == "<P>a<br><input disabled></input></P>");
+    /// assert!(html.display_xhtml().to_string() == r#"<p>a<br /><input disabled="disabled" /></p>"#);
+    /// ```
+    #[must_use]
+    pub const fn display_xhtml(&self) -> XhtmlDisplay<'_> {
+        XhtmlDisplay(self)
+    }
+}
@@ -0,0 +1,49 @@
+use html_filter::prelude::*;
+
+fn parse(html: &str) -> String {
+    let options = ParseOptions::new().adoption_agency(true);
+    Html::parse_with_warnings(html, &options)
+        .unwrap()
+        .0
+        .to_string()
+}
+
+#[test]
+fn classic_misnesting() {
+    assert!(parse("<b><i>x</b></i>") == "<b><i>x</i></b><i></i>");
+}
+
+#[test]
+fn misnesting_with_text() {
+    assert!(parse("<p><b>1<i>2</b>3</i>4</p>") == "<p><b>1<i>2</i></b><i>3</i>4</p>");
+}
+
+#[test]
+fn corrected_tree() {
+    let options = ParseOptions::new().adoption_agency(true);
+    let (tree, warnings) = Html::parse_with_warnings("<b><i>x</b>y</i>", &options).unwrap();
+    assert!(warnings.is_empty());
+    let mut cursor = tree.cursor();
+    assert!(cursor.first_child());
+    assert!(matches!(cursor.node(), Html::Tag { tag, .. } if tag.as_name() == "b"));
+    assert!(cursor.first_child() && cursor.first_child());
+    assert!(matches!(cursor.node(), Html::Text(text) if text == "x"));
+    assert!(cursor.parent() && cursor.parent() && cursor.next_sibling());
+    assert!(cursor.node().to_string() == "<i>y</i>");
+}
+
+#[test]
+fn only_formatting_elements_are_reopened() {
+    assert!(parse("<b><span><i>x</b>y</i>") == "<b><span><i>x</i></span></b><i>y</i>");
+}
+
+#[test]
+fn well_nested_is_unchanged() {
+    let html = "<div><b><i>x</i></b><a href='#'>y</a></div>";
+    assert!(parse(html) == html);
+}
+
+#[test]
+fn disabled_by_default() {
+    assert!(Html::parse("<b><i>x</b></i>").is_err());
+}
@@ -0,0 +1,71 @@
+use html_filter::prelude::*;
+
+fn attrs(html: &str) -> Vec<(String, Option<String>)> {
+    let tree = Html::parse(html).unwrap();
+    let Html::Tag { tag, .. } = tree else {
+        panic!("expected a tag");
+    };
+    tag.as_attrs()
+        .iter()
+        .map(|attr| (attr.as_name().to_owned(), attr.as_value().cloned()))
+        .collect()
+}
+
+fn href() -> Vec<(String, Option<String>)> {
+    vec![("href".to_owned(), Some("x".to_owned()))]
+}
+
+#[test]
+fn spaces_around_eq() {
+    assert!(attrs(r#"<a href = "x">y</a>"#) == href());
+    assert!(attrs("<a href  =  'x'>y</a>")[0].1.as_deref() == Some("x"));
+}
+
+#[test]
+fn space_before_eq() {
+    assert!(attrs(r#"<a href ="x">y</a>"#) == href());
+}
+
+#[test]
+fn space_after_eq() {
+    assert!(attrs(r#"<a href= "x">y</a>"#) == href());
+}
+
+#[test]
+fn other_whitespace() {
+    assert!(attrs("<a href\n=\t\"x\">y</a>") == href());
+    assert!(attrs("<a\r\nhref\r\n=\r\n\"x\"\r\n>y</a>") == href());
+}
+
+#[test]
+fn boolean_attributes() {
+    let expected = vec![
+        ("disabled".to_owned(), None),
+        ("href".to_owned(), Some("x".to_owned())),
+        ("hidden".to_owned(), None),
+    ];
+    assert!(attrs(r#"<a disabled href="x" hidden>y</a>"#) == expected);
+    assert!(attrs(r#"<a disabled   href = "x" hidden >y</a>"#) == expected);
+    assert!(attrs("<input disabled />") == [("disabled".to_owned(), None)]);
+}
+
+#[test]
+fn display_normalised() {
+    let tree = Html::parse(r#"<a disabled href = "x">y</a>"#).unwrap();
+    assert!(tree.to_string() == r#"<a disabled href="x">y</a>"#);
+}
+
+#[test]
+fn unquoted_value_still_rejected() {
+    assert!(matches!(
+        Html::parse("<a href = x>y</a>").unwrap_err(),
+        ParseError::MissingAttributeQuote { character: 'x', .. }
+    ));
+}
+
+#[test]
+fn eq_without_name() {
+    let err = Html::parse(r#"<a href="x" ="y">z</a>"#).unwrap_err();
+    assert!(err.to_string() == "Invalid character '=' in attribute name.");
+    assert!(Html::parse(r#"<a / ="y">"#).is_err());
+}
@@ -0,0 +1,109 @@
+use html_filter::prelude::*;
+
+fn comment(html: &str) -> String {
+    match Html::parse(html).unwrap() {
+        Html::BogusComment(content) => content,
+        other => panic!("{html:?} gave {other:?}"),
+    }
+}
+
+#[test]
+fn unknown_declaration() {
+    assert!(comment("<!foo>") == "foo");
+    assert!(comment("<!ELEMENT br EMPTY>") == "ELEMENT br EMPTY");
+    assert!(comment("<!-audio>") == "-audio");
+}
+
+#[test]
+fn downlevel_conditional_comments() {
+    assert!(comment("<![if IE]>") == "[if IE]");
+    assert!(comment("<![endif]>") == "[endif]");
+    let html = Html::parse("<![if !IE]><p>Not IE</p><![endif]>").unwrap();
+    assert!(html.to_string() == "<![if !IE]><p>Not IE</p><![endif]>");
+}
+
+#[test]
+fn roundtrip() {
+    for input in [
+        "<!foo>",
+        "<![if IE]>text<![endif]>",
+        "<div><!x y='z'></div>",
+    ] {
+        let tree = Html::parse(input).unwrap();
+        assert!(Html::parse(&tree.to_string()).unwrap() == tree);
+    }
+}
+
+#[test]
+fn delimiters_are_kept() {
+    for input in [
+        "<!-- normal -->",
+        "<!foo>",
+        "<!---->",
+        "<!ELEMENT br EMPTY><!-- x -->",
+        "<p><!x y='z'><!--y--></p>",
+    ] {
+        assert!(Html::parse(input).unwrap().to_string() == input);
+    }
+    assert!(
+        matches!(Html::parse("<!-- foo -->").unwrap(), Html::Comment(content) if content == " foo ")
+    );
+    assert!(Html::parse("<!foo>").unwrap() != Html::parse("<!--foo-->").unwrap());
+}
+
+#[test]
+fn conversions() {
+    let html = Html::parse("<!foo><!--bar-->").unwrap();
+    assert!(html.comments() == ["foo", "bar"]);
+    assert!(
+        html.events()
+            == [
+                Event::BogusComment("foo".to_owned()),
+                Event::Comment("bar".to_owned())
+            ]
+    );
+    let json = html.to_json();
+    assert!(json == r#"[{"bogus_comment":"foo"},{"comment":"bar"}]"#);
+    assert!(Html::from_json(&json).unwrap() == html);
+    assert!(FlatHtml::from(&html).to_string() == "<!foo><!--bar-->");
+    assert!(html.display_xhtml().to_string() == "<!--foo--><!--bar-->");
+}
+
+#[test]
+fn validation() {
+    assert!(Html::BogusComment("foo".to_owned()).validate().is_ok());
+    for content in ["a>b", "--x", "doctype html", ""] {
+        let errors = Html::BogusComment(content.to_owned())
+            .validate()
+            .unwrap_err();
+        assert!(
+            matches!(errors.as_slice(), [ValidationError::InvalidBogusComment { path, .. }] if path == &[0])
+        );
+    }
+}
+
+#[test]
+fn unterminated() {
+    assert!(comment("<!foo") == "foo");
+}
+
+#[test]
+fn doctypes_are_kept() {
+    for input in [
+        "<!DOCTYPE html>",
+        "<!doctype html>",
+        "<!DocType html>",
+        "<!>",
+    ] {
+        assert!(matches!(Html::parse(input).unwrap(), Html::Doctype { .. }));
+    }
+}
+
+#[test]
+fn tokens() {
+    let tokens = Tokenizer::new("<![if IE]><br>")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(tokens[0] == Token::Comment("[if IE]".to_owned()));
+    assert!(matches!(&tokens[1], Token::TagOpen { name, .. } if name == "br"));
+}
@@ -0,0 +1,45 @@
+use html_filter::prelude::*;
+
+fn names(html: &Html) -> Vec<String> {
+    html.children().map(ToString::to_string).collect()
+}
+
+#[test]
+fn one_child() {
+    let html = Html::parse("<p>Text</p>").unwrap();
+    assert!(names(&html) == ["Text"]);
+}
+
+#[test]
+fn multiple_children() {
+    let html = Html::parse("<div><p>a</p>b<!-- c --></div>").unwrap();
+    assert!(names(&html) == ["<p>a</p>", "b", "<!-- c -->"]);
+}
+
+#[test]
+fn root_vec() {
+    let html = Html::parse("<p>a</p><p>b</p>").unwrap();
+    assert!(names(&html) == ["<p>a</p>", "<p>b</p>"]);
+}
+
+#[test]
+fn self_closing() {
+    let html = Html::parse("<div><br/><img src='a.png'></div>").unwrap();
+    let div = html.children().next().unwrap();
+    assert!(
+        div.children()
+            .all(|child| child.children().next().is_none())
+    );
+    assert!(Html::Empty.children().next().is_none());
+    assert!(Html::text("a").children().next().is_none());
+}
+
+#[test]
+fn children_mut() {
+    let mut html = Html::parse("<ul><li>a</li><li>b</li></ul>").unwrap();
+    for item in html.children_mut() {
+        *item = Html::tag("li", &[("class", Some("item"))]);
+    }
+    assert!(html.to_string() == r#"<ul><li class="item"></li><li class="item"></li></ul>"#);
+    assert!(Html::Empty.children_mut().next().is_none());
+}
@@ -0,0 +1,56 @@
+use html_filter::prelude::*;
+
+fn parse_tag(html: &str, options: &ParseOptions) -> Tag {
+    match Html::parse_with_warnings(html, options).unwrap().0 {
+        Html::Tag { tag, .. } => tag,
+        _ => panic!("expected a tag"),
+    }
+}
+
+#[test]
+fn duplicates_and_spaces() {
+    let mut tag = parse_tag(r#"<div class="a  b a">"#, &ParseOptions::new());
+    tag.normalize_classes();
+    assert!(tag.find_attr_value("class").unwrap() == "a b");
+    assert!(tag.to_string() == r#"div class="a b""#);
+
+    let mut tag = parse_tag(r#"<div CLASS=" b  b ">"#, &ParseOptions::new());
+    tag.normalize_classes();
+    assert!(tag.to_string() == r#"div CLASS="b""#);
+}
+
+#[test]
+fn first_seen_order() {
+    let mut tag = Tag::builder("p").attr("class", "\tc b\n a b c ").build();
+    tag.normalize_classes();
+    assert!(tag.find_attr_value("class").unwrap() == "c b a");
+
+    let mut tag = Tag::builder("p").attr("class", "   ").build();
+    tag.normalize_classes();
+    assert!(tag.find_attr_value("class").unwrap().is_empty());
+}
+
+#[test]
+fn missing_class() {
+    let mut tag = parse_tag("<p id='x' hidden>", &ParseOptions::new());
+    tag.normalize_classes();
+    assert!(tag.to_string() == "p id='x' hidden");
+
+    let mut tag = parse_tag("<p class>", &ParseOptions::new());
+    tag.normalize_classes();
+    assert!(tag.to_string() == "p class");
+}
+
+#[test]
+fn source_dropped_when_modified() {
+    let options = ParseOptions::new().preserve_source(true);
+    let mut tag = parse_tag("<p  class='a'>", &options);
+    tag.normalize_classes();
+    let html = Html::from(tag);
+    assert!(html.to_string() == "<p  class='a'>");
+
+    let mut tag = parse_tag("<p  class='a a'>", &options);
+    tag.normalize_classes();
+    let html = Html::from(tag);
+    assert!(html.to_string() == "<p class='a'></p>");
+}
@@ -0,0 +1,43 @@
+use html_filter::prelude::*;
+
+#[test]
+fn nested_comments() {
+    let html = Html::parse(
+        r#"<!-- build: 2024-10-16 -->
+<!DOCTYPE html>
+<html>
+    <head><!-- meta --><title>T <!-- not a comment --></title></head>
+    <body>
+        <div><ul><li><!-- deep --></li></ul><!----></div>
+        <script>// <!-- raw --></script>
+        <template><!-- inert --></template>
+    </body>
+</html>
+<!-- trailing"#,
+    )
+    .unwrap();
+    assert!(
+        html.comments()
+            == [
+                " build: 2024-10-16 ",
+                " meta ",
+                " deep ",
+                "",
+                " inert ",
+                " trailing"
+            ]
+    );
+    assert!(
+        html.comments()
+            .into_iter()
+            .find(|comment| comment.trim_start().starts_with("build:"))
+            == Some(" build: 2024-10-16 ")
+    );
+}
+
+#[test]
+fn no_comments() {
+    assert!(Html::parse("<p>a</p>").unwrap().comments().is_empty());
+    assert!(Html::Empty.comments().is_empty());
+    assert!(Html::comment("x").comments() == ["x"]);
+}
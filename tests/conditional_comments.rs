@@ -0,0 +1,72 @@
+use html_filter::prelude::*;
+
+const HTML: &str = "<head><title>Page</title><!--[if lt IE 9]><script src='shiv.js'></script><link rel='stylesheet' href='ie.css' /><![endif]--></head>";
+
+fn parse(html: &str) -> Html {
+    let options = ParseOptions::new().parse_conditional_comments(true);
+    Html::parse_with_warnings(html, &options).unwrap().0
+}
+
+#[test]
+fn disabled_by_default() {
+    let tree = Html::parse(HTML).unwrap();
+    assert!(tree.to_string() == HTML);
+    assert!(tree.stats().comment_count() == 1);
+    assert!(tree.stats().tag_count("link") == 0);
+}
+
+#[test]
+fn parsed_link() {
+    let tree = parse(HTML);
+    assert!(
+        tree.to_string()
+            == "<head><title>Page</title><!--[if lt IE 9]><script src='shiv.js'></script><link rel='stylesheet' href='ie.css'></link><![endif]--></head>"
+    );
+    assert!(tree.stats().comment_count() == 0);
+    assert!(tree.stats().tag_count("link") == 1);
+    let link = tree.find(&Filter::new().tag_name("link"));
+    assert!(link.to_string() == "<link rel='stylesheet' href='ie.css'></link>");
+}
+
+#[test]
+fn other_comments_are_kept() {
+    assert!(
+        parse("<!-- [if IE] --><p>a</p><!--x-->").to_string() == "<!-- [if IE] --><p>a</p><!--x-->"
+    );
+    assert!(parse("<p><![endif]--></p>").stats().comment_count() == 1);
+}
+
+#[test]
+fn nested() {
+    let html = "<!--[if IE]><p><!--[if IE 6]><b>6</b><![endif]--></p><![endif]-->";
+    let tree = parse(html);
+    assert!(tree.to_string() == html);
+    assert!(tree.find(&Filter::new().tag_name("b")).to_string() == "<b>6</b>");
+}
+
+#[test]
+fn warning_positions() {
+    let options = ParseOptions::new()
+        .parse_conditional_comments(true)
+        .lenient(true);
+    let (_, warnings) =
+        Html::parse_with_warnings("<!--[if IE]><p></b></p><![endif]-->", &options).unwrap();
+    assert!(warnings.len() == 1);
+    assert!(warnings[0].position().column() == 16);
+}
+
+#[test]
+fn markers() {
+    let tree = parse("<!--[if lt IE 9]><br><![endif]-->");
+    assert!(
+        tree.events()
+            == [
+                Event::Conditional(Some("lt IE 9".to_owned())),
+                Event::Start(Tag::builder("br").build()),
+                Event::End("br".to_owned()),
+                Event::Conditional(None),
+            ]
+    );
+    assert!(Html::from_json(&tree.to_json()).unwrap() == tree);
+    assert!(tree.to_flat().to_string() == tree.to_string());
+}
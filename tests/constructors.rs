@@ -0,0 +1,34 @@
+use html_filter::prelude::*;
+
+#[test]
+fn build_document() {
+    let html = Html::Vec(Box::new([
+        Html::tag("div", &[("id", Some("main")), ("hidden", None)])
+            .with_child(Html::tag("h1", &[]).with_child(Html::text("Title")))
+            .with_child(Html::text("Some "))
+            .with_child(Html::tag("b", &[]).with_child(Html::text("bold")))
+            .with_child(Html::text(" text")),
+        Html::comment(" generated "),
+    ]));
+    let expected =
+        r#"<div id="main" hidden><h1>Title</h1>Some <b>bold</b> text</div><!-- generated -->"#;
+    assert!(html.to_string() == expected);
+    assert!(html == Html::parse(expected).unwrap());
+}
+
+#[test]
+fn namespace() {
+    let svg = Html::tag("svg", &[]).with_child(Html::tag("rect", &[]));
+    if let Html::Tag { tag, .. } = &svg {
+        assert!(tag.as_namespace() == Namespace::Svg);
+    }
+    assert!(svg.to_string() == "<svg><rect></rect></svg>");
+}
+
+#[test]
+fn is_empty() {
+    assert!(Html::default().is_empty());
+    assert!(!Html::comment("").is_empty());
+    assert!(!Html::tag("p", &[]).is_empty());
+    assert!(Html::text("x").with_child(Html::text("y")) == Html::text("x"));
+}
@@ -0,0 +1,49 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<div class="a"><p>b</p></div>"#;
+
+#[test]
+fn from_str() {
+    let tree: Html = HTML.parse().unwrap();
+    assert!(tree == Html::parse(HTML).unwrap());
+    assert!("<a></b>".parse::<Html>().is_err());
+}
+
+#[test]
+fn try_from_str() {
+    let tree = Html::try_from(HTML).unwrap();
+    assert!(tree.to_string() == HTML);
+    let converted: Result<Html, _> = "<p>".try_into();
+    assert!(converted.is_ok());
+}
+
+#[test]
+fn try_from_string() {
+    let tree = Html::try_from(HTML.to_owned()).unwrap();
+    assert!(tree == Html::parse(HTML).unwrap());
+}
+
+#[test]
+fn errors() {
+    let expected = Html::parse("<div></span>").unwrap_err();
+    assert!(Html::try_from("<div></span>").unwrap_err() == expected);
+    assert!(Html::try_from("<div></span>".to_owned()).unwrap_err() == expected);
+    assert!(matches!(
+        Html::try_from("<p id=x>"),
+        Err(ParseError::MissingAttributeQuote { character: 'x', .. })
+    ));
+}
+
+fn parse_generic<T>(input: T) -> Result<usize, ParseError>
+where
+    T: TryInto<Html, Error = ParseError>,
+{
+    Ok(input.try_into()?.stats().tag_count("p"))
+}
+
+#[test]
+fn generic_context() {
+    assert!(parse_generic(HTML) == Ok(1));
+    assert!(parse_generic(HTML.to_owned()) == Ok(1));
+    assert!(parse_generic("</a>").is_err());
+}
@@ -0,0 +1,47 @@
+use html_filter::prelude::*;
+
+const HTML: &str = "<!DOCTYPE html><html><body><main><section><p>Deep <b>text</b></p></section></main></body></html>";
+
+#[test]
+fn up_from_nested_text() {
+    let tree = Html::parse(HTML).unwrap();
+    let mut cursor = tree.cursor();
+    assert!(cursor.first_child());
+    assert!(matches!(cursor.node(), Html::Doctype { .. }));
+    assert!(cursor.next_sibling());
+    while cursor.last_child() {}
+    assert!(matches!(cursor.node(), Html::Text(text) if text == "text"));
+    assert!(cursor.depth() == 7);
+
+    let mut names = vec![];
+    while cursor.parent() {
+        if let Html::Tag { tag, .. } = cursor.node() {
+            names.push(tag.as_name().clone());
+        }
+    }
+    assert!(names == ["b", "p", "section", "main", "body", "html"]);
+    assert!(std::ptr::eq(cursor.node(), &tree));
+    assert!(cursor.depth() == 0);
+}
+
+#[test]
+fn siblings() {
+    let tree = Html::parse("<p>a<br>b<i>c</i></p>").unwrap();
+    let mut cursor = tree.cursor();
+    assert!(!cursor.next_sibling() && !cursor.prev_sibling() && !cursor.parent());
+    assert!(cursor.child(2));
+    assert!(matches!(cursor.node(), Html::Text(text) if text == "b"));
+    assert!(cursor.next_sibling() && !cursor.next_sibling());
+    assert!(cursor.prev_sibling() && cursor.prev_sibling());
+    assert!(!cursor.first_child());
+    assert!(matches!(cursor.node(), Html::Tag { tag, .. } if tag.as_name() == "br"));
+    assert!(!cursor.child(0) && cursor.parent() && !cursor.child(4));
+}
+
+#[test]
+fn empty() {
+    let tree = Html::new();
+    let mut cursor = tree.cursor();
+    assert!(!cursor.first_child() && !cursor.last_child());
+    assert!(matches!(cursor.node(), Html::Empty));
+}
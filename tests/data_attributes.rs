@@ -0,0 +1,40 @@
+use html_filter::prelude::*;
+
+#[test]
+fn multiple_data_attributes() {
+    let html = Html::parse(
+        r#"<button data-user-id="5" data-action='save' data-confirm data-user-id="6">Save</button>"#,
+    )
+    .unwrap();
+    let data = html.data_attributes();
+    assert!(data.len() == 3);
+    assert!(data["user-id"] == "5");
+    assert!(data["action"] == "save");
+    assert!(data["confirm"].is_empty());
+    assert!(data.keys().collect::<Vec<_>>() == ["action", "confirm", "user-id"]);
+}
+
+#[test]
+fn non_data_attributes_excluded() {
+    let html =
+        Html::parse(r#"<a href="/" class="x" data="raw" database="db" aria-label="home">x</a>"#)
+            .unwrap();
+    assert!(html.data_attributes().is_empty());
+
+    let html = Html::parse(r#"<p id="a" data-x="1">"#).unwrap();
+    let data = html.data_attributes();
+    assert!(data.len() == 1);
+    assert!(data.get("x").is_some_and(|value| value == "1"));
+    assert!(!data.contains_key("id"));
+}
+
+#[test]
+fn not_a_tag() {
+    assert!(Html::text("data-x").data_attributes().is_empty());
+    assert!(
+        Html::parse("<p data-a='1'></p><p data-b='2'></p>")
+            .unwrap()
+            .data_attributes()
+            .is_empty()
+    );
+}
@@ -0,0 +1,40 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<!DOCTYPE html><html lang="en"><head><title>Page</title></head><body><!-- menu --><ul class="nav"><li>a</li><li><input disabled></li></ul></body></html>"#;
+
+#[test]
+fn alternate_outline() {
+    let html = Html::parse(HTML).unwrap();
+    let expected = r#"Vec
+  Doctype(DOCTYPE html)
+  Tag(html lang="en")
+    Tag(head)
+      Tag(title)
+        Text("Page")
+    Tag(body)
+      Comment(" menu ")
+      Tag(ul class="nav")
+        Tag(li)
+          Text("a")
+        Tag(li)
+          Tag(input disabled)"#;
+    assert!(format!("{html:#?}") == expected, "{html:#?}");
+}
+
+#[test]
+fn alternate_leaves() {
+    assert!(format!("{:#?}", Html::Empty) == "Empty");
+    assert!(format!("{:#?}", Html::text("a\nb")) == r#"Text("a\nb")"#);
+    assert!(format!("{:#?}", Html::comment("c")) == r#"Comment("c")"#);
+}
+
+#[test]
+fn default_format() {
+    let html = Html::parse("a<!--b-->").unwrap();
+    assert!(format!("{html:?}") == r#"Vec([Text("a"), Comment("b")])"#);
+
+    let html = Html::parse("<p></p>").unwrap();
+    let debug = format!("{html:?}");
+    assert!(debug.starts_with("Tag { tag: Tag { "));
+    assert!(debug.ends_with(", child: Empty }"));
+}
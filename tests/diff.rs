@@ -0,0 +1,82 @@
+use html_filter::prelude::*;
+
+fn diff(old: &str, new: &str) -> Vec<Patch> {
+    Html::diff(&Html::parse(old).unwrap(), &Html::parse(new).unwrap())
+}
+
+#[test]
+fn identical() {
+    let html = "<div id='a'><p>Text</p><!-- note --></div>";
+    assert!(diff(html, html).is_empty());
+}
+
+#[test]
+fn changed_text() {
+    let patches = diff("<div><p>Old</p><p>Same</p></div>", "<div><p>New</p><p>Same</p></div>");
+    assert!(patches == [Patch::SetText { path: vec![0, 0, 0], text: "New".to_owned() }]);
+}
+
+#[test]
+fn added_child() {
+    let patches = diff("<ul><li>A</li><li>C</li></ul>", "<ul><li>A</li><li>B</li><li>C</li></ul>");
+    assert!(patches.len() == 1);
+    assert!(matches!(
+        &patches[0],
+        Patch::Insert { node, path } if path == &[0, 1] && node.to_string() == "<li>B</li>"
+    ));
+}
+
+#[test]
+fn removed_children() {
+    let patches = diff("<p>a</p><p>b</p><p>c</p><p>d</p>", "<p>a</p><p>d</p>");
+    assert!(
+        patches
+            == [
+                Patch::Remove { path: vec![2] },
+                Patch::Remove { path: vec![1] }
+            ]
+    );
+}
+
+#[test]
+fn changed_attribute() {
+    let patches = diff(
+        r#"<a href="old" class="link" hidden>Link</a>"#,
+        r#"<a href="new" class="link">Link</a>"#,
+    );
+    assert!(patches.len() == 2);
+    assert!(matches!(
+        &patches[0],
+        Patch::RemoveAttribute { name, path } if name == "hidden" && path == &[0]
+    ));
+    assert!(matches!(
+        &patches[1],
+        Patch::SetAttribute { attr, path }
+            if path == &[0] && attr.as_name() == "href" && attr.as_value().unwrap() == "new"
+    ));
+}
+
+#[test]
+fn replaced_node() {
+    let patches = diff("<div><p>Text</p></div>", "<div><span>Text</span></div>");
+    assert!(patches.len() == 1);
+    assert!(matches!(
+        &patches[0],
+        Patch::Replace { node, path } if path == &[0, 0] && node.to_string() == "<span>Text</span>"
+    ));
+}
+
+#[test]
+fn reordered_attributes() {
+    let patches = diff("<p id='a' class='b'></p>", "<p class='b' id='a'></p>");
+    assert!(matches!(&patches[..], [Patch::Replace { path, .. }] if path == &[0]));
+}
+
+#[test]
+fn preserved_source_is_ignored() {
+    let options = ParseOptions::new().preserve_source(true);
+    let (old, _) = Html::parse_with_warnings("<p  id='a' >Text</p >", &options).unwrap();
+    let new = Html::parse("<p id='a'>Text</p>").unwrap();
+    assert!(old == new);
+    assert!(Html::diff(&old, &new).is_empty());
+}
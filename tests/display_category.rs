@@ -0,0 +1,35 @@
+use html_filter::prelude::*;
+
+#[test]
+fn names() {
+    assert!(DisplayCategory::of("div") == DisplayCategory::Block);
+    assert!(DisplayCategory::of("Table") == DisplayCategory::Block);
+    assert!(DisplayCategory::of("span") == DisplayCategory::Inline);
+    assert!(DisplayCategory::of("td") == DisplayCategory::Inline);
+    assert!(DisplayCategory::of("custom-element") == DisplayCategory::Inline);
+    assert!(DisplayCategory::of("head") == DisplayCategory::Other);
+    assert!(DisplayCategory::of("STYLE") == DisplayCategory::Other);
+}
+
+#[test]
+fn nodes() {
+    let html = Html::parse("<div><span>a</span><custom-element>b</custom-element>c<!--d--></div>")
+        .unwrap();
+    assert!(html.is_block_element());
+    assert!(!html.is_inline_element());
+    let children = html.children().collect::<Vec<_>>();
+    assert!(children.len() == 4);
+    assert!(children[0].is_inline_element());
+    assert!(children[1].is_inline_element());
+    for child in &children[2..] {
+        assert!(!child.is_inline_element());
+        assert!(!child.is_block_element());
+    }
+}
+
+#[test]
+fn hidden_elements() {
+    let html = Html::parse("<script>a</script>").unwrap();
+    assert!(!html.is_block_element());
+    assert!(!html.is_inline_element());
+}
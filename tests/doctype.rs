@@ -0,0 +1,80 @@
+use html_filter::prelude::*;
+
+fn roundtrip(html: &str) -> String {
+    Html::parse(html).unwrap().to_string()
+}
+
+#[test]
+fn with_attribute() {
+    assert!(roundtrip("<!doctype html>") == "<!doctype html>");
+    assert!(roundtrip("<!DOCTYPE html><p>a</p>") == "<!DOCTYPE html><p>a</p>");
+}
+
+#[test]
+fn without_attribute() {
+    assert!(roundtrip("<!doctype>") == "<!doctype>");
+    assert!(roundtrip("<!DOCTYPE >") == "<!DOCTYPE>");
+    assert!(roundtrip("<!>") == "<!>");
+}
+
+#[test]
+fn other_names() {
+    // `<!name>` is read as a bogus comment, but a doctype can still be named
+    // otherwise when built by hand.
+    assert!(roundtrip("<!name>") == "<!name>");
+    let tree = Html::from_json(r#"[{"doctype":"name"},{"doctype":"name","attr":"x"}]"#).unwrap();
+    assert!(tree.to_string() == "<!name><!name x>");
+    assert!(FlatHtml::from(&tree).to_string() == "<!name><!name x>");
+    assert!(tree.display_xhtml().to_string() == "<!name><!name x>");
+}
+
+#[test]
+fn preserved_spacing() {
+    let options = ParseOptions::new().preserve_source(true);
+    for html in [
+        "<!DOCTYPE >",
+        "<!doctype  html >",
+        "<!doctype>",
+        "<!doctype html>",
+    ] {
+        let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+        assert!(tree.to_string() == html);
+    }
+}
+
+const SUBSET: &str = r#"<!DOCTYPE note [<!ELEMENT note (to)><!ENTITY a "]>">]><note>x</note>"#;
+
+#[test]
+fn internal_subset() {
+    assert!(roundtrip(SUBSET) == SUBSET);
+    assert!(
+        roundtrip("<!DOCTYPE note [ <!ELEMENT note (#PCDATA)> ] >")
+            == "<!DOCTYPE note [ <!ELEMENT note (#PCDATA)> ]>"
+    );
+    assert!(roundtrip("<!DOCTYPE [<!ENTITY a 'b'>]>") == "<!DOCTYPE [<!ENTITY a 'b'>]>");
+    let tree = Html::parse(SUBSET).unwrap();
+    let Html::Vec(nodes) = &tree else {
+        unreachable!()
+    };
+    assert!(matches!(
+        &nodes[0],
+        Html::Doctype { name, attr: Some(attr), subset: Some(subset), .. }
+            if name == "DOCTYPE" && attr == "note" && subset == r#"<!ELEMENT note (to)><!ENTITY a "]>">"#
+    ));
+    assert!(FlatHtml::from(&tree).to_string() == SUBSET);
+    assert!(Html::from_json(&tree.to_json()).unwrap() == tree);
+    assert!(tree != Html::parse("<!DOCTYPE note><note>x</note>").unwrap());
+}
+
+#[test]
+fn unterminated_subset() {
+    assert!(
+        Html::parse("<!DOCTYPE note [<!ELEMENT note (to)>").unwrap_err() == ParseError::UnclosedTag
+    );
+    assert!(Html::parse("<!DOCTYPE note [<!ENTITY a ']>").unwrap_err() == ParseError::UnclosedTag);
+    assert!(Html::parse("<!DOCTYPE note []").unwrap_err() == ParseError::UnclosedTag);
+    assert!(matches!(
+        Html::parse("<!DOCTYPE note [] x>"),
+        Err(ParseError::InvalidCharacter { character: 'x', .. })
+    ));
+}
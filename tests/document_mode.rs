@@ -0,0 +1,45 @@
+use html_filter::prelude::*;
+
+fn document(html: &str) -> Html {
+    let options = ParseOptions::new().document_mode(true);
+    Html::parse_with_warnings(html, &options).unwrap().0
+}
+
+#[test]
+fn head_and_body_inserted() {
+    let tree = document("<title>x</title><p>y</p>");
+    assert!(tree.to_string() == "<html><head><title>x</title></head><body><p>y</p></body></html>");
+    assert!(tree.select("html > head > title").unwrap().len() == 1);
+    assert!(tree.select("html > body > p").unwrap().len() == 1);
+    assert!(tree.select("head p, body title").unwrap().is_empty());
+}
+
+#[test]
+fn fragment_by_default() {
+    let html = "<title>x</title><p>y</p>";
+    assert!(Html::parse(html).unwrap().to_string() == html);
+}
+
+#[test]
+fn existing_tags_kept() {
+    let html = "<!DOCTYPE html>\n<html lang='en'><head><meta charset='utf-8'></head>\n<body class='a'><p>y</p></body></html>";
+    let tree = document(html);
+    assert!(
+        tree.to_string()
+            == "<!DOCTYPE html>\n<html lang='en'><head><meta charset='utf-8'></meta>\n</head><body class='a'><p>y</p></body></html>"
+    );
+}
+
+#[test]
+fn metadata_after_content() {
+    let tree = document("<!-- c --><link rel='a'/>text<meta name='b'/>");
+    assert!(
+        tree.to_string()
+            == "<!-- c --><html><head><link rel='a'></link></head><body>text<meta name='b'></meta></body></html>"
+    );
+}
+
+#[test]
+fn empty_document() {
+    assert!(document("").to_string() == "<html><head></head><body></body></html>");
+}
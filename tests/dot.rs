@@ -0,0 +1,39 @@
+use html_filter::prelude::*;
+
+#[test]
+fn nodes_and_edges() {
+    let html = Html::parse("<!doctype html><ul><li>a</li><li><!-- b --></li></ul>text").unwrap();
+    let dot = html.to_dot();
+    assert!(dot.starts_with("digraph html {\n") && dot.ends_with("}\n"));
+    assert!(dot.matches("[label=").count() == 7);
+    assert!(dot.matches(" -> ").count() == 4);
+    for line in [
+        "n0 [label=\"<!doctype html>\"];",
+        "n1 [label=\"<ul>\"];",
+        "n2 [label=\"<li>\"];",
+        "n1 -> n2;",
+        "n3 [label=\"\\\"a\\\"\"];",
+        "n2 -> n3;",
+        "n1 -> n4;",
+        "n5 [label=\"<!-- b -->\"];",
+        "n4 -> n5;",
+        "n6 [label=\"\\\"text\\\"\"];",
+    ] {
+        assert!(dot.contains(line), "{line} not in {dot}");
+    }
+    assert!(!dot.contains("-> n6"));
+}
+
+#[test]
+fn long_labels() {
+    let html = Html::parse("<p>a \"quoted\"\ntext that goes on and on</p>").unwrap();
+    assert!(
+        html.to_dot()
+            .contains("n1 [label=\"\\\"a \\\"quoted\\\"\\ntext that...\\\"\"];")
+    );
+}
+
+#[test]
+fn empty() {
+    assert!(Html::Empty.to_dot() == "digraph html {\n}\n");
+}
@@ -0,0 +1,48 @@
+use html_filter::prelude::*;
+
+fn roundtrip(html: &str) -> String {
+    Html::parse(html).unwrap().to_string()
+}
+
+#[test]
+fn parsed() {
+    assert!(roundtrip("<>content</>") == "<>content</>");
+    assert!(roundtrip("<p><>a</>b</p>") == "<p><>a</>b</p>");
+    assert!(roundtrip("<>") == "<></>");
+    assert!(roundtrip("< >x") == "< >x");
+    let tree = Html::parse("<>content</>").unwrap();
+    let Html::Tag { tag, child, .. } = &tree else {
+        unreachable!()
+    };
+    assert!(tag.as_name().is_empty());
+    assert!(**child == Html::text("content"));
+}
+
+#[test]
+fn built() {
+    let html = Html::from(Tag::builder("").build()).with_child(Html::text("content"));
+    assert!(html.to_string() == "<>content</>");
+    assert!(html.display_xhtml().to_string() == "<>content</>");
+    assert!(FlatHtml::from(&html).to_string() == "<>content</>");
+    assert!(Html::from(Tag::builder("").build()).to_string() == "<></>");
+    assert!(Html::parse(&html.to_string()).unwrap() == html);
+}
+
+#[test]
+fn no_space() {
+    let tag = Tag::builder("").build();
+    assert!(tag.to_string().is_empty());
+    let html = Html::from(tag).with_child(Html::from(Tag::builder("").build()));
+    assert!(!html.to_string().contains(' '));
+}
+
+#[test]
+fn empty_closing_tag() {
+    let options = ParseOptions::new();
+    let (tree, warnings) = Html::parse_with_warnings("<><p>a</>b", &options).unwrap();
+    assert!(warnings.is_empty());
+    assert!(tree.to_string() == "<><p>a</p></>b");
+    let (tree, warnings) = Html::parse_with_warnings("<p>a</>b</p>", &options).unwrap();
+    assert!(matches!(warnings.as_slice(), [ParseWarning::EmptyClosingTag { .. }]));
+    assert!(tree.to_string() == "<p>ab</p>");
+}
@@ -0,0 +1,59 @@
+use html_filter::prelude::*;
+
+#[test]
+fn utf8_bom() {
+    let tree = Html::parse_bytes("\u{feff}<p>Caf\u{e9}</p>".as_bytes()).unwrap();
+    assert!(tree.to_string() == "<p>Caf\u{e9}</p>");
+    assert!(Html::parse_bytes(b"<p>a</p>").unwrap() == Html::parse("<p>a</p>").unwrap());
+}
+
+#[test]
+fn utf16_bom() {
+    let bytes = [0xFF, 0xFE, b'<', 0, b'b', 0, b'>', 0, 0xE9, 0];
+    assert!(Html::parse_bytes(&bytes).unwrap().to_string() == "<b>\u{e9}</b>");
+    let bytes = [0xFE, 0xFF, 0xD8, 0x3D, 0xDE, 0x00];
+    assert!(Html::parse_bytes(&bytes).unwrap().to_string() == "\u{1f600}");
+}
+
+#[test]
+fn latin1_override() {
+    let bytes = b"<p title='\xE9t\xE9'>Gr\xFC\xDFe</p>";
+    assert!(Html::parse_bytes(bytes).is_err());
+    let tree = Html::parse_bytes_with_encoding(bytes, Encoding::Latin1).unwrap();
+    assert!(tree.to_string() == "<p title='\u{e9}t\u{e9}'>Gr\u{fc}\u{df}e</p>");
+}
+
+#[test]
+fn invalid_sequences() {
+    let err = Html::parse_bytes(b"\xEF\xBB\xBF<p>\xC3</p>").unwrap_err();
+    assert!(matches!(err, ParseError::InvalidEncoding { encoding: "UTF-8", offset: 6, .. }));
+    assert!(err.to_string() == "Invalid UTF-8 sequence at byte 6.");
+
+    let err = Html::parse_bytes(&[0xFF, 0xFE, b'a', 0, 0x00, 0xDC]).unwrap_err();
+    assert!(matches!(
+        err,
+        ParseError::InvalidEncoding { encoding: "UTF-16LE", offset: 4, .. }
+    ));
+    let err = Html::parse_bytes_with_encoding(b"\0a\0", Encoding::Utf16Be).unwrap_err();
+    assert!(matches!(err, ParseError::InvalidEncoding { offset: 2, .. }));
+}
+
+#[cfg(feature = "charset")]
+#[test]
+fn meta_charset() {
+    let html = b"<html><head><META Charset=\"ISO-8859-1\"><title>Caf\xE9</title></head></html>";
+    assert!(Encoding::detect(html) == Encoding::Latin1);
+    let tree = Html::parse_bytes(html).unwrap();
+    assert!(tree.to_string().contains("<title>Caf\u{e9}</title>"));
+
+    let html = b"<meta http-equiv='Content-Type' content='text/html; charset=latin1'>\xE9";
+    assert!(Encoding::detect(html) == Encoding::Latin1);
+    assert!(Encoding::detect(b"<meta charset='unknown'>") == Encoding::Utf8);
+    assert!(Encoding::detect(b"\xEF\xBB\xBF<meta charset='latin1'>") == Encoding::Utf8);
+}
+
+#[cfg(not(feature = "charset"))]
+#[test]
+fn meta_charset_ignored() {
+    assert!(Encoding::detect(b"<meta charset='latin1'>") == Encoding::Utf8);
+}
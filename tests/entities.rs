@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<a title="a&amp;b">x &amp; y</a>"#;
+
+fn parse(attr: bool, text: bool) -> String {
+    let options = ParseOptions::new()
+        .decode_attr_entities(attr)
+        .decode_text_entities(text);
+    Html::parse_with_warnings(HTML, &options)
+        .unwrap()
+        .0
+        .to_string()
+}
+
+#[test]
+fn neither() {
+    assert!(parse(false, false) == HTML);
+    assert!(Html::parse(HTML).unwrap().to_string() == HTML);
+}
+
+#[test]
+fn only_attr() {
+    assert!(parse(true, false) == r#"<a title="a&b">x &amp; y</a>"#);
+}
+
+#[test]
+fn only_text() {
+    assert!(parse(false, true) == r#"<a title="a&amp;b">x & y</a>"#);
+}
+
+#[test]
+fn both() {
+    assert!(parse(true, true) == r#"<a title="a&b">x & y</a>"#);
+}
+
+#[test]
+fn references() {
+    let options = ParseOptions::new().decode_text_entities(true);
+    let decode = |html: &str| {
+        Html::parse_with_warnings(html, &options)
+            .unwrap()
+            .0
+            .text_content(false)
+    };
+    assert!(decode("&lt;&gt;&quot;&apos;&nbsp;") == "<>\"'\u{a0}");
+    assert!(decode("&#65;&#x42;&#X43;") == "ABC");
+    assert!(decode("&unknown; &amp &#xZZ; &#0; & ;") == "&unknown; & &#xZZ; &#0; & ;");
+    assert!(decode("&&amp;;") == "&&;");
+}
+
+#[test]
+fn raw_text_not_decoded() {
+    let options = ParseOptions::new().decode_text_entities(true);
+    let html = "<script>a &amp;&amp; b</script><p>&amp;</p>";
+    let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    assert!(tree.to_string() == "<script>a &amp;&amp; b</script><p>&</p>");
+}
+
+fn decode_text(html: &str) -> String {
+    let options = ParseOptions::new().decode_text_entities(true);
+    Html::parse_with_warnings(html, &options)
+        .unwrap()
+        .0
+        .text_content(false)
+}
+
+fn decode_attr(value: &str) -> String {
+    let options = ParseOptions::new().decode_attr_entities(true);
+    let (tree, _) = Html::parse_with_warnings(&format!("<a title=\"{value}\">"), &options).unwrap();
+    let Html::Tag { tag, .. } = tree else {
+        panic!("expected a tag");
+    };
+    tag.find_attr_value("title").unwrap().clone()
+}
+
+#[test]
+fn bare_ampersand() {
+    assert!(decode_text("Tom & Jerry") == "Tom & Jerry");
+    assert!(decode_attr("Tom & Jerry") == "Tom & Jerry");
+    assert!(decode_text("&") == "&");
+    assert!(decode_text("a&&b;&") == "a&&b;&");
+}
+
+#[test]
+fn ambiguous_reference() {
+    assert!(decode_text("I'm &notit; I tell you") == "I'm ¬it; I tell you");
+    assert!(decode_text("I'm &notin; I tell you") == "I'm ∉ I tell you");
+    assert!(decode_attr("&notit;") == "&notit;");
+    assert!(decode_attr("&not it") == "¬ it");
+}
+
+#[test]
+fn empty_numeric_reference() {
+    assert!(decode_text("&#;") == "&#;");
+    assert!(decode_text("&#x;") == "&#x;");
+    assert!(decode_attr("&#;&#X;") == "&#;&#X;");
+}
+
+#[test]
+fn legacy_without_semicolon() {
+    assert!(decode_text("&copy 2024 &reg") == "© 2024 ®");
+    assert!(decode_text("&copy;&amp;&lt") == "©&<");
+    assert!(decode_text("&apos &notin") == "&apos ¬in");
+    assert!(decode_attr("?a=1&copy=2&amp;b&lt") == "?a=1&copy=2&b<");
+}
+
+fn custom(html: &str) -> String {
+    let entities = BTreeMap::from([
+        ("mydash".to_owned(), "\u{2014}".to_owned()),
+        ("amp".to_owned(), "and".to_owned()),
+        ("abbr".to_owned(), "HyperText".to_owned()),
+    ]);
+    let options = ParseOptions::new()
+        .custom_entities(entities)
+        .decode_attr_entities(true)
+        .decode_text_entities(true);
+    Html::parse_with_warnings(html, &options)
+        .unwrap()
+        .0
+        .to_string()
+}
+
+#[test]
+fn custom_entities() {
+    assert!(custom("a&mydash;b") == "a\u{2014}b");
+    assert!(
+        custom("<p title='&abbr;'>&abbr; &lt; &mydash</p>")
+            == "<p title='HyperText'>HyperText < &mydash</p>"
+    );
+    assert!(custom("&unknown; &#65;") == "&unknown; A");
+}
+
+#[test]
+fn custom_entities_precedence() {
+    assert!(custom("a &amp; b") == "a and b");
+    assert!(custom("a &amp b") == "a & b");
+}
+
+#[test]
+fn custom_entities_without_decoding() {
+    let entities = BTreeMap::from([("mydash".to_owned(), "\u{2014}".to_owned())]);
+    let options = ParseOptions::new().custom_entities(entities);
+    let (tree, _) = Html::parse_with_warnings("a&mydash;b", &options).unwrap();
+    assert!(tree.to_string() == "a&mydash;b");
+}
@@ -9,7 +9,7 @@ macro_rules! make_err_test {
                 let expected = $err;
                 if let Err(err) = Html::parse(html) {
                     assert!(
-                        err == expected,
+                        err.to_string() == expected,
                         "Error mismatch! Expected\n{expected}\nbut found \n{err}\n."
                     )
                 } else {
@@ -23,17 +23,23 @@ macro_rules! make_err_test {
 make_err_test!(
 
 bang_closing: "</!doc h>" => "Invalid character '!' in closing tag."
-invalid_dash: "<!-audio>" => "Invalid character '-' in doctype."
-doctype_val: "<!dx h=''>" => "Doctype attribute must not have a value."
-close_doctype: "<!doc />" => "Invalid character '/' in doctype."
+doctype_val: "<!doctype h=''>" => "Doctype attribute must not have a value."
+close_doctype: "<!DOCTYPE />" => "Invalid character '/' in doctype."
 invalid_bang: "<button!>" => "Invalid character '!' in tag name."
-prefix_name: "<image:br>" => "Invalid character ':' in tag name."
+invalid_name_char: "<image$br>" => "Invalid character '$' in tag name."
 invalid_equal: "<p id=a>" => "Invalid character 'a': expected ''' or '\"' after '=' sign."
 unclosed_tag: "<textarea" => "EOF: Missing closing '>'."
-unopened_tag: "<br></em>" => "Invalid closing tag: Found closing tag for 'em' but it isn't open."
+unopened_tag: "<br></em>" => "Invalid closing tag: Found closing tag for 'em' but it isn't open at line 1, column 5."
 unopened_comment: " --> " => "Tried to close unopened comment."
 attr_close: "</a id='c'>" => "Closing tags don't support attributes."
 second_close: "<!---->-->" => "Tried to close unopened comment."
-doctype_2attr: "<!dx a b>" => "Doctype expected at most one attribute."
+doctype_2attr: "<!doctype a b>" => "Doctype expected at most one attribute."
 
 );
+
+#[test]
+fn stray_open() {
+    for html in ["a < b", "a <", "1 <2", "< id='a'>"] {
+        assert!(Html::parse(html).unwrap() == Html::text(html), "{html}");
+    }
+}
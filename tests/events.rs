@@ -0,0 +1,73 @@
+use html_filter::prelude::*;
+
+fn start(name: &str) -> Event {
+    Event::Start(Tag::builder(name).build())
+}
+
+fn end(name: &str) -> Event {
+    Event::End(name.to_owned())
+}
+
+fn text(content: &str) -> Event {
+    Event::Text(content.to_owned())
+}
+
+#[test]
+fn small_document() {
+    let html = Html::parse(
+        "<!DOCTYPE html><html><body><!-- nav --><ul class=\"menu\"><li>One</li><li>Two<br></li></ul></body></html>",
+    )
+    .unwrap();
+    let expected = [
+        Html::parse("<!DOCTYPE html>").unwrap().events()[0].clone(),
+        start("html"),
+        start("body"),
+        Event::Comment(" nav ".to_owned()),
+        Event::Start(Tag::builder("ul").class("menu").build()),
+        start("li"),
+        text("One"),
+        end("li"),
+        start("li"),
+        text("Two"),
+        start("br"),
+        end("br"),
+        end("li"),
+        end("ul"),
+        end("body"),
+        end("html"),
+    ];
+    assert!(html.events() == expected);
+    assert!(
+        matches!(&expected[0], Event::Doctype { name, attr: Some(attr), .. } if name == "DOCTYPE" && attr == "html")
+    );
+}
+
+#[test]
+fn whitespace_independent() {
+    let compact = Html::parse("<p><b>a</b></p>").unwrap();
+    let mut indented = Html::parse("<p>\n  <b>a</b>\n</p>").unwrap();
+    assert!(compact.events() != indented.events());
+    indented.trim_whitespace_nodes();
+    assert!(compact.events() == indented.events());
+}
+
+#[test]
+fn balanced() {
+    let html = Html::parse("<div><p>a<p>b<img src='x'/></div>").unwrap();
+    let events = html.events();
+    let starts = events
+        .iter()
+        .filter(|event| matches!(event, Event::Start(_)))
+        .count();
+    let ends = events
+        .iter()
+        .filter(|event| matches!(event, Event::End(_)))
+        .count();
+    assert!(starts == 4 && ends == 4);
+}
+
+#[test]
+fn empty() {
+    assert!(Html::Empty.events().is_empty());
+    assert!(Html::text("a").events() == [text("a")]);
+}
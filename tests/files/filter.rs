@@ -23,7 +23,7 @@ macro_rules! test_filter {
 test_filter!(
 
 doctype: Filter::new().no_tags().none_except_doctype() =>
-"<!><!DOCTYPE ><!DOCTYPE html>"
+"<!><!DOCTYPE><!DOCTYPE html>"
 
 prefix: Filter::new().attribute_value("xlink:href", "#").none_except_text() =>
 r##"<a xlink:href="#">About</a>"##
@@ -217,7 +217,7 @@ r##"
 no_script_style: Filter::new().except_tag_name("script").except_tag_name("style") =>
 r##"
 <!>
-<!DOCTYPE >
+<!DOCTYPE>
 <!DOCTYPE html>
 <html lang="en">
     <head>
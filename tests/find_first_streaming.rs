@@ -0,0 +1,51 @@
+use html_filter::prelude::*;
+
+fn large_page() -> String {
+    let mut page = String::from("<html><head><title>Huge &amp; slow</title></head><body>");
+    page.push_str(&"<div class='row'><p>Lorem ipsum<br>dolor</p></div>".repeat(50_000));
+    page.push_str("<p id='last'>end</p></body></html>");
+    page
+}
+
+#[test]
+fn title_of_large_page() {
+    let mut page = large_page();
+    // The rest of the page is never read, so it can be invalid.
+    page.push_str("<a href='unterminated");
+    assert!(Html::parse(&page).is_err());
+    let title = Html::find_first_streaming(&page, "head > title").unwrap();
+    assert!(title == Some(Html::parse("<title>Huge &amp; slow</title>").unwrap()));
+}
+
+#[test]
+fn same_as_select_one() {
+    let page = large_page();
+    let tree = Html::parse(&page).unwrap();
+    for selector in ["#last", "div.row > p", "body br", "p, title"] {
+        let streamed = Html::find_first_streaming(&page, selector).unwrap();
+        assert!(streamed.as_ref() == tree.select_one(selector).unwrap(), "{selector}");
+    }
+    assert!(Html::find_first_streaming(&page, "span").unwrap().is_none());
+    assert!(Html::find_first_streaming(&page, "p >").is_err());
+}
+
+#[test]
+fn raw_text_and_foreign() {
+    let page = "<script>let s = '<p>';</script><svg><path/><rect/></svg><p class=\"a&amp;b\">x</p>";
+    let rect = Html::find_first_streaming(page, "svg > rect")
+        .unwrap()
+        .unwrap();
+    assert!(rect.to_string() == "<rect></rect>");
+    assert!(rect.namespace() == Some(Namespace::Svg));
+    assert!(
+        Html::find_first_streaming(page, "p")
+            .unwrap()
+            .unwrap()
+            .to_string()
+            .ends_with(">x</p>")
+    );
+    let para = Html::find_first_streaming(page, "[class='a&b']")
+        .unwrap()
+        .unwrap();
+    assert!(para.to_string() == "<p class=\"a&amp;b\">x</p>");
+}
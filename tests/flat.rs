@@ -0,0 +1,42 @@
+use std::fs::read_to_string;
+
+use html_filter::prelude::*;
+
+#[test]
+fn display() {
+    let content = read_to_string("tests/data/index.html").unwrap();
+    let tree = Html::parse(&content).unwrap();
+    let flat = tree.to_flat();
+    assert!(flat.to_string() == tree.to_string());
+}
+
+#[test]
+fn empty() {
+    let flat = Html::new().to_flat();
+    assert!(flat.is_empty());
+    assert!(flat.roots().next().is_none());
+    assert!(flat.to_string().is_empty());
+}
+
+#[test]
+fn links() {
+    let tree = Html::parse("<!doctype html><div>a<br>b<p>c</p></div>d").unwrap();
+    let flat = tree.to_flat();
+    assert!(flat.len() == 8);
+    assert!(flat.roots().map(NodeId::index).collect::<Vec<_>>() == [0, 1, 7]);
+
+    let div = flat.roots().nth(1).unwrap();
+    assert!(flat.children(div).map(NodeId::index).collect::<Vec<_>>() == [2, 3, 4, 5]);
+    let br = flat.get(flat.children(div).nth(1).unwrap()).unwrap();
+    assert!(br.first_child().is_none());
+    assert!(br.next_sibling().is_some_and(|id| id.index() == 4));
+    assert!(br.parent() == Some(div));
+
+    let text = flat.iter().find_map(|(id, node)| match node.as_data() {
+        NodeData::Text(text) if text == "c" => Some(id),
+        _ => None,
+    });
+    assert!(text.is_some_and(|id| id.index() == 6));
+    assert!(flat.get(text.unwrap()).unwrap().parent().unwrap().index() == 5);
+    assert!(flat.to_string() == tree.to_string());
+}
@@ -0,0 +1,42 @@
+use html_filter::prelude::*;
+
+#[test]
+fn empty_root() {
+    let html = Html::parse("").unwrap();
+    assert!(html.is_empty());
+    assert!(html.as_fragment().is_empty());
+    assert!(html.into_fragment().is_empty());
+}
+
+#[test]
+fn single_root() {
+    let html = Html::parse("<div><p>a</p><p>b</p></div>").unwrap();
+    let nodes = html.as_fragment();
+    assert!(nodes.len() == 1);
+    assert!(std::ptr::eq(&nodes[0], &html));
+    let owned = html.clone().into_fragment();
+    assert!(owned == [html]);
+}
+
+#[test]
+fn vec_root() {
+    let html = Html::parse("<!DOCTYPE html><p>a</p>text<!-- b -->").unwrap();
+    assert!(matches!(html, Html::Vec(_)));
+    let displayed = html
+        .as_fragment()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert!(displayed == ["<!DOCTYPE html>", "<p>a</p>", "text", "<!-- b -->"]);
+    let owned = html.clone().into_fragment();
+    assert!(owned == html.as_fragment());
+}
+
+#[test]
+fn same_as_parse_nodes() {
+    for input in ["", "a", "<p>a</p><p>b</p>", "<br>text"] {
+        let nodes = Html::parse_nodes(input).unwrap();
+        assert!(Html::parse(input).unwrap().as_fragment() == nodes);
+        assert!(Html::parse(input).unwrap().into_fragment() == nodes);
+    }
+}
@@ -0,0 +1,95 @@
+use html_filter::prelude::*;
+
+from_html! {
+    #[derive(Debug, PartialEq)]
+    pub struct Product {
+        #[html(selector = ".name")]
+        pub name: String,
+        #[html(selector = ".price", attr = "data-value")]
+        pub price: f64,
+        #[html(selector = ".price")]
+        price_label: String,
+        #[html(selector = ".discount", attr = "data-percent")]
+        discount: Option<u8>,
+        #[html(selector = "ul.tags > li")]
+        /// Tags of the product
+        tags: Vec<String>,
+    }
+}
+
+const PAGE: &str = r#"
+<article class="product">
+    <h2 class="name">
+        Desk lamp
+    </h2>
+    <span class="price" data-value="24.99">24,99 €</span>
+    <ul class="tags"><li>home</li><li>light</li></ul>
+</article>
+"#;
+
+#[test]
+fn product() {
+    let product = Product::from_html(&Html::parse(PAGE).unwrap()).unwrap();
+    assert!(
+        product
+            == Product {
+                name: "Desk lamp".to_owned(),
+                price: 24.99,
+                price_label: "24,99 €".to_owned(),
+                discount: None,
+                tags: vec!["home".to_owned(), "light".to_owned()],
+            }
+    );
+}
+
+#[test]
+fn optional_field() {
+    let html = Html::parse(
+        &PAGE.replace("<ul", r#"<span class="discount" data-percent="10">-10%</span><ul"#),
+    )
+    .unwrap();
+    assert!(Product::from_html(&html).unwrap().discount == Some(10));
+}
+
+#[test]
+fn missing_field() {
+    let html = Html::parse(&PAGE.replace(r#" data-value="24.99""#, "")).unwrap();
+    let err = Product::from_html(&html).unwrap_err();
+    assert!(matches!(&err, ExtractError::Missing { selector, .. } if selector == ".price"));
+    assert!(err.to_string() == "No value found for selector '.price'.");
+}
+
+#[test]
+fn invalid_value() {
+    let html = Html::parse(&PAGE.replace("24.99", "cheap")).unwrap();
+    let err = Product::from_html(&html).unwrap_err();
+    assert!(matches!(&err, ExtractError::InvalidValue { value, .. } if value == "cheap"));
+}
+
+from_html! {
+    #[derive(Debug)]
+    struct Invalid {
+        #[html(selector = "a +")]
+        _link: Option<String>,
+    }
+}
+
+#[test]
+fn invalid_selector() {
+    let err = Invalid::from_html(&Html::parse("<a></a>").unwrap()).unwrap_err();
+    assert!(matches!(err, ExtractError::InvalidSelector(_)));
+}
+
+#[test]
+fn extract_without_macro() {
+    let html = Html::parse("<p><b>1</b><b>x</b><b>3</b></p>").unwrap();
+    assert!(html.extract::<u8>("b", None).unwrap() == 1);
+    assert!(html.extract::<Vec<String>>("b", None).unwrap() == ["1", "x", "3"]);
+    assert!(html.extract::<Vec<u8>>("b", None).is_err());
+    assert!(html.extract::<Vec<u8>>("i", None).unwrap().is_empty());
+    assert!(
+        html.extract::<Option<bool>>("b", Some("id"))
+            .unwrap()
+            .is_none()
+    );
+}
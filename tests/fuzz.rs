@@ -0,0 +1,157 @@
+//! Feeds pseudo-random inputs to the parser, to check that it never panics.
+
+use html_filter::prelude::*;
+
+/// Pieces of HTML from which the inputs are built
+const PIECES: [&str; 48] = [
+    "<",
+    ">",
+    "/",
+    "</",
+    "/>",
+    "<!",
+    "<!--",
+    "-->",
+    "--",
+    "=",
+    "'",
+    "\"",
+    " ",
+    "\n",
+    "a",
+    "div",
+    "p",
+    "br",
+    "td",
+    "tr",
+    "table",
+    "b",
+    "i",
+    "script",
+    "style",
+    "svg",
+    "math",
+    "textarea",
+    "id",
+    "class",
+    "<![endif]-->",
+    "<!--[if IE]>",
+    "<!doctype",
+    "html",
+    "é",
+    "\u{feff}",
+    "\0",
+    "&amp;",
+    "<?",
+    "]]>",
+    "<![CDATA[",
+    "x=",
+    "<tbody>",
+    "</>",
+    "<a ",
+    "<p>",
+    "</p>",
+    "\t",
+];
+
+/// Minimal xorshift generator, to get reproducible inputs without
+/// dependencies
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        usize::try_from(self.0 % (1 << 31)).unwrap()
+    }
+
+    fn chars(&mut self) -> String {
+        const CHARS: [char; 16] = [
+            '<', '>', '/', '!', '-', '=', '\'', '"', ' ', 'a', 'b', '[', ']', '?', 'é', '\n',
+        ];
+        let len = self.next() % 60;
+        (0..len).map(|_| CHARS[self.next() % CHARS.len()]).collect()
+    }
+
+    fn input(&mut self) -> String {
+        let len = self.next() % 40;
+        (0..len)
+            .map(|_| PIECES[self.next() % PIECES.len()])
+            .collect()
+    }
+}
+
+fn all_options() -> [ParseOptions; 3] {
+    [
+        ParseOptions::new(),
+        ParseOptions::new().lenient(true),
+        ParseOptions::new()
+            .lenient(true)
+            .adoption_agency(true)
+            .lowercase_names(true)
+            .normalize_tables(true)
+            .parse_conditional_comments(true)
+            .preserve_source(true)
+            .xml_self_closing(true),
+    ]
+}
+
+fn check(input: &str) {
+    for options in all_options() {
+        let Ok((tree, _)) = Html::parse_with_warnings(input, &options) else {
+            continue;
+        };
+        let displayed = tree.to_string();
+        let _xhtml = tree.display_xhtml().to_string();
+        let _stats = tree.stats();
+        let _flat = FlatHtml::from(&tree);
+        let _json = Html::from_json(&tree.to_json());
+        let _selected = tree.select("div > p a, [id]");
+        let _filtered = tree.to_filtered(&Filter::new().tag_name("p"));
+        if let Ok((reparsed, _)) = Html::parse_with_warnings(&displayed, &options) {
+            let mut patched = tree.clone();
+            patched.apply(&Html::diff(&tree, &reparsed)).unwrap();
+            assert!(patched == reparsed, "{input:?}");
+        }
+    }
+    // The displayed tree must be parsed into the same tree. The only exceptions
+    // are texts joined around an ignored `</>`, like `--` and `>` that create a
+    // `-->`, or a stray `<` and `p>` that create a tag.
+    if let Ok(tree) = Html::parse(input) {
+        let displayed = tree.to_string();
+        let joined = Html::parse_with_warnings(input, &ParseOptions::new())
+            .unwrap()
+            .1
+            .iter()
+            .any(|warning| matches!(warning, ParseWarning::EmptyClosingTag { .. }));
+        match Html::parse(&displayed) {
+            Ok(reparsed) => assert!(reparsed.to_string() == displayed || joined, "{input:?}"),
+            Err(ParseError::UnopenedComment) => (),
+            Err(err) => assert!(joined, "{input:?} => {err}"),
+        }
+    }
+    let _tokens = Tokenizer::new(input).collect::<Vec<_>>();
+    let _bytes = Html::parse_bytes(input.as_bytes());
+}
+
+#[test]
+fn random_inputs() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..20_000 {
+        check(&rng.input());
+        check(&rng.chars());
+    }
+}
+
+#[test]
+fn deep_nesting() {
+    let options = ParseOptions::new().max_depth(512);
+    for html in ["<b>", "<div><span>", "<table><tr><td>", "<svg><g>"] {
+        let err = Html::parse_with_warnings(&html.repeat(100_000), &options).unwrap_err();
+        assert!(err.to_string() == "Limit exceeded: more than 512 levels of nesting.");
+    }
+    let options = ParseOptions::new().lenient(true).max_depth(1000);
+    let (tree, _) = Html::parse_with_warnings(&"<b>".repeat(1000), &options).unwrap();
+    assert!(tree.stats().max_depth() == 1000);
+}
@@ -0,0 +1,77 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher as _, RandomState};
+
+use html_filter::prelude::*;
+
+#[test]
+fn reordered_attributes() {
+    let state = RandomState::new();
+    let first = Html::parse(r#"<div a="1" b="2">x</div>"#).unwrap();
+    let second = Html::parse(r#"<div b="2" a="1">x</div>"#).unwrap();
+    assert!(state.hash_one(&first) == state.hash_one(&second));
+    let third = Html::parse(r#"<div a="1" b="3">x</div>"#).unwrap();
+    assert!(state.hash_one(&first) != state.hash_one(&third));
+}
+
+#[test]
+fn normalised_attributes() {
+    let state = RandomState::new();
+    let tag = |html: &str| match Html::parse(html).unwrap() {
+        Html::Tag { tag, .. } => tag,
+        _ => unreachable!(),
+    };
+    let reference = state.hash_one(tag(r#"<input type="text" id="a">"#));
+    assert!(state.hash_one(tag(r#"<INPUT ID='a' Type="text">"#)) == reference);
+    assert!(state.hash_one(tag(r#"<input id="a" type="text" id="b">"#)) == reference);
+    assert!(state.hash_one(tag(r#"<input type="text" id="b">"#)) != reference);
+    assert!(state.hash_one(tag(r#"<input type="text" id>"#)) != reference);
+
+    let svg = |html: &str| match Html::parse(html).unwrap() {
+        Html::Tag { child, .. } => *child,
+        _ => unreachable!(),
+    };
+    let reference = svg(r#"<svg><foreignObject viewBox="0"></foreignObject></svg>"#);
+    let lowercase = svg(r#"<svg><foreignobject viewbox="0"></foreignobject></svg>"#);
+    assert!(state.hash_one(&lowercase) != state.hash_one(&reference));
+    let attr = svg(r#"<svg><foreignObject viewbox="0"></foreignObject></svg>"#);
+    assert!(state.hash_one(&attr) != state.hash_one(&reference));
+}
+
+#[test]
+fn consistent_with_eq() {
+    let state = RandomState::new();
+    let options = ParseOptions::new().preserve_source(true);
+    let html = "<!DOCTYPE html><p class='a'>b<!--c--><br/></p>";
+    let preserved = Html::parse_with_warnings(html, &options).unwrap().0;
+    let plain = Html::parse(html).unwrap();
+    assert!(preserved == plain);
+    assert!(state.hash_one(&preserved) == state.hash_one(&plain));
+}
+
+#[test]
+fn memoization() {
+    let mut cache = HashMap::new();
+    for html in ["<p>a</p>", "<p>b</p>", "<p>a</p>"] {
+        let tree = Html::parse(html).unwrap();
+        *cache.entry(tree).or_insert(0) += 1;
+    }
+    assert!(cache.len() == 2);
+    assert!(cache[&Html::parse("<p>a</p>").unwrap()] == 2);
+    let set = ["a", "b"]
+        .map(Html::text)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert!(set.contains(&Html::text("a")));
+}
+
+#[test]
+fn reordered_lookup() {
+    let mut cache = HashMap::new();
+    cache.insert(Html::parse(r#"<div a="1" b="2">x</div>"#).unwrap(), 1);
+    let reordered = Html::parse(r#"<div b="2" a="1">x</div>"#).unwrap();
+    assert!(cache.get(&reordered) == Some(&1));
+    let normalised = Html::parse(r#"<DIV B='2' A="1" a="3">x</DIV>"#).unwrap();
+    assert!(cache.get(&normalised) == Some(&1));
+    let different = Html::parse(r#"<div a="1" b="3">x</div>"#).unwrap();
+    assert!(!cache.contains_key(&different));
+}
@@ -0,0 +1,88 @@
+use html_filter::prelude::*;
+
+fn implicit(node: &Html) -> bool {
+    match node {
+        Html::Tag { tag, .. } => tag.is_implicitly_closed(),
+        _ => panic!("expected a tag, found {node}"),
+    }
+}
+
+fn children(node: &Html) -> Vec<&Html> {
+    node.children().collect()
+}
+
+fn parse_paragraphs(html: &str) -> Html {
+    let options = ParseOptions::new().close_paragraphs(true);
+    Html::parse_with_warnings(html, &options).unwrap().0
+}
+
+#[test]
+fn paragraphs() {
+    let html = parse_paragraphs("<p>a<p>b");
+    assert!(html.to_string() == "<p>a</p><p>b</p>");
+    let nodes = children(&html);
+    assert!(nodes.len() == 2);
+    assert!(implicit(nodes[0]));
+    assert!(implicit(nodes[1]));
+    assert!(Html::parse("<p>a<p>b").unwrap().to_string() == "<p>a<p>b</p></p>");
+
+    let html = parse_paragraphs("<p>a</p><p>b</p>");
+    assert!(children(&html).into_iter().all(|node| !implicit(node)));
+
+    let html = parse_paragraphs("<p>a<p>b</p>");
+    let nodes = children(&html);
+    assert!(implicit(nodes[0]));
+    assert!(!implicit(nodes[1]));
+}
+
+#[test]
+fn paragraph_with_children() {
+    let html = parse_paragraphs("<p>a<b>b<p>c");
+    assert!(html.to_string() == "<p>a<b>b</b></p><p>c</p>");
+    let first = children(&html)[0];
+    assert!(implicit(first));
+    assert!(implicit(children(first)[1]));
+}
+
+#[test]
+fn paragraph_in_button() {
+    let html = parse_paragraphs("<p>a<button><p>b</p></button></p>");
+    assert!(html.to_string() == "<p>a<button><p>b</p></button></p>");
+    assert!(!implicit(&html));
+}
+
+#[test]
+fn closed_with_parent() {
+    let html = Html::parse("<div><b>bold</div>").unwrap();
+    assert!(!implicit(&html));
+    assert!(implicit(children(&html)[0]));
+
+    let html = Html::parse("<div><i>a</i>").unwrap();
+    assert!(implicit(&html));
+    assert!(!implicit(children(&html)[0]));
+}
+
+#[test]
+fn table_cells() {
+    let html = Html::parse("<tr><td>a<td>b</td></tr>").unwrap();
+    let cells = children(&html);
+    assert!(implicit(cells[0]));
+    assert!(!implicit(cells[1]));
+}
+
+#[test]
+fn self_closing_and_built() {
+    let html = Html::parse("<br><div/>").unwrap();
+    let nodes = children(&html);
+    assert!(!implicit(nodes[0]));
+    assert!(implicit(nodes[1]));
+
+    let tag = Tag::builder("p").build();
+    assert!(!tag.is_implicitly_closed());
+}
+
+#[test]
+fn display_unchanged() {
+    let html = Html::parse("<div><span>a</div>").unwrap();
+    assert!(html.to_string() == "<div><span>a</span></div>");
+}
@@ -0,0 +1,27 @@
+use html_filter::prelude::*;
+
+#[test]
+fn vec_root() {
+    let tree = Html::parse("<p>a</p>text<!-- c --><br>").unwrap();
+    let mut names = vec![];
+    for node in &tree {
+        names.push(node.to_string());
+    }
+    assert!(names == ["<p>a</p>", "text", "<!-- c -->", "<br>"]);
+    let owned = tree.clone().into_iter().collect::<Vec<_>>();
+    assert!(owned.iter().collect::<Vec<_>>() == (&tree).into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn single_node_root() {
+    let tree = Html::parse("<ul><li>a</li><li>b</li></ul>").unwrap();
+    let nodes = (&tree).into_iter().collect::<Vec<_>>();
+    assert!(nodes == [&tree]);
+    assert!(tree.clone().into_iter().eq([tree]));
+}
+
+#[test]
+fn empty_root() {
+    assert!((&Html::Empty).into_iter().next().is_none());
+    assert!(Html::Empty.into_iter().next().is_none());
+}
@@ -0,0 +1,100 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<!DOCTYPE html>
+<!-- "quoted" comment -->
+<html lang="en">
+    <body>
+        <p class='x' hidden>Tab	and "quotes" \ é</p>
+        <svg viewBox="0 0 1 1"><rect></rect></svg>
+    </body>
+</html>"#;
+
+#[test]
+fn roundtrip() {
+    let tree = Html::parse(HTML).unwrap();
+    let json = tree.to_json();
+    let back = Html::from_json(&json).unwrap();
+    assert!(back.to_json() == json);
+    assert!(back.to_string() == HTML.replace("class='x'", "class=\"x\""));
+}
+
+#[test]
+fn shape() {
+    let tree = Html::parse("<!doctype html><a href=\"/\">\n<!--c--></a>").unwrap();
+    assert!(
+        tree.to_json()
+            == r#"[{"doctype":"doctype","attr":"html"},{"tag":"a","attrs":{"href":"/"},"children":["\n",{"comment":"c"}]}]"#
+    );
+    assert!(Html::new().to_json() == "[]");
+    assert!(matches!(Html::from_json(" [ ] ").unwrap(), Html::Empty));
+}
+
+#[test]
+fn escapes() {
+    let html = Html::from_json(r#""\u00e9\ud83d\ude00\n\/""#).unwrap();
+    assert!(html.to_string() == "é😀\n/");
+    assert!(Html::parse("\u{1}").unwrap().to_json() == r#""\u0001""#);
+}
+
+#[test]
+fn namespace() {
+    let html = Html::from_json(
+        r#"{"tag":"svg","attrs":{},"children":[{"tag":"g","attrs":{},"children":[]}]}"#,
+    )
+    .unwrap();
+    if let Html::Tag { tag, .. } = html.find(&Filter::new().tag_name("g")) {
+        assert!(tag.as_namespace() == Namespace::Svg);
+    } else {
+        panic!("g not found");
+    }
+}
+
+#[test]
+fn errors() {
+    for json in [
+        "",
+        "[",
+        "[1]",
+        "{\"tag\":\"a\"",
+        "{\"tag\":\"a\",\"other\":null}",
+        "{}",
+        "null",
+        "nul",
+        "\"\\x\"",
+        "\"\\ud83d\"",
+        "\"abc",
+        "[] []",
+        "{\"tag\":\"a\",\"attrs\":{\"id\":[]}}",
+        "{\"tag\" \"a\"}",
+        "{3:4}",
+        "\"\\u12\"",
+    ] {
+        assert!(Html::from_json(json).is_err(), "{json} should fail");
+    }
+    assert!(matches!(
+        Html::from_json("{\"tag\":\"a\",\"other\":null}"),
+        Err(JsonError::UnexpectedKey { key, .. }) if key == "other"
+    ));
+    assert!(
+        Html::from_json("[1]").unwrap_err().to_string()
+            == "Invalid JSON: unsupported value starting with '1'."
+    );
+    assert!(
+        Html::from_json("{}").unwrap_err().to_string()
+            == "Invalid HTML JSON: object must have a 'tag', 'comment', 'bogus_comment', \
+                'conditional' or 'doctype' key."
+    );
+}
+
+#[test]
+fn deep_nesting() {
+    for json in [
+        "[".repeat(100_000),
+        "{\"tag\":\"b\",\"children\":[".repeat(100_000),
+    ] {
+        let err = Html::from_json(&json).unwrap_err();
+        assert!(matches!(err, JsonError::TooDeep { max: 512, .. }), "{err}");
+    }
+    let html = Html::parse(&"<b>".repeat(256)).unwrap();
+    assert!(Html::from_json(&html.to_json()).unwrap() == html);
+}
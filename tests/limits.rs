@@ -0,0 +1,61 @@
+use html_filter::prelude::*;
+
+fn check(html: &str, options: &ParseOptions, expected: Option<&str>) {
+    match (Html::parse_with_warnings(html, options), expected) {
+        (Ok(_), None) => (),
+        (Err(err), Some(message)) => {
+            assert!(matches!(err, ParseError::LimitExceeded { .. }));
+            assert!(err.to_string() == message, "{err}");
+        }
+        (res, _) => panic!("{html:?} gave {res:?}"),
+    }
+}
+
+#[test]
+fn attributes_per_tag() {
+    let options = ParseOptions::new().max_attributes_per_tag(2);
+    check("<p a b></p>", &options, None);
+    check(
+        "<p a b c></p>",
+        &options,
+        Some("Limit exceeded: more than 2 attributes in a tag."),
+    );
+    check(
+        "<p a b><br a b c></p>",
+        &options.lenient(true),
+        Some("Limit exceeded: more than 2 attributes in a tag."),
+    );
+}
+
+#[test]
+fn attribute_value_len() {
+    let options = ParseOptions::new().max_attribute_value_len(3);
+    check("<p id='abc' hidden></p>", &options, None);
+    check(
+        "<p hidden id='abcd'></p>",
+        &options,
+        Some("Limit exceeded: more than 3 bytes in an attribute value."),
+    );
+    check(
+        "<p id='é'></p>",
+        &ParseOptions::new().max_attribute_value_len(1),
+        Some("Limit exceeded: more than 1 bytes in an attribute value."),
+    );
+}
+
+#[test]
+fn total_nodes() {
+    let html = "<!DOCTYPE html><div><!-- c -->text<br></div>";
+    check(html, &ParseOptions::new().max_total_nodes(5), None);
+    check(
+        html,
+        &ParseOptions::new().max_total_nodes(4),
+        Some("Limit exceeded: more than 4 nodes."),
+    );
+}
+
+#[test]
+fn unlimited_by_default() {
+    let html = format!("<p {}>{}</p>", "a ".repeat(1000), "<b></b>".repeat(1000));
+    check(&html, &ParseOptions::new(), None);
+}
@@ -0,0 +1,103 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<main class="page">
+    <nav class=" menu  active "><a class="link" href="/">Home</a></nav>
+    <section data-id="1" class="activeness"><p data-hidden>Hidden</p></section>
+    <section class="active"><div class="active"></div></section>
+    <p>No attributes</p>
+</main>"#;
+
+fn has_class(class: &'static str) -> impl Fn(&str, Option<&str>) -> bool {
+    move |_, value| value.is_some_and(|classes| classes.split_whitespace().any(|x| x == class))
+}
+
+fn names<'html>(nodes: &[&'html Html]) -> Vec<&'html str> {
+    nodes
+        .iter()
+        .filter_map(|node| match node {
+            Html::Tag { tag, .. } => Some(tag.as_name().as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn class_membership() {
+    let tree = Html::parse(HTML).unwrap();
+    assert!(names(&tree.find_by_attr("class", has_class("active"))) == ["nav", "section", "div"]);
+    assert!(names(&tree.find_by_attr("class", has_class("page"))) == ["main"]);
+    assert!(tree.find_by_attr("class", has_class("missing")).is_empty());
+}
+
+#[test]
+fn data_presence() {
+    let tree = Html::parse(HTML).unwrap();
+    let data = tree.find_by_attr("data-id", |_, _| true);
+    assert!(names(&data) == ["section"]);
+    let hidden =
+        tree.find_by_attr("data-hidden", |name, value| name == "data-hidden" && value.is_none());
+    assert!(hidden.len() == 1 && hidden[0].to_string() == "<p data-hidden>Hidden</p>");
+    assert!(
+        tree.find_by_attr("data-id", |_, value| value == Some("2"))
+            .is_empty()
+    );
+}
+
+#[test]
+fn attr_name_case() {
+    let tree = Html::parse("<p CLASS='x'></p><svg viewBox='0'></svg>").unwrap();
+    assert!(names(&tree.find_by_attr("class", |_, _| true)) == ["p"]);
+    assert!(names(&tree.find_by_attr("viewBox", |_, _| true)) == ["svg"]);
+    assert!(tree.find_by_attr("viewbox", |_, _| true).is_empty());
+}
+
+#[test]
+fn tag_names() {
+    let tree = Html::parse(HTML).unwrap();
+    assert!(
+        tree.tag_names().collect::<Vec<_>>()
+            == ["main", "nav", "a", "section", "p", "section", "div", "p"]
+    );
+    let counts = tree
+        .tag_names()
+        .fold(std::collections::BTreeMap::new(), |mut counts, name| {
+            *counts.entry(name).or_insert(0) += 1;
+            counts
+        });
+    assert!(counts["p"] == 2 && counts["section"] == 2 && counts["a"] == 1);
+    assert!(
+        Html::parse("text<!-- c --><!doctype html>")
+            .unwrap()
+            .tag_names()
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+fn contains_tag() {
+    let tree = Html::parse(HTML).unwrap();
+    for name in ["main", "nav", "a", "DIV", "p"] {
+        assert!(tree.contains_tag(name), "{name}");
+    }
+    for name in ["form", "span", "li", ""] {
+        assert!(!tree.contains_tag(name), "{name}");
+    }
+    let svg = Html::parse("<svg><linearGradient /></svg>").unwrap();
+    assert!(svg.contains_tag("linearGradient"));
+    assert!(!svg.contains_tag("lineargradient"));
+}
+
+#[test]
+fn contains_text() {
+    let tree = Html::parse(HTML).unwrap();
+    assert!(tree.contains_text("Home"));
+    assert!(tree.contains_text("No attr"));
+    assert!(!tree.contains_text("Nowhere"));
+    assert!(!tree.contains_text("active"));
+    assert!(
+        !Html::parse("<!-- Home --><p>x</p>")
+            .unwrap()
+            .contains_text("Home")
+    );
+}
@@ -0,0 +1,58 @@
+use html_filter::prelude::*;
+
+fn parse(html: &str, lowercase: bool) -> Html {
+    let options = ParseOptions::new().lowercase_names(lowercase);
+    Html::parse_with_warnings(html, &options).unwrap().0
+}
+
+fn names(html: &Html) -> (String, Vec<String>) {
+    let Html::Tag { tag, .. } = html else {
+        panic!("expected a tag")
+    };
+    (
+        tag.as_name().to_owned(),
+        tag.as_attrs()
+            .iter()
+            .map(|attr| attr.as_name().to_owned())
+            .collect(),
+    )
+}
+
+#[test]
+fn enabled() {
+    let html = parse(r#"<DIV CLASS="x" Hidden>Text</DIV>"#, true);
+    assert!(names(&html) == ("div".to_owned(), vec!["class".to_owned(), "hidden".to_owned()]));
+    assert!(html.to_string() == r#"<div class="x" hidden>Text</div>"#);
+}
+
+#[test]
+fn disabled() {
+    let html = parse(r#"<DIV CLASS="x" Hidden>Text</DIV>"#, false);
+    assert!(names(&html) == ("DIV".to_owned(), vec!["CLASS".to_owned(), "Hidden".to_owned()]));
+    assert!(html.to_string() == r#"<DIV CLASS="x" Hidden>Text</DIV>"#);
+}
+
+#[test]
+fn mixed_case_closing_tags() {
+    let html = parse("<Ul><LI>a</li><li>b</LI></uL>", true);
+    assert!(html.to_string() == "<ul><li>a</li><li>b</li></ul>");
+}
+
+#[test]
+fn foreign_content_is_kept() {
+    let html = parse(
+        "<SECTION><svg viewBox='0 0 1 1'><linearGradient gradientUnits='x'></linearGradient></svg></SECTION>",
+        true,
+    );
+    assert!(
+        html.to_string()
+            == "<section><svg viewBox='0 0 1 1'><linearGradient gradientUnits='x'></linearGradient></svg></section>"
+    );
+}
+
+#[test]
+fn lookups() {
+    let html = parse("<P CLASS='a'>x</P>", true);
+    assert!(html.has_class("a"));
+    assert!(html.find_by_attr("class", |_, _| true).len() == 1);
+}
@@ -0,0 +1,75 @@
+use html_filter::prelude::*;
+
+fn tag(html: &str) -> Html {
+    Html::parse(html).unwrap()
+}
+
+#[test]
+fn matching() {
+    let input = tag(r#"<input id="email" class="field wide" type="text" required>"#);
+    for selector in [
+        "input",
+        "INPUT",
+        "*",
+        "#email",
+        ".field",
+        ".wide.field",
+        "[required]",
+        "[type=\"text\"]",
+        "input#email.field[type='text'][required]",
+        "p, input",
+        "  .field  ",
+    ] {
+        assert!(input.matches(selector).unwrap(), "{selector}");
+    }
+}
+
+#[test]
+fn not_matching() {
+    let input = tag(r#"<input id="email" class="field" type="text">"#);
+    for selector in [
+        "p",
+        "#name",
+        ".wide",
+        ".field.wide",
+        "[required]",
+        "[type='password']",
+        "input#email.other",
+        "h1, h2",
+    ] {
+        assert!(!input.matches(selector).unwrap(), "{selector}");
+    }
+}
+
+#[test]
+fn only_the_node_itself() {
+    let html = tag("<ul class='menu'><li class='item'>A</li></ul>");
+    assert!(html.matches("ul").unwrap());
+    assert!(!html.matches(".item").unwrap());
+    for node in html.select("li").unwrap() {
+        assert!(node.matches("li.item").unwrap());
+    }
+}
+
+#[test]
+fn non_tags() {
+    assert!(!Html::text("a").matches("*").unwrap());
+    assert!(!Html::Empty.matches("*").unwrap());
+    assert!(!tag("<p></p><p></p>").matches("p").unwrap());
+}
+
+#[test]
+fn invalid_selectors() {
+    let html = tag("<li></li>");
+    assert!(
+        html.matches("ul li").unwrap_err().to_string()
+            == "Invalid character 'l' at position 3 in selector."
+    );
+    assert!(
+        html.matches("ul>li").unwrap_err().to_string()
+            == "Invalid character '>' at position 2 in selector."
+    );
+    assert!(html.matches("li,").is_err());
+    assert!(html.matches("a[href").unwrap_err().to_string() == "Unexpected end of selector.");
+    assert!(html.matches("").is_err() == Selector::parse("").is_err());
+}
@@ -0,0 +1,74 @@
+use html_filter::prelude::*;
+
+const MISNESTED: &str = "<div><span>a</div>b";
+
+fn parse(policy: MismatchPolicy) -> Result<(Html, Vec<ParseWarning>), ParseError> {
+    Html::parse_with_warnings(MISNESTED, &ParseOptions::new().on_mismatch(policy))
+}
+
+#[test]
+fn close_ancestor() {
+    let (tree, warnings) = parse(MismatchPolicy::CloseAncestor).unwrap();
+    assert!(tree.to_string() == "<div><span>a</span></div>b");
+    if let [ParseWarning::MisnestedClosingTag { name, open, position, .. }] = warnings.as_slice() {
+        assert!(name == "div" && open == "span");
+        assert!(position.offset() == 12);
+    } else {
+        panic!("Expected one warning, found {warnings:?}");
+    }
+    assert!(
+        warnings[0].to_string()
+            == "Misnested closing tag for 'div' at line 1, column 13: 'span' is still open."
+    );
+    assert!(parse(MismatchPolicy::default()) == parse(MismatchPolicy::CloseAncestor));
+}
+
+#[test]
+fn ignore() {
+    let (tree, warnings) = parse(MismatchPolicy::Ignore).unwrap();
+    assert!(tree.to_string() == "<div><span>ab</span></div>");
+    assert!(matches!(
+        warnings.as_slice(),
+        [ParseWarning::MisnestedClosingTag { name, open, .. }] if name == "div" && open == "span"
+    ));
+}
+
+#[test]
+fn error() {
+    let err = parse(MismatchPolicy::Error).unwrap_err();
+    assert!(matches!(
+        &err,
+        ParseError::MisnestedClosingTag { name, open, .. } if name == "div" && open == "span"
+    ));
+    assert!(
+        err.to_string()
+            == "Invalid closing tag: Found closing tag for 'div' but 'span' is still open at line 1, column 13."
+    );
+    let lenient = ParseOptions::new()
+        .lenient(true)
+        .on_mismatch(MismatchPolicy::Error);
+    assert!(Html::parse_with_warnings(MISNESTED, &lenient).is_err());
+}
+
+#[test]
+fn optional_end_tags() {
+    let html = "<ul><li>a<li>b</ul><table><tr><td>c</table>";
+    for policy in [
+        MismatchPolicy::CloseAncestor,
+        MismatchPolicy::Error,
+        MismatchPolicy::Ignore,
+    ] {
+        let options = ParseOptions::new().on_mismatch(policy);
+        let (_, warnings) = Html::parse_with_warnings(html, &options).unwrap();
+        assert!(warnings.is_empty());
+    }
+}
+
+#[test]
+fn unmatched_unchanged() {
+    let options = ParseOptions::new().on_mismatch(MismatchPolicy::Ignore);
+    assert!(matches!(
+        Html::parse_with_warnings("<div></span></div>", &options),
+        Err(ParseError::UnmatchedClosingTag { .. })
+    ));
+}
@@ -0,0 +1,70 @@
+use html_filter::prelude::*;
+
+fn list() -> Html {
+    Html::parse("<ul><li>a</li><li>b</li></ul>").unwrap()
+}
+
+fn item(text: &str) -> Html {
+    Html::tag("li", &[]).with_child(Html::text(text))
+}
+
+#[test]
+fn append_child() {
+    let mut html = list();
+    html.append_child(item("c"));
+    assert!(html.to_string() == "<ul><li>a</li><li>b</li><li>c</li></ul>");
+    assert!(html.children().count() == 3);
+}
+
+#[test]
+fn prepend_child() {
+    let mut html = list();
+    html.prepend_child(item("z"));
+    assert!(html.to_string() == "<ul><li>z</li><li>a</li><li>b</li></ul>");
+    assert!(html.children().count() == 3);
+}
+
+#[test]
+fn promote_single_child() {
+    let mut html = Html::parse("<p>text</p>").unwrap();
+    html.prepend_child(Html::tag("br", &[]));
+    html.append_child(Html::text("end"));
+    assert!(html.to_string() == "<p><br>textend</p>");
+    assert!(html.children().count() == 3);
+
+    let mut empty = Html::tag("p", &[]);
+    empty.append_child(Html::text("only"));
+    assert!(matches!(&empty, Html::Tag { child, .. } if **child == Html::text("only")));
+}
+
+#[test]
+fn add_empty_and_vec() {
+    let mut html = list();
+    html.append_child(Html::Empty);
+    html.prepend_child(Html::Empty);
+    assert!(html == list());
+    html.append_child(Html::Vec(Box::new([item("c"), item("d")])));
+    assert!(html.children().count() == 4);
+    assert!(html.to_string() == "<ul><li>a</li><li>b</li><li>c</li><li>d</li></ul>");
+}
+
+#[test]
+fn not_a_tag() {
+    let mut html = Html::text("a");
+    html.append_child(item("b"));
+    html.prepend_child(item("c"));
+    assert!(html == Html::text("a"));
+}
+
+#[test]
+fn replace_with() {
+    let mut html = list();
+    let old = html.children_mut().next().unwrap().replace_with(item("x"));
+    assert!(old == item("a"));
+    assert!(html.to_string() == "<ul><li>x</li><li>b</li></ul>");
+
+    let mut root = list();
+    let old_root = root.replace_with(Html::Empty);
+    assert!(old_root == list());
+    assert!(root.is_empty());
+}
@@ -0,0 +1,121 @@
+use html_filter::prelude::*;
+
+const SVG: &str = r##"<html>
+    <body>
+        <p>Logo</p>
+        <svg viewBox="0 0 10 10" xmlns:xlink="http://www.w3.org/1999/xlink">
+            <svg:g fillRule="evenodd"><use xlink:href="#logo"></use></svg:g>
+            <foreignObject><p>Inside</p></foreignObject>
+        </svg>
+        <math><mi>x</mi></math>
+    </body>
+</html>"##;
+
+fn namespace_of(html: &Html, name: &str) -> Namespace {
+    if let Html::Tag { tag, .. } = html.to_found(&Filter::new().tag_name(name)) {
+        tag.as_namespace()
+    } else {
+        panic!("tag {name} not found")
+    }
+}
+
+#[test]
+fn svg_roundtrip() {
+    let tree = Html::parse(SVG).unwrap();
+    assert!(tree.to_string() == SVG);
+}
+
+#[test]
+fn svg_namespaces() {
+    let tree = Html::parse(SVG).unwrap();
+    assert!(namespace_of(&tree, "body") == Namespace::Html);
+    assert!(namespace_of(&tree, "svg") == Namespace::Svg);
+    assert!(namespace_of(&tree, "svg:g") == Namespace::Svg);
+    assert!(namespace_of(&tree, "use") == Namespace::Svg);
+    assert!(namespace_of(&tree, "foreignObject") == Namespace::Svg);
+    assert!(namespace_of(&tree, "mi") == Namespace::MathMl);
+}
+
+#[test]
+fn svg_attribute_case() {
+    let tree = Html::parse(SVG).unwrap();
+    if let Html::Tag { tag, .. } = tree.find(&Filter::new().tag_name("svg")) {
+        assert!(
+            tag.find_attr_value("viewBox")
+                .is_some_and(|value| value == "0 0 10 10")
+        );
+    } else {
+        panic!("svg not found")
+    }
+}
+
+#[test]
+fn revert_to_html() {
+    let tree = Html::parse("<svg><a:b></a:b></svg><a:b></a:b>").unwrap();
+    let Html::Vec(nodes) = &tree else {
+        unreachable!()
+    };
+    assert!(matches!(&nodes[1], Html::Tag { tag, .. } if tag.as_namespace() == Namespace::Html));
+    let tree = Html::parse("<svg><foreignObject><p>x</p></foreignObject></svg>").unwrap();
+    assert!(namespace_of(&tree, "p") == Namespace::Html);
+}
+
+#[test]
+fn attribute_case() {
+    let tree =
+        Html::parse("<div CLASS='box' Id='main'><svg viewBox='0 0 10 10'></svg></div>").unwrap();
+    let Html::Tag { tag: div, child, .. } = &tree else {
+        unreachable!()
+    };
+    assert!(tree.namespace() == Some(Namespace::Html));
+    assert!(
+        div.find_attr_value("class")
+            .is_some_and(|value| value == "box")
+    );
+    assert!(
+        div.find_attr_value("CLASS")
+            .is_some_and(|value| value == "box")
+    );
+    assert!(
+        div.clone()
+            .into_attr_value("ID")
+            .is_some_and(|value| value == "main")
+    );
+    assert!(tree.has_class("box"));
+
+    let Html::Tag { tag: svg, .. } = &**child else {
+        unreachable!()
+    };
+    assert!(child.namespace() == Some(Namespace::Svg));
+    assert!(
+        svg.find_attr_value("viewBox")
+            .is_some_and(|value| value == "0 0 10 10")
+    );
+    assert!(svg.find_attr_value("viewbox").is_none());
+    assert!(Html::text("a").namespace().is_none());
+}
+
+#[test]
+fn selector_case() {
+    let tree = Html::parse(SVG).unwrap();
+    assert!(tree.select("svg[viewBox]").unwrap().len() == 1);
+    assert!(tree.select("svg[viewbox]").unwrap().is_empty());
+    assert!(tree.select("P").unwrap().len() == 2);
+    assert!(tree.select("foreignObject").unwrap().len() == 1);
+    assert!(tree.select("foreignobject").unwrap().is_empty());
+}
+
+#[test]
+fn equality_case() {
+    let camel = Html::parse(r#"<svg><foreignObject viewBox="0"></foreignObject></svg>"#).unwrap();
+    let lower = Html::parse(r#"<svg><foreignobject viewbox="0"></foreignobject></svg>"#).unwrap();
+    assert!(camel != lower);
+    assert!(camel.select("[viewBox]").unwrap().len() == 1);
+    assert!(lower.select("[viewBox]").unwrap().is_empty());
+    let attr = Html::parse(r#"<svg><foreignObject viewbox="0"></foreignObject></svg>"#).unwrap();
+    assert!(camel != attr);
+    assert!(
+        Html::parse("<div CLASS='a'></div>").unwrap()
+            == Html::parse("<DIV class='a'></DIV>").unwrap()
+    );
+}
@@ -0,0 +1,51 @@
+use html_filter::prelude::*;
+
+const HTML: &str = "<tr><td>a</td><!-- c --><td>b</td>text<td/></tr>";
+
+#[test]
+fn first_child() {
+    let row = Html::parse(HTML).unwrap();
+    assert!(row.first_child().unwrap().to_string() == "<td>a</td>");
+    assert!(row.first_child().unwrap().first_child() == Some(&Html::text("a")));
+}
+
+#[test]
+fn last_child() {
+    let row = Html::parse(HTML).unwrap();
+    assert!(row.last_child().unwrap().to_string() == "<td></td>");
+    assert!(row.last_child().unwrap().last_child().is_none());
+}
+
+#[test]
+fn nth_child() {
+    let row = Html::parse(HTML).unwrap();
+    assert!(row.nth_child(0) == row.first_child());
+    assert!(row.nth_child(1) == Some(&Html::comment(" c ")));
+    assert!(row.nth_child(2).unwrap().to_string() == "<td>b</td>");
+    assert!(row.nth_child(3) == Some(&Html::text("text")));
+    assert!(row.nth_child(4) == row.last_child());
+    assert!(row.nth_child(5).is_none());
+}
+
+#[test]
+fn root_vec() {
+    let html = Html::parse("a<br>c").unwrap();
+    assert!(html.first_child() == Some(&Html::text("a")));
+    assert!(html.nth_child(1).unwrap().to_string() == "<br>");
+    assert!(html.last_child() == Some(&Html::text("c")));
+}
+
+#[test]
+fn leaves() {
+    for node in [
+        Html::text("a"),
+        Html::comment("a"),
+        Html::Empty,
+        Html::parse("<!DOCTYPE html>").unwrap(),
+        Html::parse("<br>").unwrap(),
+    ] {
+        assert!(node.first_child().is_none());
+        assert!(node.last_child().is_none());
+        assert!(node.nth_child(0).is_none());
+    }
+}
@@ -0,0 +1,74 @@
+use std::ops::Range;
+
+use html_filter::prelude::*;
+
+const PAGE: &str = "<!DOCTYPE html><div id='a'>\n  <p>Some <b>bold</b> text</p><br><!-- note -->\n  <ul><li>1</li><li>2</li></ul>\n</div>tail";
+
+/// Checks that the given ranges cover `outer` one after the other, and
+/// returns the ranges of the children of each node.
+fn check_contiguous(map: &[(Range<usize>, Vec<usize>)], parent: &[usize], outer: Range<usize>) {
+    let children = map
+        .iter()
+        .filter(|(_, path)| path.len() == parent.len() + 1 && path.starts_with(parent))
+        .collect::<Vec<_>>();
+    if children.is_empty() {
+        return;
+    }
+    assert!(children.first().unwrap().0.start >= outer.start);
+    assert!(children.last().unwrap().0.end <= outer.end);
+    for pair in children.windows(2) {
+        assert!(pair[0].0.end == pair[1].0.start);
+    }
+    for (range, path) in children {
+        check_contiguous(map, path, range.clone());
+    }
+}
+
+#[test]
+fn same_output_as_display() {
+    for input in [PAGE, "", "text", "<svg><rect/></svg><img src='a'/>"] {
+        let html = Html::parse(input).unwrap();
+        let mut output = String::new();
+        html.write_with_map(&mut output).unwrap();
+        assert!(output == html.to_string());
+    }
+}
+
+#[test]
+fn ranges_are_contiguous() {
+    let html = Html::parse(PAGE).unwrap();
+    let mut output = String::new();
+    let map = html.write_with_map(&mut output).unwrap();
+    let top_level = map
+        .iter()
+        .filter(|(_, path)| path.len() == 1)
+        .collect::<Vec<_>>();
+    assert!(top_level.first().unwrap().0.start == 0);
+    assert!(top_level.last().unwrap().0.end == output.len());
+    check_contiguous(&map, &[], 0..output.len());
+}
+
+#[test]
+fn ranges_map_to_nodes() {
+    let html = Html::parse(PAGE).unwrap();
+    let mut output = String::new();
+    let map = html.write_with_map(&mut output).unwrap();
+    assert!(map.len() == 18);
+    for (range, path) in &map {
+        assert!(output[range.clone()] == html.at_path(path).unwrap().to_string());
+    }
+    let node_at = |needle: &str| {
+        let offset = output.find(needle).unwrap();
+        let (_, path) = map
+            .iter()
+            .rfind(|(range, _)| range.contains(&offset))
+            .unwrap();
+        html.at_path(path).unwrap().to_string()
+    };
+    assert!(node_at("bold") == "bold");
+    assert!(node_at("<b>") == "<b>bold</b>");
+    assert!(node_at("note") == "<!-- note -->");
+    assert!(node_at("2<") == "2");
+    assert!(node_at("</ul>") == "<ul><li>1</li><li>2</li></ul>");
+    assert!(node_at("tail") == "tail");
+}
@@ -0,0 +1,52 @@
+use html_filter::prelude::*;
+
+fn format(input: &str) -> Result<String, ParseError> {
+    Tag::parse_attributes(input).map(|attrs| attrs.iter().map(ToString::to_string).collect())
+}
+
+#[test]
+fn valid_lists() {
+    assert!(format("").unwrap().is_empty());
+    assert!(format("   ").unwrap().is_empty());
+    assert!(format(r#"id="a" class="b""#).unwrap() == r#" id="a" class="b""#);
+    assert!(format("  checked\tdisabled\n").unwrap() == " checked disabled");
+    assert!(
+        format(r#"title = 'a > "b"' data-x="&amp;""#).unwrap()
+            == r#" title='a > "b"' data-x="&amp;""#
+    );
+    assert!(format("xlink:href='#a'").unwrap() == " xlink:href='#a'");
+}
+
+#[test]
+fn structure() {
+    let attrs = Tag::parse_attributes(r#"href="/" download"#).unwrap();
+    assert!(attrs.len() == 2);
+    assert!(attrs[0].as_name() == "href");
+    assert!(attrs[0].as_value().is_some_and(|value| value == "/"));
+    assert!(attrs[1].as_name() == "download");
+    assert!(attrs[1].as_value().is_none());
+}
+
+#[test]
+fn malformed_lists() {
+    assert!(matches!(
+        format("id=a"),
+        Err(ParseError::MissingAttributeQuote { character: 'a', .. })
+    ));
+    assert!(
+        matches!(format(r#"id="a"#), Err(ParseError::UnterminatedAttribute { name, .. }) if name == "id")
+    );
+    assert!(matches!(format("=a"), Err(ParseError::InvalidCharacter { character: '=', .. })));
+    assert!(matches!(
+        format("a>b"),
+        Err(ParseError::InvalidCharacter { character: '>', .. })
+    ));
+    assert!(matches!(
+        format("a /"),
+        Err(ParseError::InvalidCharacter { character: '/', .. })
+    ));
+    assert!(matches!(
+        format("a=\"b\" /c"),
+        Err(ParseError::InvalidCharacter { character: '/', .. })
+    ));
+}
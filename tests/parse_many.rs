@@ -0,0 +1,31 @@
+use html_filter::prelude::*;
+
+const SEPARATOR: &str = "\n<!-- ===== -->\n";
+
+#[test]
+fn malformed_middle_document() {
+    let input = [
+        "<!DOCTYPE html><title>First</title>",
+        "<div>\n  <p>Second</span></p>\n</div>",
+        "<ul><li>Third</li></ul>",
+    ]
+    .join(SEPARATOR);
+    let results = Html::parse_many(&input, SEPARATOR);
+    assert!(results.len() == 3);
+    assert!(results[0].as_ref().unwrap().text_content(false) == "First");
+    assert!(results[2].as_ref().unwrap().to_string() == "<ul><li>Third</li></ul>");
+    if let Err(ParseError::UnmatchedClosingTag { name, position, .. }) = &results[1] {
+        assert!(name == "span");
+        assert!(position.line() == 2 && position.column() == 12);
+    } else {
+        panic!("Expected an unmatched closing tag, found {:?}", results[1]);
+    }
+}
+
+#[test]
+fn edge_separators() {
+    assert!(Html::parse_many("<p>a</p>", SEPARATOR) == [Html::parse("<p>a</p>")]);
+    assert!(Html::parse_many("a|b", "") == [Ok(Html::text("a|b"))]);
+    let results = Html::parse_many("|a|", "|");
+    assert!(results == [Ok(Html::Empty), Ok(Html::text("a")), Ok(Html::Empty)]);
+}
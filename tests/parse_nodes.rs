@@ -0,0 +1,40 @@
+use html_filter::prelude::*;
+
+#[test]
+fn three_nodes() {
+    let nodes = Html::parse_nodes("a<br/>c").unwrap();
+    assert!(nodes.len() == 3);
+    assert!(nodes[0] == Html::text("a"));
+    assert!(nodes[1].to_string() == "<br>");
+    assert!(nodes[2] == Html::text("c"));
+
+    let options = ParseOptions::new().xml_self_closing(true);
+    let (tree, _) = Html::parse_with_warnings("a<b/>c", &options).unwrap();
+    assert!(tree.children().count() == 3);
+}
+
+#[test]
+fn non_void_self_closing() {
+    // `<b/>` opens a `<b>` in HTML, so `c` is its child.
+    let nodes = Html::parse_nodes("a<b/>c").unwrap();
+    assert!(nodes.len() == 2);
+    assert!(nodes[1].to_string() == "<b>c</b>");
+}
+
+#[test]
+fn single_and_empty() {
+    let nodes = Html::parse_nodes("<div><p>a</p><p>b</p></div>").unwrap();
+    assert!(nodes.len() == 1);
+    assert!(nodes[0].to_string() == "<div><p>a</p><p>b</p></div>");
+
+    assert!(Html::parse_nodes("").unwrap().is_empty());
+}
+
+#[test]
+fn same_as_parse() {
+    let html = "<!DOCTYPE html><!-- c --><p>x</p>tail";
+    let nodes = Html::parse_nodes(html).unwrap();
+    assert!(nodes.len() == 4);
+    assert!(nodes.iter().map(ToString::to_string).collect::<String>() == html);
+    assert!(Html::parse_nodes("</p>").is_err());
+}
@@ -0,0 +1,74 @@
+use html_filter::prelude::*;
+
+const DOCUMENTS: [&str; 10] = [
+    "",
+    "Text",
+    "<!-- comment -->",
+    "<p>Text</p>",
+    "<p id='a'>Text</p>",
+    r#"<p id="a" class='b'>Other<br></p>"#,
+    "<ul><li>A</li><li>B</li><li>C</li></ul>",
+    "<ul><li class='x'>A</li><li>C</li></ul><p>End</p>",
+    "<!DOCTYPE html><html><head><title>T</title></head><body><p>Text</p></body></html>",
+    "<html><body><div><p>Text</p><p>More <b>bold</b></p></div><!-- x --></body></html>",
+];
+
+#[test]
+fn roundtrip() {
+    for old_str in DOCUMENTS {
+        for new_str in DOCUMENTS {
+            let old = Html::parse(old_str).unwrap();
+            let new = Html::parse(new_str).unwrap();
+            let mut tree = old.clone();
+            tree.apply(&Html::diff(&old, &new)).unwrap();
+            assert!(tree == new, "{old_str:?} -> {new_str:?} gave {tree}");
+            assert!(tree.to_string() == new.to_string());
+        }
+    }
+}
+
+#[test]
+fn invalid_path() {
+    let mut tree = Html::parse("<p>Text</p>").unwrap();
+    let err = tree
+        .apply(&[Patch::SetText { path: vec![0, 1], text: "New".to_owned() }])
+        .unwrap_err();
+    assert!(matches!(err, ApplyError::InvalidPath { path, .. } if path == [0, 1]));
+    let err = tree.apply(&[Patch::Remove { path: vec![] }]).unwrap_err();
+    assert!(matches!(err, ApplyError::InvalidPath { path, .. } if path.is_empty()));
+}
+
+#[test]
+fn unexpected_node() {
+    let mut tree = Html::parse("<p>Text</p>").unwrap();
+    let err = tree
+        .apply(&[Patch::SetText { path: vec![0], text: "New".to_owned() }])
+        .unwrap_err();
+    assert!(err.to_string() == "Node at [0] isn't a text.");
+    let err = tree
+        .apply(&[Patch::RemoveAttribute { name: "id".to_owned(), path: vec![0] }])
+        .unwrap_err();
+    assert!(err.to_string() == "Tag at [0] doesn't have an attribute 'id'.");
+}
+
+#[test]
+fn attribute_name_case() {
+    let mut tree = Html::parse("<p ID='a' CLASS='b'>Text</p>").unwrap();
+    tree.apply(&[
+        Patch::RemoveAttribute { name: "id".to_owned(), path: vec![0] },
+        Patch::SetAttribute { attr: Attribute::from("class".to_owned()), path: vec![0] },
+    ])
+    .unwrap();
+    assert!(tree.to_string() == "<p class>Text</p>");
+}
+
+#[test]
+fn partial_application() {
+    let mut tree = Html::parse("<p>a</p><p>b</p>").unwrap();
+    let patches = [
+        Patch::Remove { path: vec![1] },
+        Patch::Remove { path: vec![1] },
+    ];
+    assert!(tree.apply(&patches).is_err());
+    assert!(tree.to_string() == "<p>a</p>");
+}
@@ -0,0 +1,48 @@
+use html_filter::prelude::*;
+
+fn names(path: &[&Html]) -> Vec<String> {
+    path.iter()
+        .map(|node| match node {
+            Html::Tag { tag, .. } => tag.as_name().to_owned(),
+            Html::Vec(_) => "#vec".to_owned(),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+const HTML: &str = r#"<!DOCTYPE html><body><nav><ul><li><a href="/">Home</a></li><li><a href="/blog">Blog</a></li></ul></nav><main><p>Some <b>bold</b> text</p></main></body>"#;
+
+#[test]
+fn nested_text() {
+    let html = Html::parse(HTML).unwrap();
+    let bold = html.select("b").unwrap()[0];
+    let text = bold.first_child().unwrap();
+    assert!(*text == Html::text("bold"));
+
+    let path = html.path_to(text).unwrap();
+    assert!(names(&path) == ["#vec", "body", "main", "p", "b", "bold"]);
+    assert!(std::ptr::eq(*path.last().unwrap(), text));
+}
+
+#[test]
+fn link_in_nav() {
+    let html = Html::parse(HTML).unwrap();
+    for link in html.select("a").unwrap() {
+        let path = html.path_to(link).unwrap();
+        assert!(names(&path) == ["#vec", "body", "nav", "ul", "li", "a"]);
+    }
+    let paragraph = html.select("p").unwrap()[0];
+    let path = html.path_to(paragraph).unwrap();
+    assert!(!names(&path).contains(&"nav".to_owned()));
+}
+
+#[test]
+fn root_and_missing() {
+    let html = Html::parse(HTML).unwrap();
+    assert!(html.path_to(&html).unwrap().len() == 1);
+
+    let copy = html.clone();
+    assert!(html.path_to(&copy).is_none());
+    let other_bold = copy.select("b").unwrap()[0];
+    assert!(html.path_to(other_bold).is_none());
+}
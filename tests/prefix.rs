@@ -0,0 +1,62 @@
+use html_filter::prelude::*;
+
+#[test]
+fn two_steps() {
+    let input = "<a>x</a><b>y</b>";
+    let (first, consumed) = Html::parse_prefix(input).unwrap();
+    assert!(first.to_string() == "<a>x</a>");
+    assert!(consumed == 8);
+
+    let rest = &input[consumed..];
+    let (second, consumed) = Html::parse_prefix(rest).unwrap();
+    assert!(second.to_string() == "<b>y</b>");
+    assert!(consumed == rest.len());
+}
+
+#[test]
+fn nested_same_name() {
+    let input = "<div><div>a</div>b</div><p>c</p>";
+    let (first, consumed) = Html::parse_prefix(input).unwrap();
+    assert!(first.to_string() == "<div><div>a</div>b</div>");
+    assert!(&input[consumed..] == "<p>c</p>");
+}
+
+#[test]
+fn text_and_comments() {
+    let input = "some text<!-- note --><br>";
+    let (text, consumed) = Html::parse_prefix(input).unwrap();
+    assert!(text == Html::text("some text"));
+
+    let input = &input[consumed..];
+    let (comment, consumed) = Html::parse_prefix(input).unwrap();
+    assert!(comment.to_string() == "<!-- note -->");
+
+    let (br, consumed) = Html::parse_prefix(&input[consumed..]).unwrap();
+    assert!(br.to_string() == "<br>");
+    assert!(consumed == 4);
+}
+
+#[test]
+fn whole_stream() {
+    let mut input = "<li>1</li><li>2<b>!</b></li><br/><li>3</li>";
+    let mut fragments = vec![];
+    while !input.is_empty() {
+        let (node, consumed) = Html::parse_prefix(input).unwrap();
+        fragments.push(node.to_string());
+        input = &input[consumed..];
+    }
+    assert!(fragments == ["<li>1</li>", "<li>2<b>!</b></li>", "<br>", "<li>3</li>"]);
+}
+
+#[test]
+fn incomplete() {
+    let (html, consumed) = Html::parse_prefix("<p>a<b>b").unwrap();
+    assert!(consumed == 8);
+    assert!(html.to_string() == "<p>a<b>b</b></p>");
+
+    let (html, consumed) = Html::parse_prefix("").unwrap();
+    assert!(html.is_empty());
+    assert!(consumed == 0);
+
+    assert!(Html::parse_prefix("<p></span>").is_err());
+}
@@ -0,0 +1,69 @@
+use std::fmt::Write as _;
+
+use html_filter::prelude::*;
+
+fn streamed(html: &Html, indent: usize) -> String {
+    let mut output = String::new();
+    html.write_pretty(&mut output, indent).unwrap();
+    output
+}
+
+#[test]
+fn nested_tags() {
+    let html = Html::parse("<div id='a'><ul><li>a</li><li><b>b</b> c</li></ul></div>").unwrap();
+    assert!(
+        html.to_pretty_string(2)
+            == "<div id='a'>\n  <ul>\n    <li>a</li>\n    <li>\n      <b>b</b>\n      c\n    </li>\n  </ul>\n</div>\n"
+    );
+}
+
+#[test]
+fn whitespace_texts() {
+    let html = Html::parse("<div>\n  <p>\n    text\n  </p>\n</div>").unwrap();
+    assert!(html.to_pretty_string(1) == "<div>\n <p>\n  text\n </p>\n</div>\n");
+}
+
+#[test]
+fn preformatted() {
+    let html =
+        Html::parse("<body><pre>\n  a\n    b</pre><script>if (a) {\n}</script></body>").unwrap();
+    assert!(
+        html.to_pretty_string(2)
+            == "<body>\n  <pre>\n  a\n    b</pre>\n  <script>if (a) {\n}</script>\n</body>\n"
+    );
+}
+
+#[test]
+fn comments_and_doctype() {
+    let html = Html::parse("<!DOCTYPE html><html><!-- a --><body></body></html>").unwrap();
+    assert!(
+        html.to_pretty_string(2)
+            == "<!DOCTYPE html>\n<html>\n  <!-- a -->\n  <body></body>\n</html>\n"
+    );
+}
+
+#[test]
+fn streamed_matches_string() {
+    for input in [
+        "",
+        "text",
+        "<p>a<br>b<img src=\"a.png\"/></p>",
+        "<table><tr><td>1</td><td>2</td></tr></table>",
+        "<div>\n<textarea> a\n</textarea><!-- b -->c</div>",
+        include_str!("data/index.html"),
+    ] {
+        let html = Html::parse(input).unwrap();
+        for indent in [0, 2, 4] {
+            assert!(streamed(&html, indent) == html.to_pretty_string(indent));
+        }
+    }
+}
+
+#[test]
+fn streamed_appends() {
+    let html = Html::parse("<p>a</p>").unwrap();
+    let mut output = String::from("start\n");
+    html.write_pretty(&mut output, 2).unwrap();
+    writeln!(output, "end").unwrap();
+    assert!(output == "start\n<p>a</p>\nend\n");
+}
@@ -0,0 +1,85 @@
+use html_filter::prelude::*;
+
+const HTML: &str = "<a href='&#106;avascript&#58;alert(1)' title=\"a &amp; b\" download>x</a>";
+
+fn parse_tag(options: &ParseOptions) -> Tag {
+    match Html::parse_with_warnings(HTML, options).unwrap().0 {
+        Html::Tag { tag, .. } => tag,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn decoded_and_raw() {
+    let options = ParseOptions::new()
+        .decode_attr_entities(true)
+        .keep_raw_attr_values(true);
+    let tag = parse_tag(&options);
+    assert!(tag.find_attr_value("href").unwrap() == "javascript:alert(1)");
+    assert!(tag.get_raw("href").unwrap() == "&#106;avascript&#58;alert(1)");
+    assert!(tag.find_attr_value("title").unwrap() == "a & b");
+    assert!(tag.get_raw("title").unwrap() == "a &amp; b");
+    assert!(tag.get_raw("download").is_none());
+    assert!(tag.get_raw("missing").is_none());
+}
+
+#[test]
+fn without_decoding() {
+    let tag = parse_tag(&ParseOptions::new().keep_raw_attr_values(true));
+    assert!(tag.find_attr_value("title").unwrap() == "a &amp; b");
+    assert!(tag.get_raw("title").unwrap() == "a &amp; b");
+}
+
+#[test]
+fn disabled_by_default() {
+    let tag = parse_tag(&ParseOptions::new().decode_attr_entities(true));
+    assert!(tag.find_attr_value("title").unwrap() == "a & b");
+    assert!(tag.get_raw("title").is_none());
+    assert!(
+        Tag::builder("a")
+            .attr("href", "/")
+            .build()
+            .get_raw("href")
+            .is_none()
+    );
+}
+
+#[test]
+fn lowercase_names() {
+    let options = ParseOptions::new()
+        .keep_raw_attr_values(true)
+        .lowercase_names(true);
+    let html = Html::parse_with_warnings("<p ID='&lt;'>x</p>", &options)
+        .unwrap()
+        .0;
+    let Html::Tag { tag, .. } = html else {
+        unreachable!()
+    };
+    assert!(tag.get_raw("id").unwrap() == "&lt;");
+}
+
+#[test]
+fn ignored_by_equality() {
+    let options = ParseOptions::new()
+        .decode_attr_entities(true)
+        .keep_raw_attr_values(true);
+    let decoded = ParseOptions::new().decode_attr_entities(true);
+    assert!(parse_tag(&options) == parse_tag(&decoded));
+}
+
+#[test]
+fn forgotten_when_modified() {
+    let options = ParseOptions::new()
+        .decode_attr_entities(true)
+        .keep_raw_attr_values(true);
+    let html = Html::parse_with_warnings("<p class='b &amp; b' id='&lt;'>x</p>", &options)
+        .unwrap()
+        .0;
+    let Html::Tag { mut tag, .. } = html else {
+        unreachable!()
+    };
+    tag.normalize_classes();
+    assert!(tag.find_attr_value("class").unwrap() == "b &");
+    assert!(tag.get_raw("class").is_none());
+    assert!(tag.get_raw("id").unwrap() == "&lt;");
+}
@@ -0,0 +1,58 @@
+use html_filter::prelude::*;
+
+#[test]
+fn title_with_less_than() {
+    let html = "<head><title>1 < 2 <b>bold</b></title></head>";
+    let tree = Html::parse(html).unwrap();
+    assert!(tree.to_string() == html);
+    let title = tree.children().next().unwrap();
+    assert!(title.children().count() == 1);
+    assert!(title.text_content(false) == "1 < 2 <b>bold</b>");
+}
+
+#[test]
+fn title_with_entities() {
+    let html = "<title>Tom &amp; Jerry &lt;3</title>";
+    assert!(Html::parse(html).unwrap().to_string() == html);
+
+    let options = ParseOptions::new().decode_text_entities(true);
+    let (tree, _) = Html::parse_with_warnings(html, &options).unwrap();
+    assert!(tree.text_content(false) == "Tom & Jerry <3");
+
+    let options = ParseOptions::new().decode_text_entities(true);
+    let (tree, _) = Html::parse_with_warnings("<script>a &amp;&amp; b</script>", &options).unwrap();
+    assert!(tree.text_content(false) == "a &amp;&amp; b");
+}
+
+#[test]
+fn textarea() {
+    let html = "<form><textarea name='t'><p>not a tag</p> &amp; </textarea><p>a tag</p></form>";
+    let tree = Html::parse(html).unwrap();
+    assert!(tree.to_string() == html);
+    let filter = Filter::new().tag_name("p");
+    assert!(tree.find(&filter).to_string() == "<p>a tag</p>");
+}
+
+#[test]
+fn unclosed_title() {
+    let tree = Html::parse("<title>a <i>b").unwrap();
+    assert!(tree.to_string() == "<title>a <i>b</title>");
+}
+
+#[test]
+fn svg_title_is_markup() {
+    let html = "<svg><title>a <tspan>b</tspan></title></svg>";
+    let tree = Html::parse(html).unwrap();
+    assert!(tree.to_string() == html);
+    let filter = Filter::new().tag_name("tspan");
+    assert!(tree.find(&filter).to_string() == "<tspan>b</tspan>");
+}
+
+#[test]
+fn tokens() {
+    let tokens = Tokenizer::new("<title>x<y</title>")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(tokens.len() == 3);
+    assert!(tokens[1] == Token::Text("x<y".to_owned()));
+}
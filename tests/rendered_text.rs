@@ -0,0 +1,48 @@
+use html_filter::prelude::*;
+
+const PAGE: &str = "<html><head><title>Menu</title><style>p { color: red }</style></head>
+<body>
+  <h1>Today's   menu</h1>
+  <!-- updated daily -->
+  <div>Starters:<br>Soup<br>Salad &amp; bread</div>
+  <p>Mains are <b>served</b>
+     with <i>fries</i>.</p>
+  <pre>  a
+  b</pre>
+  <script>track();</script>
+</body></html>";
+
+#[test]
+fn compared_to_text_content() {
+    let html = Html::parse(PAGE).unwrap();
+    let rendered = html.rendered_text();
+    assert!(
+        rendered
+            == "Today's menu\nStarters:\nSoup\nSalad & bread\nMains are served with fries.\n  a\n  b"
+    );
+    let raw = html.text_content(false);
+    assert!(raw.contains("Menu") && raw.contains("track();") && raw.contains("&amp;"));
+    assert!(raw.contains("Today's   menu"));
+    assert!(
+        !rendered.contains("Menu") && !rendered.contains("track") && !rendered.contains("color")
+    );
+}
+
+#[test]
+fn inline_and_blocks() {
+    let html = Html::parse("a<span> b </span>c<div>d</div><div></div>e<br><br>f").unwrap();
+    assert!(html.rendered_text() == "a b c\nd\ne\n\nf");
+    assert!(
+        Html::parse("  <p>  </p>  ")
+            .unwrap()
+            .rendered_text()
+            .is_empty()
+    );
+    assert!(Html::text("x").rendered_text() == "x");
+}
+
+#[test]
+fn textarea_keeps_whitespace() {
+    let html = Html::parse("<p>in <textarea>a  b</textarea> form</p>").unwrap();
+    assert!(html.rendered_text() == "in a  b form");
+}
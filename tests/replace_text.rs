@@ -0,0 +1,55 @@
+use html_filter::prelude::*;
+
+fn replace(html: &str, from: &str, to: &str) -> (usize, String) {
+    let mut tree = Html::parse(html).unwrap();
+    let count = tree.replace_text(from, to);
+    (count, tree.to_string())
+}
+
+#[test]
+fn no_occurrence() {
+    let html = "<p>Hello world</p>";
+    assert!(replace(html, "name", "Alice") == (0, html.to_owned()));
+    assert!(replace(html, "", "Alice") == (0, html.to_owned()));
+    assert!(replace("", "a", "b") == (0, String::new()));
+}
+
+#[test]
+fn single_occurrence() {
+    assert!(
+        replace("<p>Hello world</p>", "world", "there") == (1, "<p>Hello there</p>".to_owned())
+    );
+    assert!(replace("secret", "secret", "") == (1, String::new()));
+}
+
+#[test]
+fn multiple_nodes() {
+    assert!(
+        replace("<ul><li>a-a</li><li>b</li><li><i>a</i></li></ul>a", "a", "[x]")
+            == (4, "<ul><li>[x]-[x]</li><li>b</li><li><i>[x]</i></li></ul>[x]".to_owned())
+    );
+}
+
+#[test]
+fn only_texts() {
+    let html = r#"<!DOCTYPE x><a href="x"><!-- x --><script>x()</script><style>x {}</style><pre>x</pre></a>"#;
+    assert!(
+        replace(html, "x", "y")
+            == (
+                1,
+                r#"<!DOCTYPE x><a href="x"><!-- x --><script>x()</script><style>x {}</style><pre>y</pre></a>"#
+                    .to_owned()
+            )
+    );
+    let html = "<SCRIPT>name</SCRIPT><Style>name {}</Style>";
+    assert!(replace(html, "name", "Alice") == (0, html.to_owned()));
+}
+
+#[test]
+fn split_occurrences() {
+    let options = ParseOptions::new().split_text_runs(true);
+    let (mut tree, _) = Html::parse_with_warnings("a&amp;b", &options).unwrap();
+    assert!(tree.replace_text("a&amp;b", "c") == 0);
+    assert!(tree.replace_text("b", "c") == 1);
+    assert!(tree.to_string() == "a&amp;c");
+}
@@ -0,0 +1,37 @@
+use html_filter::prelude::*;
+
+fn roundtrip(html: &str) {
+    let tree = Html::parse(html).unwrap();
+    assert!(tree.to_string() == html, "{html:?} gave {tree}");
+}
+
+#[test]
+fn multiple_root_comments() {
+    roundtrip("<!-- a --><!-- b --><!-- c -->");
+    roundtrip("<!-- a -->\n<!-- b -->\n");
+}
+
+#[test]
+fn comment_before_doctype() {
+    roundtrip("<!-- generated --><!DOCTYPE html><html></html>");
+    roundtrip("<!-- a -->\n<!-- b --><!DOCTYPE html>\n<html><body></body></html>");
+}
+
+#[test]
+fn interleaved_comments_and_tags() {
+    roundtrip("<!-- a --><p>1</p><!-- b --><p>2</p><!-- c -->");
+    roundtrip("<!--a--><div><!--b--><p>x</p><!--c--></div><!--d-->text<!--e-->");
+}
+
+#[test]
+fn order_of_root_nodes() {
+    let tree = Html::parse("<!-- a --><!DOCTYPE html><!-- b --><html></html>").unwrap();
+    let Html::Vec(nodes) = tree else {
+        panic!("expected a list of nodes")
+    };
+    assert!(nodes.len() == 4);
+    assert!(matches!(&nodes[0], Html::Comment(content) if content == " a "));
+    assert!(matches!(&nodes[1], Html::Doctype { name, .. } if name == "DOCTYPE"));
+    assert!(matches!(&nodes[2], Html::Comment(content) if content == " b "));
+    assert!(matches!(&nodes[3], Html::Tag { tag, .. } if tag.as_name() == "html"));
+}
@@ -0,0 +1,88 @@
+use html_filter::prelude::*;
+
+const LIST: &str = r#"<div id="main"><ul class="list"><li class="item">A</li><li class="item active"><a href="/b">B</a></li><li>C</li></ul><p class="item">D</p></div>"#;
+
+fn select(selector: &str) -> Vec<String> {
+    Html::parse(LIST)
+        .unwrap()
+        .select(selector)
+        .unwrap()
+        .into_iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn query_selector_all_items() {
+    let items = {
+        let tree = Html::parse(LIST).unwrap();
+        tree.query_selector_all(".item").unwrap()
+    };
+    let serialized = items.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert!(
+        serialized
+            == [
+                r#"<li class="item">A</li>"#,
+                r#"<li class="item active"><a href="/b">B</a></li>"#,
+                r#"<p class="item">D</p>"#,
+            ]
+    );
+}
+
+#[test]
+fn compound() {
+    assert!(select("li.item.active > a") == [r#"<a href="/b">B</a>"#]);
+    assert!(select("LI.item").len() == 2);
+    assert!(select("#main").len() == 1);
+    assert!(select("*").len() == 7);
+    assert!(select("[href='/b']") == select("a[href]"));
+    assert!(select(r#"a[href="/c"]"#).is_empty());
+}
+
+#[test]
+fn combinators() {
+    assert!(select("div li").len() == 3);
+    assert!(select("div > li").is_empty());
+    assert!(select("#main > .item") == [r#"<p class="item">D</p>"#]);
+    assert!(select("div ul > li a") == [r#"<a href="/b">B</a>"#]);
+}
+
+#[test]
+fn list_in_document_order() {
+    assert!(select("p, ul") == [select("ul")[0].clone(), select("p")[0].clone()]);
+}
+
+#[test]
+fn invalid() {
+    let tree = Html::parse(LIST).unwrap();
+    assert!(tree.select("").unwrap_err() == SelectorError::UnexpectedEnd);
+    assert!(tree.select("ul >").unwrap_err() == SelectorError::UnexpectedEnd);
+    assert!(tree.select("a[href").unwrap_err() == SelectorError::UnexpectedEnd);
+    assert!(matches!(
+        tree.query_selector_all("ul ~ li").unwrap_err(),
+        SelectorError::InvalidCharacter { character: '~', position: 3, .. }
+    ));
+    assert!(matches!(
+        tree.select("a,,b").unwrap_err(),
+        SelectorError::InvalidCharacter { character: ',', position: 2, .. }
+    ));
+}
+
+#[test]
+fn select_one() {
+    let tree = Html::parse(LIST).unwrap();
+    let first = tree.select_one(".item").unwrap().unwrap();
+    assert!(first.to_string() == r#"<li class="item">A</li>"#);
+    assert!(std::ptr::eq(first, tree.select(".item").unwrap()[0]));
+
+    let nested = tree.select_one("div *").unwrap().unwrap();
+    assert!(nested.to_string().starts_with(r#"<ul class="list">"#));
+    assert!(tree.select_one("ul > a").unwrap().is_none());
+    assert!(tree.select_one("table").unwrap().is_none());
+    assert!(tree.select_one("li >").is_err());
+
+    for selector in ["*", "li", "#main li a", "p.item", "ol"] {
+        let all = tree.select(selector).unwrap();
+        assert!(tree.select_one(selector).unwrap() == all.first().copied());
+    }
+}
@@ -0,0 +1,52 @@
+use html_filter::prelude::*;
+
+#[test]
+fn non_void_nests() {
+    let (tree, warnings) =
+        Html::parse_with_warnings("<div/>text</div>", &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == "<div>text</div>");
+    assert!(
+        matches!(&tree, Html::Tag { child, .. } if matches!(&**child, Html::Text(text) if text == "text"))
+    );
+    assert!(
+        warnings.first().unwrap().to_string()
+            == "Ignored self-closing slash on 'div' at line 1, column 1 as it isn't a void element."
+    );
+}
+
+#[test]
+fn void_self_closes() {
+    let (tree, warnings) =
+        Html::parse_with_warnings("<p><img src='a'/>text<br/></p>", &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == "<p><img src='a'></img>text<br></p>");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn uppercase_void_self_closes() {
+    let (tree, warnings) =
+        Html::parse_with_warnings("<p><IMG src='a' />text<BR/></p>", &ParseOptions::new()).unwrap();
+    let Html::Tag { child, .. } = &tree else {
+        panic!()
+    };
+    assert!(matches!(&**child, Html::Vec(children) if children.len() == 3));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn foreign_self_closes() {
+    let (tree, warnings) =
+        Html::parse_with_warnings("<svg><rect/><circle/></svg>", &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == "<svg><rect></rect><circle></circle></svg>");
+    assert!(warnings.is_empty());
+    let html = Html::parse("<svg><foreignObject><div/>x</div></foreignObject></svg>").unwrap();
+    assert!(html.to_string() == "<svg><foreignObject><div>x</div></foreignObject></svg>");
+}
+
+#[test]
+fn xml_self_closing() {
+    let options = ParseOptions::new().xml_self_closing(true);
+    let (tree, warnings) = Html::parse_with_warnings("<div/>text", &options).unwrap();
+    assert!(tree.to_string() == "<div></div>text");
+    assert!(warnings.is_empty());
+}
@@ -0,0 +1,42 @@
+use html_filter::prelude::*;
+
+#[test]
+fn built_br_with_child() {
+    let br = Html::from(Tag::builder("br").build());
+    assert!(br.to_string() == "<br>");
+    let html = br.with_child(Html::text("a"));
+    assert!(html.to_string() == "<br>a</br>");
+}
+
+#[test]
+fn preserved_self_closing_with_child() {
+    let options = ParseOptions::new().preserve_source(true);
+    let (mut html, _) = Html::parse_with_warnings("<p><img  src='a.png'/></p>", &options).unwrap();
+    assert!(html.to_string() == "<p><img  src='a.png'/></p>");
+    for child in html.children_mut() {
+        let img = core::mem::take(child);
+        *child = img.with_child(Html::text("alt"));
+    }
+    assert!(html.to_string() == "<p><img src='a.png'>alt</img></p>");
+}
+
+#[test]
+fn preserved_br_with_child() {
+    let options = ParseOptions::new().preserve_source(true);
+    let (html, _) = Html::parse_with_warnings("<br >", &options).unwrap();
+    assert!(html.to_string() == "<br >");
+    assert!(html.with_child(Html::text("x")).to_string() == "<br>x</br>");
+}
+
+#[test]
+fn unclosed_source_kept() {
+    let options = ParseOptions::new().preserve_source(true);
+    let (html, _) = Html::parse_with_warnings("<p >a", &options).unwrap();
+    assert!(html.to_string() == "<p >a");
+}
+
+#[test]
+fn flat_display() {
+    let html = Html::from(Tag::builder("br").build()).with_child(Html::text("a"));
+    assert!(html.to_flat().to_string() == "<br>a</br>");
+}
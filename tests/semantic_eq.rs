@@ -0,0 +1,74 @@
+use html_filter::prelude::*;
+
+fn parse(html: &str) -> Html {
+    Html::parse(html).unwrap()
+}
+
+const ALL: EqOptions = EqOptions::new()
+    .ignore_attr_order(true)
+    .ignore_comments(true)
+    .ignore_whitespace(true);
+
+#[test]
+fn whitespace_only() {
+    let indented = parse("<ul>\n  <li>A</li>\n  <li>B</li>\n</ul>\n");
+    let compact = parse("<ul><li>A</li><li>B</li></ul>");
+    assert!(!indented.semantically_eq(&compact, EqOptions::new()));
+    assert!(indented.semantically_eq(&compact, EqOptions::new().ignore_whitespace(true)));
+    assert!(!indented.semantically_eq(&parse("<ul><li>A</li></ul>"), ALL));
+}
+
+#[test]
+fn comments_only() {
+    let commented = parse("<!-- header --><p>a<!-- note -->b</p>");
+    let plain = parse("<p>ab</p>");
+    assert!(!commented.semantically_eq(&plain, EqOptions::new()));
+    assert!(!commented.semantically_eq(&plain, EqOptions::new().ignore_whitespace(true)));
+    assert!(commented.semantically_eq(&plain, EqOptions::new().ignore_comments(true)));
+}
+
+#[test]
+fn whitespace_and_comments() {
+    let rendered = parse("<div>\n  <!-- list -->\n  <ul>\n    <li>One</li>\n  </ul>\n</div>");
+    let expected = parse("<div><ul><li>One</li></ul></div>");
+    let options = EqOptions::new()
+        .ignore_comments(true)
+        .ignore_whitespace(true);
+    assert!(rendered.semantically_eq(&expected, options));
+    assert!(!rendered.semantically_eq(&expected, EqOptions::new().ignore_comments(true)));
+    assert!(!rendered.semantically_eq(&expected, EqOptions::new().ignore_whitespace(true)));
+}
+
+#[test]
+fn attributes() {
+    let left = parse(r#"<input type="text" name='q' required>"#);
+    let reordered = parse(r#"<input required name="q" type='text'>"#);
+    assert!(
+        left.semantically_eq(&parse(r#"<input type='text' name="q" required>"#), EqOptions::new())
+    );
+    assert!(!left.semantically_eq(&reordered, EqOptions::new()));
+    assert!(left.semantically_eq(&reordered, EqOptions::new().ignore_attr_order(true)));
+    assert!(!left.semantically_eq(&parse(r#"<input type="text" name="r" required>"#), ALL));
+    assert!(!left.semantically_eq(&parse(r#"<input type="text" name="q">"#), ALL));
+}
+
+#[test]
+fn significant_differences() {
+    assert!(!parse("<p>a</p>").semantically_eq(&parse("<p>b</p>"), ALL));
+    assert!(!parse("<p>a</p>").semantically_eq(&parse("<div>a</div>"), ALL));
+    assert!(!parse("<p> a </p>").semantically_eq(&parse("<p>a</p>"), ALL));
+    assert!(!parse("<pre> </pre>").semantically_eq(&parse("<pre></pre>"), ALL));
+    assert!(parse(" <br> ").semantically_eq(&parse("<br>"), ALL));
+}
+
+#[test]
+fn same_as_partial_eq() {
+    for html in [
+        "<p>a</p>",
+        "<!DOCTYPE html><p><!--x--></p>",
+        "a<b>c</b>",
+        "",
+    ] {
+        assert!(parse(html).semantically_eq(&parse(html), EqOptions::new()));
+    }
+}
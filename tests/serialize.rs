@@ -0,0 +1,128 @@
+use html_filter::prelude::*;
+
+const HTML: &str =
+    "<ul class='menu'>\n  <li id=\"a\">x &amp; y</li>\n  <li hidden><br><pre> </pre></li>\n</ul>";
+
+#[test]
+fn default_policy() {
+    let html = Html::parse(HTML).unwrap();
+    assert!(html.outer_html() == HTML);
+    assert!(html.outer_html_with(&SerializePolicy::new()) == html.to_string());
+    assert!(html.outer_html_with(&SerializePolicy::default()) == HTML);
+}
+
+#[test]
+fn xhtml_double_quotes_trimmed() {
+    let html = Html::parse(HTML).unwrap();
+    let policy = SerializePolicy::new()
+        .xhtml(true)
+        .quotes(QuoteStyle::Double)
+        .trim_whitespace(true);
+    assert!(
+        html.outer_html_with(&policy)
+            == r#"<ul class="menu"><li id="a">x &amp; y</li><li hidden="hidden"><br /><pre> </pre></li></ul>"#
+    );
+    assert!(
+        html.outer_html_with(&SerializePolicy::new().xhtml(true))
+            == html.display_xhtml().to_string()
+    );
+}
+
+#[test]
+fn single_quotes_escaped() {
+    let html = Html::from(Tag::builder("a").attr("title", "it's <b>").build())
+        .with_child(Html::text("Tom & Jerry"));
+    assert!(html.to_string() == r#"<a title="it's <b>">Tom & Jerry</a>"#);
+
+    let policy = SerializePolicy::new()
+        .quotes(QuoteStyle::Single)
+        .escape(true);
+    assert!(html.outer_html_with(&policy) == "<a title='it&#39;s &lt;b&gt;'>Tom &amp; Jerry</a>");
+
+    let policy = SerializePolicy::new().escape(true);
+    assert!(html.outer_html_with(&policy) == r#"<a title="it's &lt;b&gt;">Tom &amp; Jerry</a>"#);
+}
+
+#[test]
+fn escaped_decoded_entities() {
+    let options = ParseOptions::new()
+        .decode_text_entities(true)
+        .decode_attr_entities(true);
+    let input = r#"<p title="&quot;q&quot;">a &lt; b</p><script>if (a < b) {}</script>"#;
+    let (html, _) = Html::parse_with_warnings(input, &options).unwrap();
+    assert!(html.to_string() == r#"<p title=""q"">a < b</p><script>if (a < b) {}</script>"#);
+
+    let policy = SerializePolicy::new().escape(true);
+    assert!(html.outer_html_with(&policy) == input);
+}
+
+#[test]
+fn preserved_sources() {
+    let input = "<div  id='a' >\n<br/></div >";
+    let options = ParseOptions::new().preserve_source(true);
+    let (html, _) = Html::parse_with_warnings(input, &options).unwrap();
+    assert!(html.outer_html() == input);
+
+    let policy = SerializePolicy::new().trim_whitespace(true);
+    assert!(html.outer_html_with(&policy) == "<div  id='a' ><br/></div >");
+
+    let policy = SerializePolicy::new().quotes(QuoteStyle::Double);
+    assert!(html.outer_html_with(&policy) == "<div id=\"a\">\n<br></div>");
+}
+
+#[test]
+fn ensure_doctype() {
+    let policy = SerializePolicy::new().ensure_doctype(Some("html".to_owned()));
+    let html = Html::parse("<html><body>x</body></html>").unwrap();
+    assert!(html.outer_html_with(&policy) == "<!DOCTYPE html><html><body>x</body></html>");
+    assert!(Html::Empty.outer_html_with(&policy) == "<!DOCTYPE html>");
+
+    for input in [
+        "<!DOCTYPE html><html></html>",
+        "<!-- generated -->\n<!doctype html>\n<p>a</p>",
+        "<!DOCTYPE svg><svg></svg>",
+    ] {
+        let html = Html::parse(input).unwrap();
+        assert!(html.outer_html_with(&policy) == input);
+    }
+
+    let html = Html::parse("<div><p>a</p></div>").unwrap();
+    let policy = SerializePolicy::new().ensure_doctype(None);
+    assert!(html.outer_html_with(&policy) == "<div><p>a</p></div>");
+}
+
+#[test]
+fn trailing_newline() {
+    let html = Html::parse("<p>a</p>").unwrap();
+    let policy = SerializePolicy::new().trailing_newline(true);
+    assert!(html.outer_html_with(&policy) == "<p>a</p>\n");
+    assert!(Html::text("a\n").outer_html_with(&policy) == "a\n\n");
+
+    let policy = policy.ensure_doctype(Some("html".to_owned())).xhtml(true);
+    let html = Html::parse("<html><body><br></body></html>").unwrap();
+    assert!(html.outer_html_with(&policy) == "<!DOCTYPE html><html><body><br /></body></html>\n");
+}
+
+#[test]
+fn self_closing_slash() {
+    let html = Html::parse(r#"<p>a<br>b<img src="x"/><BR></p>"#).unwrap();
+    let policy = SerializePolicy::new().self_closing_slash(["br"]);
+    assert!(html.outer_html_with(&policy) == r#"<p>a<br/>b<img src="x"></img><BR/></p>"#);
+    assert!(html.outer_html() == r#"<p>a<br>b<img src="x"></img><BR></BR></p>"#);
+
+    let policy = policy.self_closing_slash(["img"]);
+    assert!(html.outer_html_with(&policy) == r#"<p>a<br>b<img src="x"/><BR></BR></p>"#);
+
+    let with_child = Html::from(Tag::builder("br").build()).with_child(Html::text("x"));
+    let policy = SerializePolicy::new().self_closing_slash(["br"]);
+    assert!(with_child.outer_html_with(&policy) == "<br>x</br>");
+
+    let svg = Html::parse("<svg><linearGradient></linearGradient><rect></rect></svg>").unwrap();
+    let policy = SerializePolicy::new().self_closing_slash(["lineargradient", "rect"]);
+    assert!(svg.outer_html_with(&policy) == "<svg><linearGradient></linearGradient><rect/></svg>");
+
+    let options = ParseOptions::new().preserve_source(true);
+    let (preserved, _) = Html::parse_with_warnings("<div ><br></div >", &options).unwrap();
+    let policy = SerializePolicy::new().self_closing_slash(["br"]);
+    assert!(preserved.outer_html_with(&policy) == "<div><br/></div>");
+}
@@ -0,0 +1,108 @@
+use html_filter::prelude::*;
+
+/// Returns the direct children of a node: tag names, or text content.
+fn children(html: &Html) -> Vec<String> {
+    html.children()
+        .map(|child| match child {
+            Html::Tag { tag, .. } => format!("<{}>", tag.as_name()),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+fn child(html: &Html, idx: usize) -> &Html {
+    html.children().nth(idx).unwrap()
+}
+
+#[test]
+fn text_after_closed_tag_then_open_tag() {
+    let tree = Html::parse("<div>a</div>b<span>").unwrap();
+    assert!(children(&tree) == ["<div>", "b", "<span>"]);
+    assert!(children(child(&tree, 0)) == ["a"]);
+    assert!(children(child(&tree, 2)).is_empty());
+}
+
+#[test]
+fn text_after_closed_tag() {
+    let tree = Html::parse("<div>a</div>b").unwrap();
+    assert!(children(&tree) == ["<div>", "b"]);
+
+    let tree = Html::parse("<div>a</div>b<span>c</span>d").unwrap();
+    assert!(children(&tree) == ["<div>", "b", "<span>", "d"]);
+    assert!(children(child(&tree, 2)) == ["c"]);
+}
+
+#[test]
+fn text_after_nested_closed_tag() {
+    let tree = Html::parse("<div><p><b>a</b>b</p>c</div>d").unwrap();
+    assert!(children(&tree) == ["<div>", "d"]);
+    let div = child(&tree, 0);
+    assert!(children(div) == ["<p>", "c"]);
+    assert!(children(child(div, 0)) == ["<b>", "b"]);
+
+    let tree = Html::parse("<ul><li>a</li>b<li>c</li>d</ul>e").unwrap();
+    assert!(children(&tree) == ["<ul>", "e"]);
+    assert!(children(child(&tree, 0)) == ["<li>", "b", "<li>", "d"]);
+}
+
+#[test]
+fn text_around_self_closing_tags() {
+    let tree = Html::parse("a<br>b<br/>c").unwrap();
+    assert!(children(&tree) == ["a", "<br>", "b", "<br>", "c"]);
+
+    let tree = Html::parse("<div><br>b<i>c</i></div>").unwrap();
+    assert!(children(&tree) == ["<br>", "b", "<i>"]);
+}
+
+#[test]
+fn text_around_comments() {
+    let tree = Html::parse("<!--x-->b<p></p>c").unwrap();
+    assert!(children(&tree) == ["<!--x-->", "b", "<p>", "c"]);
+}
+
+/// Checks that no list of nodes is directly inside another one.
+fn assert_flat(html: &Html) {
+    if let Html::Vec(vec) = html {
+        assert!(
+            !vec.iter()
+                .any(|node| matches!(node, Html::Vec(_) | Html::Empty))
+        );
+    }
+    html.children().for_each(assert_flat);
+}
+
+#[test]
+fn root_transitions_are_flat() {
+    for (input, expected) in [
+        ("a<b>x</b>c", &["a", "<b>", "c"][..]),
+        ("<b>x</b>c", &["<b>", "c"]),
+        ("a<br>b<br>c", &["a", "<br>", "b", "<br>", "c"]),
+        ("a<b>x</b>c<i>y</i>d", &["a", "<b>", "c", "<i>", "d"]),
+        ("a<!--x-->b<b></b>c", &["a", "<!--x-->", "b", "<b>", "c"]),
+        ("<p>a</p><p>b</p>c", &["<p>", "<p>", "c"]),
+    ] {
+        let tree = Html::parse(input).unwrap();
+        assert_flat(&tree);
+        assert!(children(&tree) == expected, "{input}: {:?}", children(&tree));
+        assert!(tree.to_string().replace("</br>", "") == input.replace("</br>", ""));
+    }
+}
+
+#[test]
+fn nested_transitions_are_flat() {
+    let tree = Html::parse("<div>a<b>x</b>c<i>y</i>d</div>e<span>f</span>g").unwrap();
+    assert_flat(&tree);
+    assert!(children(&tree) == ["<div>", "e", "<span>", "g"]);
+    assert!(children(child(&tree, 0)) == ["a", "<b>", "c", "<i>", "d"]);
+
+    let mut builder = TreeBuilder::new();
+    builder.push_text("a");
+    builder.push_tag(Tag::builder("b").build(), false);
+    builder.close_tag("b").unwrap();
+    builder.push_text("c");
+    builder.push_tag(Tag::builder("br").build(), true);
+    builder.push_text("d");
+    let tree = builder.build();
+    assert_flat(&tree);
+    assert!(children(&tree) == ["a", "<b>", "c", "<br>", "d"]);
+}
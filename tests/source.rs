@@ -0,0 +1,61 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<!DOCTYPE   html >
+<html  lang='en'>
+    <head >
+        <meta charset="utf-8" >
+        <title>Irregular</title >
+        <style  type='text/css'>p { color: red; }</style  >
+    </head>
+    <body	class="a  b" id='main'  >
+        <!--  comment  -->
+        <img src='a.png' alt="A"/>
+        <p>Some <b
+            >bold</b> text<br/><br>
+        </p >
+        <svg><rect  width='1' /></svg>
+    </body>
+</html   >
+"#;
+
+fn parse_preserved(html: &str) -> Html {
+    let options = ParseOptions::new().preserve_source(true);
+    Html::parse_with_warnings(html, &options).unwrap().0
+}
+
+#[test]
+fn roundtrip_identical() {
+    let tree = parse_preserved(HTML);
+    assert!(tree.to_string() == HTML);
+    assert!(tree.to_flat().to_string() == HTML);
+}
+
+#[test]
+fn normalised_by_default() {
+    let tree = Html::parse(HTML).unwrap();
+    let html = tree.to_string();
+    assert!(html != HTML);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<img src='a.png' alt=\"A\"></img>"));
+}
+
+#[test]
+fn filtered_keeps_source() {
+    let tree = parse_preserved(HTML);
+    let body = tree.to_found(&Filter::new().tag_name("p"));
+    assert!(body.to_string() == "<p>Some <b\n            >bold</b> text<br/><br>\n        </p >");
+}
+
+#[test]
+fn opening_tag_spacing() {
+    for input in [
+        "<div   class=\"x\"  >a</div>",
+        "<div\n\tclass = 'x'\n\tid='y'\n>a</div >",
+        "<input   disabled    value=\"v\"   />",
+        "<p  hidden >a<br   /><span\nid='s'\n  >b</span\n></p  >",
+    ] {
+        assert!(parse_preserved(input).to_string() == input);
+    }
+    let normalised = Html::parse("<div   class=\"x\"  id='y'  >a</div>").unwrap();
+    assert!(normalised.to_string() == "<div class=\"x\" id='y'>a</div>");
+}
@@ -0,0 +1,41 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<!DOCTYPE html>
+<!-- header -->
+<html lang="en">
+    <head>
+        <meta charset="utf-8">
+        <title>Title</title>
+    </head>
+    <body class="main" id="body">
+        <p>Some <b>bold <i>text</i></b></p>
+        <p hidden>Other<!-- inline --></p>
+    </body>
+</html>"#;
+
+#[test]
+fn known_document() {
+    let stats = Html::parse(HTML).unwrap().stats();
+    assert!(stats.tag_count("html") == 1);
+    assert!(stats.tag_count("p") == 2);
+    assert!(stats.tag_count("b") == 1);
+    assert!(stats.tag_count("span") == 0);
+    assert!(stats.tag_counts().len() == 8);
+    assert!(stats.total_tag_count() == 9);
+    assert!(stats.attribute_count() == 5);
+    assert!(stats.comment_count() == 2);
+    // html, body, p, b, i, text
+    assert!(stats.max_depth() == 6);
+    // "Title", "Some ", "bold ", "text", "Other" and the whitespaces
+    assert!(stats.text_count() == 5 + 11);
+}
+
+#[test]
+fn small_trees() {
+    assert!(Html::new().stats() == TreeStats::default());
+    assert!(Html::new().stats().max_depth() == 0);
+    let stats = Html::parse("text").unwrap().stats();
+    assert!(stats.max_depth() == 1 && stats.text_count() == 1);
+    let stats = Html::parse("<br><br>").unwrap().stats();
+    assert!(stats.max_depth() == 1 && stats.tag_count("br") == 2);
+}
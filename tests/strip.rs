@@ -0,0 +1,61 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<!-- header -->
+<html>
+    <head>
+        <script src="app.js"></script>
+        <title>Title</title>
+    </head>
+    <body>
+        <!-- main content -->
+        <p>Some <!-- inline -->text</p>
+        <script>
+            console.log("<p>");
+        </script>
+        <div><script></script><!-- last --></div>
+    </body>
+</html>"#;
+
+#[test]
+fn strip_comments() {
+    let mut tree = Html::parse(HTML).unwrap();
+    tree.strip_comments();
+    let html = tree.to_string();
+    assert!(!html.contains("<!--"));
+    assert!(html.contains("<p>Some text</p>"));
+    assert!(html.contains("<title>Title</title>"));
+    assert!(html.matches("<script").count() == 3);
+}
+
+#[test]
+fn strip_scripts() {
+    let mut tree = Html::parse(HTML).unwrap();
+    tree.strip_elements(&["script"]);
+    let html = tree.to_string();
+    assert!(!html.contains("script") && !html.contains("console"));
+    assert!(html.contains("<p>Some <!-- inline -->text</p>"));
+    assert!(html.contains("<title>Title</title>"));
+    assert!(html.contains("<div><!-- last --></div>"));
+}
+
+#[test]
+fn strip_uppercase() {
+    let mut tree = Html::parse("<p>a<SCRIPT>alert(1)</SCRIPT>b</p>").unwrap();
+    tree.strip_elements(&["script"]);
+    assert!(tree.to_string() == "<p>ab</p>");
+
+    let mut tree = Html::parse("<svg><linearGradient /><lineargradient /></svg>").unwrap();
+    tree.strip_elements(&["linearGradient"]);
+    assert!(tree.to_string() == "<svg><lineargradient></lineargradient></svg>");
+}
+
+#[test]
+fn strip_root() {
+    let mut tree = Html::parse("<!-- only -->").unwrap();
+    tree.strip_comments();
+    assert!(matches!(tree, Html::Empty));
+
+    let mut tree = Html::parse("<script></script>text").unwrap();
+    tree.strip_elements(&["style", "script"]);
+    assert!(matches!(&tree, Html::Text(text) if text == "text"));
+}
@@ -0,0 +1,43 @@
+use html_filter::prelude::*;
+
+fn styles(html: &str) -> Vec<(String, String)> {
+    Html::parse(html).unwrap().inline_styles()
+}
+
+fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+    expected
+        .iter()
+        .map(|(property, value)| ((*property).to_owned(), (*value).to_owned()))
+        .collect()
+}
+
+#[test]
+fn trailing_semicolon() {
+    assert!(styles(r#"<p style="color:red;">"#) == pairs(&[("color", "red")]));
+    assert!(styles(r#"<p style="color:red">"#) == pairs(&[("color", "red")]));
+    assert!(styles(r#"<p style="a:b;;c:d;">"#) == pairs(&[("a", "b"), ("c", "d")]));
+}
+
+#[test]
+fn whitespace() {
+    assert!(
+        styles(r#"<p style="  color :  red ;margin: 0 auto ">"#)
+            == pairs(&[("color", "red"), ("margin", "0 auto")])
+    );
+}
+
+#[test]
+fn invalid_declarations() {
+    assert!(styles(r#"<p style="color red; margin: 0; bold">"#) == pairs(&[("margin", "0")]));
+    assert!(
+        styles(r#"<p style="background: url(http://a.b/c.png)">"#)
+            == pairs(&[("background", "url(http://a.b/c.png)")])
+    );
+}
+
+#[test]
+fn missing_style() {
+    assert!(styles("<p id='a'>").is_empty());
+    assert!(styles("<p style>").is_empty());
+    assert!(styles("text").is_empty());
+}
@@ -0,0 +1,67 @@
+use html_filter::prelude::*;
+
+fn normalized(html: &str) -> String {
+    let options = ParseOptions::new().normalize_tables(true);
+    Html::parse_with_warnings(html, &options)
+        .unwrap()
+        .0
+        .to_string()
+}
+
+#[test]
+fn multi_cell_row() {
+    let tree = Html::parse("<table><tr><td>a<td>b</tr></table>").unwrap();
+    assert!(tree.to_string() == "<table><tr><td>a</td><td>b</td></tr></table>");
+    let row = tree.select("tr").unwrap()[0];
+    assert!(row.children().count() == 2);
+}
+
+#[test]
+fn rows_and_headers() {
+    let tree = Html::parse("<table><tr><th>h1<th>h2<tr><td>a<td>b</table>").unwrap();
+    assert!(
+        tree.to_string()
+            == "<table><tr><th>h1</th><th>h2</th></tr><tr><td>a</td><td>b</td></tr></table>"
+    );
+    assert!(tree.select("table > tr").unwrap().len() == 2);
+}
+
+#[test]
+fn sections() {
+    let tree = Html::parse("<table><thead><tr><th>h<tbody><tr><td>a</table>").unwrap();
+    assert!(
+        tree.to_string()
+            == "<table><thead><tr><th>h</th></tr></thead><tbody><tr><td>a</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn nested_tables() {
+    let html = "<table><tr><td><table><tr><td>a<td>b</table><td>c</table>";
+    assert!(
+        Html::parse(html).unwrap().to_string()
+            == "<table><tr><td><table><tr><td>a</td><td>b</td></tr></table></td><td>c</td></tr></table>"
+    );
+}
+
+#[test]
+fn implicit_tbody() {
+    assert!(
+        normalized("<table><tr><td>a</td></tr><tr><td>b</td></tr></table>")
+            == "<table><tbody><tr><td>a</td></tr><tr><td>b</td></tr></tbody></table>"
+    );
+    let explicit =
+        "<table><thead><tr><th>h</th></tr></thead><tbody><tr><td>a</td></tr></tbody></table>";
+    assert!(normalized(explicit) == explicit);
+}
+
+#[test]
+fn cells_outside_tables() {
+    assert!(Html::parse("<td>a<td>b").unwrap().to_string() == "<td>a</td><td>b</td>");
+    assert!(
+        Html::parse("<svg><td>a<td>b</td></td></svg>")
+            .unwrap()
+            .to_string()
+            == "<svg><td>a<td>b</td></td></svg>"
+    );
+}
@@ -0,0 +1,29 @@
+use html_filter::prelude::*;
+
+#[test]
+fn link() {
+    let tag = Tag::builder("a").attr("href", "/").class("btn").build();
+    assert!(Html::from(tag).to_string() == r#"<a href="/" class="btn"></a>"#);
+}
+
+#[test]
+fn insertion_order() {
+    let tag = Tag::builder("input")
+        .bool_attr("required")
+        .attr("name", "email")
+        .class("field")
+        .attr("type", "email")
+        .class("wide")
+        .attr("name", "mail")
+        .build();
+    assert!(tag.to_string() == r#"input required name="mail" class="field wide" type="email""#);
+    assert!(tag.find_attr_value("name").unwrap() == "mail");
+}
+
+#[test]
+fn with_html_constructors() {
+    let html = Html::from(Tag::builder("ul").class("menu").build())
+        .with_child(Html::from(Tag::builder("li").build()).with_child(Html::text("Home")));
+    assert!(html.to_string() == r#"<ul class="menu"><li>Home</li></ul>"#);
+    assert!(html == Html::parse(r#"<ul class="menu"><li>Home</li></ul>"#).unwrap());
+}
@@ -0,0 +1,58 @@
+use html_filter::prelude::*;
+
+fn name_of(html: &Html) -> &str {
+    match html {
+        Html::Tag { tag, .. } => tag.as_name(),
+        _ => panic!("expected a tag"),
+    }
+}
+
+#[test]
+fn custom_elements() {
+    let html = Html::parse("<my-component>x</my-component>").unwrap();
+    assert!(name_of(&html) == "my-component");
+    assert!(html.to_string() == "<my-component>x</my-component>");
+
+    let html = Html::parse("<ng-template><p>a</p></ng-template>").unwrap();
+    assert!(name_of(&html) == "ng-template");
+    assert!(html.to_string() == "<ng-template><p>a</p></ng-template>");
+}
+
+#[test]
+fn uncommon_characters() {
+    for name in ["v:button", "x.item", "my_tag", "h7", "a-1.b_c:d"] {
+        let input = format!("<{name} id='a'>x</{name}>");
+        let html = Html::parse(&input).unwrap();
+        assert!(name_of(&html) == name);
+        assert!(html.to_string() == input);
+    }
+}
+
+#[test]
+fn leading_digit() {
+    let html = Html::parse("<1bad>").unwrap();
+    assert!(html == Html::text("<1bad>"));
+
+    let html = Html::parse("<p>1 <2 and 3 <4</p>").unwrap();
+    assert!(html.text_content(false) == "1 <2 and 3 <4");
+    assert!(html.to_string() == "<p>1 <2 and 3 <4</p>");
+}
+
+#[test]
+fn stray_lower_than() {
+    let html = Html::parse("a <= b<<b>c</b>").unwrap();
+    assert!(html.to_string() == "a <= b<<b>c</b>");
+    assert!(html.text_content(false) == "a <= b<c");
+}
+
+#[test]
+fn invalid_characters() {
+    assert!(matches!(
+        Html::parse("<a$b>"),
+        Err(ParseError::InvalidCharacter { character: '$', .. })
+    ));
+    assert!(matches!(
+        Html::parse("</1bad>"),
+        Err(ParseError::InvalidCharacter { character: '1', .. })
+    ));
+}
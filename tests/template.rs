@@ -0,0 +1,34 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<ul id="list"><li>A</li></ul><template id="row"><li class="item"><a href="/item">Item</a></li></template>"#;
+
+#[test]
+fn content_is_parsed() {
+    let tree = Html::parse(HTML).unwrap();
+    assert!(tree.to_string() == HTML);
+    let template = tree.select("template").unwrap()[0];
+    assert!(template.children().count() == 1);
+    assert!(tree.select("template > li.item > a").unwrap().len() == 1);
+}
+
+#[test]
+fn text_content() {
+    let tree = Html::parse(HTML).unwrap();
+    assert!(tree.text_content(false) == "A");
+    assert!(tree.text_content(true) == "AItem");
+
+    let nested = Html::parse("<template>a<template>b</template></template>c").unwrap();
+    assert!(nested.text_content(false) == "c");
+    assert!(nested.text_content(true) == "abc");
+
+    let upper = Html::parse("a<TEMPLATE>b</TEMPLATE>").unwrap();
+    assert!(upper.text_content(false) == "a");
+    assert!(upper.text_content(true) == "ab");
+}
+
+#[test]
+fn other_nodes_are_skipped() {
+    let tree = Html::parse("<!DOCTYPE html><p>a<!-- b -->c<br>d</p>").unwrap();
+    assert!(tree.text_content(false) == "acd");
+    assert!(Html::new().text_content(true).is_empty());
+}
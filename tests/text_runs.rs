@@ -0,0 +1,59 @@
+use html_filter::prelude::*;
+
+fn parse(html: &str, split: bool, decode: bool) -> Html {
+    let options = ParseOptions::new()
+        .lenient(true)
+        .split_text_runs(split)
+        .decode_text_entities(decode);
+    Html::parse_with_warnings(html, &options).unwrap().0
+}
+
+fn texts(html: &Html) -> Vec<&str> {
+    html.children()
+        .map(|node| match node {
+            Html::Text(text) => text.as_str(),
+            _ => panic!("expected a text node, found {node:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn merged_by_default() {
+    let html = parse("<p>ab&amp;cd</p>", false, false);
+    assert!(texts(&html) == ["ab&amp;cd"]);
+    assert!(html == Html::parse("<p>ab&amp;cd</p>").unwrap());
+
+    let html = parse("<p>ab&amp;cd</p>", false, true);
+    assert!(texts(&html) == ["ab&cd"]);
+}
+
+#[test]
+fn split_runs() {
+    let html = parse("<p>ab&amp;cd</p>", true, false);
+    assert!(texts(&html) == ["ab", "&amp;", "cd"]);
+    assert!(html.to_string() == "<p>ab&amp;cd</p>");
+
+    let html = parse("<p>ab&amp;cd</p>", true, true);
+    assert!(texts(&html) == ["ab", "&", "cd"]);
+}
+
+#[test]
+fn invalid_references_stay_in_their_run() {
+    let html = parse("<p>a & b &unknown; &lt;&gt;</p>", true, false);
+    assert!(texts(&html) == ["a & b &unknown; ", "&lt;", "&gt;"]);
+}
+
+#[test]
+fn ignored_tokens_separate_runs() {
+    let html = parse("<p>a</span>b</p>", true, false);
+    assert!(texts(&html) == ["a", "b"]);
+
+    let html = parse("<p>a</span>b</p>", false, false);
+    assert!(texts(&html) == ["ab"]);
+}
+
+#[test]
+fn raw_text_not_split() {
+    let html = parse("<script>a &amp; b</script>", true, true);
+    assert!(texts(&html) == ["a &amp; b"]);
+}
@@ -0,0 +1,80 @@
+use html_filter::prelude::*;
+
+fn tokenize(html: &str) -> Vec<Token> {
+    Tokenizer::new(html).collect::<Result<_, _>>().unwrap()
+}
+
+fn text(content: &str) -> Token {
+    Token::Text(content.to_owned())
+}
+
+fn close(name: &str) -> Token {
+    Token::TagClose(name.to_owned())
+}
+
+fn open(token: &Token, expected: &str, closing: bool) -> bool {
+    matches!(token, Token::TagOpen { name, self_closing, .. } if name == expected && *self_closing == closing)
+}
+
+fn attr(token: &Token, expected: &str, expected_value: Option<&str>) -> bool {
+    matches!(token, Token::Attribute { name, value, .. } if name == expected && value.as_deref() == expected_value)
+}
+
+#[test]
+fn small_document() {
+    let tokens = tokenize(
+        "<!DOCTYPE html><html lang='en'><!-- c --><body><p>Hi <b>there</b><br/></p><script>if (a<b) {}</script></body></html>",
+    );
+    assert!(
+        matches!(&tokens[0], Token::Doctype { name, attr, .. } if name == "DOCTYPE" && attr.as_deref() == Some("html"))
+    );
+    assert!(open(&tokens[1], "html", false));
+    assert!(attr(&tokens[2], "lang", Some("en")));
+    assert!(tokens[3] == Token::Comment(" c ".to_owned()));
+    assert!(open(&tokens[4], "body", false));
+    assert!(open(&tokens[5], "p", false));
+    assert!(tokens[6] == text("Hi "));
+    assert!(open(&tokens[7], "b", false));
+    assert!(tokens[8..10] == [text("there"), close("b")]);
+    assert!(open(&tokens[10], "br", true));
+    assert!(tokens[11] == close("p"));
+    assert!(open(&tokens[12], "script", false));
+    assert!(
+        tokens[13..]
+            == [
+                text("if (a<b) {}"),
+                close("script"),
+                close("body"),
+                close("html")
+            ]
+    );
+}
+
+#[test]
+fn attributes() {
+    let tokens = tokenize(r#"<input type="checkbox" checked/>"#);
+    assert!(tokens.len() == 3);
+    assert!(open(&tokens[0], "input", true));
+    assert!(attr(&tokens[1], "type", Some("checkbox")));
+    assert!(attr(&tokens[2], "checked", None));
+}
+
+#[test]
+fn ignored_self_closing_slash() {
+    let tokens = tokenize("<div/><script/>a<b></script><IMG/><svg><rect/></svg>");
+    assert!(open(&tokens[0], "div", false));
+    assert!(open(&tokens[1], "script", false));
+    assert!(tokens[2..4] == [text("a<b>"), close("script")]);
+    assert!(open(&tokens[4], "IMG", true));
+    assert!(open(&tokens[5], "svg", false));
+    assert!(open(&tokens[6], "rect", true));
+}
+
+#[test]
+fn malformed_input() {
+    assert!(tokenize("</p><div>").len() == 2);
+    let mut tokenizer = Tokenizer::new("text</a b='c'>more");
+    assert!(tokenizer.next() == Some(Ok(text("text"))));
+    assert!(tokenizer.next() == Some(Err(ParseError::ClosingTagAttributes)));
+    assert!(tokenizer.next().is_none());
+}
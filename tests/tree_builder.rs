@@ -0,0 +1,91 @@
+use html_filter::prelude::*;
+
+#[test]
+fn full_document() {
+    let mut builder = TreeBuilder::new();
+    builder.push_tag(Tag::builder("html").build(), false);
+    builder.push_tag(Tag::builder("body").build(), false);
+    builder.push_comment(" content ");
+    builder.push_tag(Tag::builder("div").attr("id", "main").build(), false);
+    builder.push_text("Hello, ");
+    builder.push_tag(Tag::builder("b").build(), false);
+    builder.push_text("world");
+    builder.close_tag("b").unwrap();
+    builder.push_text("!");
+    builder.push_tag(Tag::builder("br").build(), true);
+    builder.open_comment();
+    builder.push_text(" note ");
+    builder.close_comment().unwrap();
+    assert!(
+        builder
+            .opened_tags()
+            .iter()
+            .map(|tag| tag.as_name().as_str())
+            .collect::<Vec<_>>()
+            == ["html", "body", "div"]
+    );
+    builder.close_tag("div").unwrap();
+    builder.close_tag("body").unwrap();
+    builder.close_tag("html").unwrap();
+    assert!(builder.opened_tags().is_empty());
+
+    let tree = builder.build();
+    let expected = Html::parse(
+        r#"<html><body><!-- content --><div id="main">Hello, <b>world</b>!<br><!-- note --></div></body></html>"#,
+    )
+    .unwrap();
+    assert!(tree == expected);
+    assert!(tree.to_string() == expected.to_string());
+}
+
+#[test]
+fn unmatched_closing_tag() {
+    let mut builder = TreeBuilder::new();
+    builder.push_tag(Tag::builder("p").build(), false);
+    builder.push_text("a");
+    let err = builder.close_tag("div").unwrap_err();
+    assert!(matches!(&err, ParseError::UnmatchedClosingTag { name, position, .. }
+        if name == "div" && position.line() == 1 && position.column() == 5));
+    builder.close_tag("p").unwrap();
+    assert!(builder.close_tag("p").is_err());
+    assert!(builder.build().to_string() == "<p>a</p>");
+}
+
+#[test]
+fn closing_outer_tag() {
+    let mut builder = TreeBuilder::new();
+    builder.push_tag(Tag::builder("ul").build(), false);
+    builder.push_tag(Tag::builder("li").build(), false);
+    builder.push_text("a");
+    builder.close_tag("ul").unwrap();
+    builder.push_text("b");
+    let tree = builder.build();
+    assert!(tree.to_string() == "<ul><li>a</li></ul>b");
+}
+
+#[test]
+fn comments() {
+    let mut builder = TreeBuilder::new();
+    assert!(builder.close_comment() == Err(ParseError::UnopenedComment));
+    builder.open_comment();
+    builder.push_text("a");
+    builder.push_tag(Tag::builder("p").build(), false);
+    assert!(builder.close_comment().is_err());
+    builder.open_comment();
+    builder.push_text("b");
+    assert!(builder.build().to_string() == "<!--a--><p><!--b--></p>");
+}
+
+#[test]
+fn unclosed_tags() {
+    let mut builder = TreeBuilder::new();
+    builder.push_tag(Tag::builder("div").build(), false);
+    builder.push_text("a");
+    let tree = builder.build();
+    assert!(tree.to_string() == "<div>a</div>");
+    let Html::Tag { tag, .. } = &tree else {
+        panic!("expected a tag");
+    };
+    assert!(tag.is_implicitly_closed());
+    assert!(TreeBuilder::new().build().is_empty());
+}
@@ -0,0 +1,54 @@
+use html_filter::prelude::*;
+
+fn tag(html: &str) -> Tag {
+    match Html::parse(html).unwrap() {
+        Html::Tag { tag, .. } => tag,
+        other => panic!("{html:?} gave {other:?}"),
+    }
+}
+
+#[test]
+fn integers() {
+    let tag = tag(r#"<td colspan="3" rowspan=" -2 " width="10px" height="2.5" data-n>"#);
+    assert!(tag.get_int("colspan") == Some(3));
+    assert!(tag.get_int("ROWSPAN") == Some(-2));
+    assert!(tag.get_int("width").is_none());
+    assert!(tag.get_int("height").is_none());
+    assert!(tag.get_int("data-n").is_none());
+    assert!(tag.get_int("missing").is_none());
+}
+
+#[test]
+fn floats() {
+    let tag = tag(r#"<meter value="0.25" min="-1" max="1e2" low="high" high>"#);
+    assert!(tag.get_float("value") == Some(0.25));
+    assert!(tag.get_float("min") == Some(-1.0));
+    assert!(tag.get_float("max") == Some(100.0));
+    assert!(tag.get_float("low").is_none());
+    assert!(tag.get_float("high").is_none());
+    assert!(tag.get_float("optimum").is_none());
+}
+
+#[test]
+fn booleans() {
+    let tag = tag(
+        r#"<input disabled checked="checked" required="" draggable="TRUE" spellcheck=" false " autocomplete="off">"#,
+    );
+    for name in ["disabled", "checked", "required", "draggable"] {
+        assert!(tag.get_bool(name) == Some(true), "{name}");
+    }
+    assert!(tag.get_bool("spellcheck") == Some(false));
+    assert!(tag.get_bool("autocomplete").is_none());
+    assert!(tag.get_bool("hidden").is_none());
+}
+
+#[test]
+fn built_tags() {
+    let tag = Tag::builder("progress")
+        .attr("value", "7")
+        .attr("max", "10.5")
+        .build();
+    assert!(tag.get_int("value") == Some(7));
+    assert!(tag.get_float("value") == Some(7.0));
+    assert!(tag.get_float("max") == Some(10.5));
+}
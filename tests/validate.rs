@@ -0,0 +1,90 @@
+use html_filter::prelude::*;
+
+fn tag(name: &str) -> Html {
+    Html::from(Tag::builder(name).build())
+}
+
+#[test]
+fn parsed_trees() {
+    for html in [
+        "",
+        "<!DOCTYPE html><html><body><p>a<br>b<!-- c --></p></body></html>",
+        "<svg><svg:rect/></svg><img src='a.png'/>",
+        include_str!("data/index.html"),
+    ] {
+        assert!(Html::parse(html).unwrap().validate().is_ok());
+    }
+}
+
+#[test]
+fn void_element_children() {
+    let html = Html::Vec(Box::new([Html::text("a"), tag("img").with_child(Html::text("x"))]));
+    let errors = html.validate().unwrap_err();
+    assert!(errors.len() == 1);
+    assert!(matches!(
+        &errors[0],
+        ValidationError::VoidElementChildren { name, path, .. } if name == "img" && path == &[1]
+    ));
+}
+
+#[test]
+fn invalid_comments() {
+    let html = tag("div")
+        .with_child(Html::comment("a --> b"))
+        .with_child(Html::comment("c-"))
+        .with_child(Html::comment("d-->"));
+    let errors = html.validate().unwrap_err();
+    assert!(errors.len() == 2);
+    assert!(matches!(&errors[0], ValidationError::InvalidComment { path, .. } if path == &[0, 0]));
+    assert!(matches!(&errors[1], ValidationError::InvalidComment { path, .. } if path == &[0, 2]));
+    assert!(Html::comment("- a -").validate().is_ok());
+}
+
+#[test]
+fn invalid_names() {
+    let errors = tag("my tag").validate().unwrap_err();
+    assert!(matches!(&errors[0], ValidationError::InvalidTagName { name, .. } if name == "my tag"));
+    assert!(tag("a/b").validate().is_err());
+    assert!(tag("1bad").validate().is_err());
+    assert!(tag("a$b").validate().is_err());
+    assert!(tag("x:y").validate().is_ok());
+    assert!(tag("my-component.v_2").validate().is_ok());
+    let tree = Html::parse("<svg></svg>").unwrap();
+    let Html::Tag { tag: svg, .. } = &tree else {
+        unreachable!()
+    };
+    assert!(svg.as_namespace() == Namespace::Svg);
+
+    let html = Html::from(
+        Tag::builder("a")
+            .attr("href x", "/")
+            .bool_attr("b=c")
+            .build(),
+    );
+    let errors = html.validate().unwrap_err();
+    assert!(errors.len() == 2);
+    assert!(errors[0].to_string() == "Tag at [0] has an invalid attribute name 'href x'.");
+    assert!(errors[1].to_string() == "Tag at [0] has an invalid attribute name 'b=c'.");
+}
+
+#[test]
+fn nested_doctype() {
+    let doctype = Html::parse("<!DOCTYPE html>").unwrap();
+    assert!(doctype.validate().is_ok());
+    let html = tag("div").with_child(Html::text("a")).with_child(doctype);
+    let errors = html.validate().unwrap_err();
+    assert!(errors.len() == 1);
+    assert!(errors[0].to_string() == "Doctype at [0, 1] is inside a tag.");
+}
+
+#[test]
+fn all_errors() {
+    let html = tag("br")
+        .with_child(Html::comment("-->"))
+        .with_child(tag("p q"));
+    let errors = html.validate().unwrap_err();
+    assert!(errors.len() == 3);
+    assert!(matches!(errors[0], ValidationError::VoidElementChildren { .. }));
+    assert!(matches!(errors[1], ValidationError::InvalidComment { .. }));
+    assert!(matches!(errors[2], ValidationError::InvalidTagName { .. }));
+}
@@ -0,0 +1,170 @@
+use html_filter::prelude::*;
+
+#[test]
+fn mismatched_lenient() {
+    let html = "<div>\n    <span>text</p></span>\n</div>";
+    assert!(Html::parse(html).is_err());
+    let options = ParseOptions::new().lenient(true);
+    let (tree, warnings) = Html::parse_with_warnings(html, &options).unwrap();
+    assert!(tree.to_string() == "<div>\n    <span>text</span>\n</div>");
+    if let [ParseWarning::UnmatchedClosingTag { name, position, .. }] = warnings.as_slice() {
+        assert!(name == "p");
+        assert!(position.line() == 2 && position.column() == 15);
+        assert!(position.offset() == 20);
+    } else {
+        panic!("Expected one warning, found {warnings:?}");
+    }
+}
+
+#[test]
+fn mismatched_strict() {
+    let html = "<div></p></div>";
+    let options = ParseOptions::new();
+    assert!(
+        Html::parse_with_warnings(html, &options)
+            .unwrap_err()
+            .to_string()
+            == "Invalid closing tag: Found closing tag for 'p' but it isn't open at line 1, column 6."
+    );
+}
+
+#[test]
+fn mismatched_position_multiline() {
+    let html = "<div>\n  <p>text</p>\n  <ul>\n    <li>a</span></li>\n  </ul>\n</div>";
+    let err = Html::parse(html).unwrap_err();
+    if let ParseError::UnmatchedClosingTag { name, position, .. } = &err {
+        assert!(name == "span");
+        assert!(position.line() == 4);
+        assert!(position.column() == 10);
+        assert!(&html[position.offset()..position.offset() + 7] == "</span>");
+    } else {
+        panic!("Expected an unmatched closing tag, found {err:?}");
+    }
+    assert!(
+        err.to_string()
+            == "Invalid closing tag: Found closing tag for 'span' but it isn't open at line 4, column 10."
+    );
+}
+
+#[test]
+fn duplicate_attribute() {
+    let (tree, warnings) =
+        Html::parse_with_warnings("<p>é<a id='x' id=\"y\">", &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == "<p>é<a id='x' id=\"y\"></a></p>");
+    assert!(warnings.len() == 1);
+    let warning = warnings.first().unwrap();
+    assert!(warning.position().column() == 5);
+    assert!(warning.to_string() == "Duplicate attribute 'id' at line 1, column 5.");
+}
+
+#[test]
+fn no_warnings() {
+    let options = ParseOptions::new().lenient(true);
+    let (_, warnings) = Html::parse_with_warnings("<div><p>x</p></div>", &options).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn unterminated_attribute_lenient() {
+    let options = ParseOptions::new().lenient(true);
+    let html = "<p>See <a class='link' href=\"page";
+    let (tree, warnings) = Html::parse_with_warnings(html, &options).unwrap();
+    assert!(tree.to_string() == "<p>See <a class='link' href=\"page\"></a></p>");
+    let link = tree.to_found(&Filter::new().tag_name("a"));
+    assert!(
+        matches!(link, Html::Tag { tag, .. } if tag.find_attr_value("href").unwrap() == "page")
+    );
+    if let [ParseWarning::UnterminatedAttribute { name, position, .. }] = warnings.as_slice() {
+        assert!(name == "href" && position.column() == 8);
+    } else {
+        panic!("Expected one warning, found {warnings:?}");
+    }
+}
+
+#[test]
+fn unterminated_attribute_strict() {
+    let err = Html::parse("<p>See <a href='pa>ge").unwrap_err();
+    assert!(matches!(&err, ParseError::UnterminatedAttribute { name, .. } if name == "href"));
+    assert!(err.to_string() == "EOF: Missing closing quote for the value of the attribute 'href'.");
+}
+
+#[test]
+fn empty_closing_tag() {
+    let (tree, warnings) =
+        Html::parse_with_warnings("<div></>text</div>", &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == "<div>text</div>");
+    if let [ParseWarning::EmptyClosingTag { position, .. }] = warnings.as_slice() {
+        assert!(position.column() == 6);
+    } else {
+        panic!("Expected one warning, found {warnings:?}");
+    }
+    assert!(warnings[0].to_string() == "Ignored empty closing tag '</>' at line 1, column 6.");
+}
+
+#[test]
+fn empty_closing_tag_at_root() {
+    let (tree, warnings) = Html::parse_with_warnings("</>a</>", &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == "a");
+    assert!(warnings.len() == 2);
+}
+
+#[test]
+fn late_doctype() {
+    let html = "<p>x</p>\n<!doctype html>";
+    let (tree, warnings) = Html::parse_with_warnings(html, &ParseOptions::new()).unwrap();
+    assert!(tree.to_string() == html);
+    if let [ParseWarning::MisplacedDoctype { position, .. }] = warnings.as_slice() {
+        assert!(position.line() == 2);
+        assert!(position.column() == 1);
+    } else {
+        panic!("Expected one warning, found {warnings:?}");
+    }
+    assert!(
+        warnings[0].to_string()
+            == "Misplaced doctype at line 2, column 1: it must precede the content."
+    );
+}
+
+#[test]
+fn nested_and_repeated_doctypes() {
+    let options = ParseOptions::new();
+    let (_, warnings) = Html::parse_with_warnings("<div><!DOCTYPE html></div>", &options).unwrap();
+    assert!(matches!(warnings.as_slice(), [ParseWarning::MisplacedDoctype { .. }]));
+
+    let (_, warnings) =
+        Html::parse_with_warnings("<!DOCTYPE html><!DOCTYPE html>", &options).unwrap();
+    assert!(warnings.len() == 1);
+
+    let (_, warnings) = Html::parse_with_warnings("text<!DOCTYPE html>", &options).unwrap();
+    assert!(warnings.len() == 1);
+}
+
+#[test]
+fn leading_doctype() {
+    let options = ParseOptions::new();
+    for html in [
+        "<!DOCTYPE html><html></html>",
+        "\n  <!DOCTYPE html>\n<p>x</p>",
+        "<!-- generated --><!doctype html><p>x</p>",
+    ] {
+        let (_, warnings) = Html::parse_with_warnings(html, &options).unwrap();
+        assert!(warnings.is_empty(), "{html}: {warnings:?}");
+    }
+}
+
+#[test]
+fn successive_positions() {
+    let html = "<p>é</b></i>\n<div>ü\n  </span>ö</em>\n</div>";
+    let options = ParseOptions::new().lenient(true);
+    let (_, warnings) = Html::parse_with_warnings(html, &options).unwrap();
+    let positions = warnings
+        .iter()
+        .map(|warning| (warning.position().line(), warning.position().column()))
+        .collect::<Vec<_>>();
+    assert!(positions == [(1, 5), (1, 9), (3, 3), (3, 11)]);
+    for warning in &warnings {
+        let position = warning.position();
+        let expected = html[..position.offset()].matches('\n').count() + 1;
+        assert!(position.line() == expected);
+    }
+}
@@ -0,0 +1,72 @@
+use html_filter::prelude::*;
+
+fn trim(html: &str) -> Html {
+    let mut tree = Html::parse(html).unwrap();
+    tree.trim_whitespace_nodes();
+    tree
+}
+
+#[test]
+fn whitespace_only_nodes_are_removed() {
+    let tree = trim("<div>\n    <p>a</p>\n\t<p>b</p>\r\n</div>\n");
+    assert!(tree.to_string() == "<div><p>a</p><p>b</p></div>");
+    assert!(tree.stats().text_count() == 2);
+}
+
+#[test]
+fn other_text_is_kept() {
+    let tree = trim("<p> a </p> <p>\n b\n</p>");
+    assert!(tree.to_string() == "<p> a </p><p>\n b\n</p>");
+}
+
+#[test]
+fn whitespace_sensitive_elements() {
+    let html = "<pre> </pre><textarea>\n</textarea><div><pre>\n  <b>x</b>\n</pre></div>";
+    assert!(trim(html).to_string() == html);
+    let html = "<PRE>\n  <b>x</b>\n</PRE>";
+    assert!(trim(html).to_string() == html);
+}
+
+#[test]
+fn root() {
+    assert!(trim(" \n ").is_empty());
+    assert!(trim(" <br> ").to_string() == "<br>");
+}
+
+fn collapse(html: &str) -> Html {
+    let mut tree = Html::parse(html).unwrap();
+    tree.collapse_text_whitespace();
+    tree
+}
+
+#[test]
+fn collapse_runs() {
+    let tree = collapse("<div>\n    <p>a \t b</p>\n\t<p> b\r\nc </p>\n</div>\n");
+    assert!(tree.to_string() == "<div> <p>a b</p> <p> b c </p> </div> ");
+    assert!(tree.stats().text_count() == 6);
+}
+
+#[test]
+fn collapse_differs_from_trim() {
+    let html = "<ul>\n  <li>a\n  b</li>\n  <li> c </li>\n</ul>";
+    assert!(collapse(html).to_string() == "<ul> <li>a b</li> <li> c </li> </ul>");
+    assert!(trim(html).to_string() == "<ul><li>a\n  b</li><li> c </li></ul>");
+
+    let policy = SerializePolicy::new().trim_whitespace(true);
+    let tree = Html::parse(html).unwrap();
+    assert!(tree.outer_html_with(&policy) == trim(html).to_string());
+
+    let mut both = collapse(html);
+    both.trim_whitespace_nodes();
+    assert!(both.to_string() == "<ul><li>a b</li><li> c </li></ul>");
+}
+
+#[test]
+fn collapse_keeps_sensitive_elements() {
+    let html = "<pre>a  b\n</pre><textarea>\n\n</textarea><script>if (a)  {}</script>";
+    assert!(collapse(html).to_string() == html);
+    let html = "<PRE>x   y</PRE><TextArea>\n\n</TextArea>";
+    assert!(collapse(html).to_string() == html);
+    let tree = collapse("<p title='a  b'>a  <!--  c  --></p>");
+    assert!(tree.to_string() == "<p title='a  b'>a <!--  c  --></p>");
+}
@@ -0,0 +1,78 @@
+use html_filter::prelude::*;
+
+#[test]
+fn wrap_text() {
+    let mut html = Html::text("Hello");
+    html.wrap(Tag::builder("b").build());
+    assert!(html.to_string() == "<b>Hello</b>");
+    html.wrap(Tag::builder("p").class("intro").build());
+    assert!(html.to_string() == r#"<p class="intro"><b>Hello</b></p>"#);
+}
+
+#[test]
+fn wrap_in_tree() {
+    let mut html = Html::parse("<p>a<i>b</i>c</p>").unwrap();
+    for child in html.children_mut() {
+        if matches!(child, Html::Text(_)) {
+            child.wrap(Tag::builder("span").build());
+        }
+    }
+    assert!(html.to_string() == "<p><span>a</span><i>b</i><span>c</span></p>");
+}
+
+#[test]
+fn unwrap_span() {
+    let mut html = Html::parse("<span class='x'>a <b>b</b></span>").unwrap();
+    html.unwrap_tag();
+    assert!(html.to_string() == "a <b>b</b>");
+    assert!(html.children().count() == 2);
+
+    let mut html = Html::parse("<span>text</span>").unwrap();
+    html.unwrap_tag();
+    assert!(html == Html::text("text"));
+}
+
+#[test]
+fn unwrap_empty_and_other_nodes() {
+    let mut html = Html::parse("<br/>").unwrap();
+    html.unwrap_tag();
+    assert!(html.is_empty());
+
+    let mut html = Html::parse("<img src='a.png'>").unwrap();
+    html.unwrap_tag();
+    assert!(html.is_empty());
+
+    let mut text = Html::text("a");
+    text.unwrap_tag();
+    assert!(text == Html::text("a"));
+}
+
+#[test]
+fn unwrap_among_siblings() {
+    let mut html = Html::parse("<div><p>a<span>b<i>c</i></span><br>d</p></div>").unwrap();
+    let paragraph = html.children_mut().next().unwrap();
+    for child in paragraph.children_mut() {
+        if matches!(child, Html::Tag { tag, .. } if tag.as_name() != "i") {
+            child.unwrap_tag();
+        }
+    }
+    html.flatten();
+    let paragraph = html.children().next().unwrap();
+    assert!(paragraph.children().count() == 4);
+    assert!(
+        paragraph
+            .children()
+            .all(|child| !matches!(child, Html::Vec(_) | Html::Empty))
+    );
+    assert!(html.to_string() == "<div><p>ab<i>c</i>d</p></div>");
+}
+
+#[test]
+fn wrap_then_unwrap() {
+    let original = Html::parse("<p>a</p><p>b</p>").unwrap();
+    let mut html = original.clone();
+    html.wrap(Tag::builder("div").build());
+    assert!(html.to_string() == "<div><p>a</p><p>b</p></div>");
+    html.unwrap_tag();
+    assert!(html == original);
+}
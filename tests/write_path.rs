@@ -0,0 +1,40 @@
+use html_filter::prelude::*;
+
+const HTML: &str = r#"<!DOCTYPE html><html><body><h1 id="title">Title</h1><ul><li>A</li><li class="b">B <i>b</i></li></ul></body></html>"#;
+
+fn write_path(tree: &Html, path: &[usize]) -> String {
+    let mut output = String::new();
+    tree.write_path(path, &mut output).unwrap();
+    output
+}
+
+#[test]
+fn subtree_equals_outer_html() {
+    let tree = Html::parse(HTML).unwrap();
+    for path in [&[0][..], &[1], &[1, 0], &[1, 0, 1, 1], &[1, 0, 1, 1, 1, 0]] {
+        assert!(write_path(&tree, path) == tree.at_path(path).unwrap().to_string());
+    }
+    assert!(write_path(&tree, &[1, 0, 1, 1]) == r#"<li class="b">B <i>b</i></li>"#);
+    assert!(write_path(&tree, &[1, 0, 0, 0]) == "Title");
+}
+
+#[test]
+fn changed_fragments() {
+    let old = Html::parse(HTML).unwrap();
+    let new = Html::parse(&HTML.replace("<li>A</li>", "<li>A2</li>")).unwrap();
+    let patches = Html::diff(&old, &new);
+    assert!(patches.len() == 1);
+    let path = patches[0].as_path();
+    assert!(write_path(&new, path) == "A2");
+    assert!(write_path(&new, &path[..path.len() - 1]) == "<li>A2</li>");
+}
+
+#[test]
+fn invalid_paths() {
+    let tree = Html::parse(HTML).unwrap();
+    let mut output = String::new();
+    assert!(tree.write_path(&[], &mut output).is_err());
+    assert!(tree.write_path(&[2], &mut output).is_err());
+    assert!(tree.write_path(&[0, 0], &mut output).is_err());
+    assert!(output.is_empty());
+}
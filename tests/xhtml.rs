@@ -0,0 +1,37 @@
+use html_filter::prelude::*;
+
+fn xhtml(html: &str) -> String {
+    Html::parse(html).unwrap().display_xhtml().to_string()
+}
+
+#[test]
+fn html_and_xhtml() {
+    let tree = Html::parse("<p>a<br>b</p>").unwrap();
+    assert!(tree.to_string() == "<p>a<br>b</p>");
+    assert!(tree.display_xhtml().to_string() == "<p>a<br />b</p>");
+}
+
+#[test]
+fn explicit_closing() {
+    assert!(xhtml("<div><p>a</div>") == "<div><p>a</p></div>");
+    assert!(xhtml("<div></div><img src='a.png'/>") == "<div></div><img src='a.png' />");
+    assert!(xhtml("<textarea></textarea>") == "<textarea></textarea>");
+}
+
+#[test]
+fn lowercase_names() {
+    assert!(
+        xhtml(r#"<DIV ID="a"><INPUT TYPE="checkbox" CHECKED/></DIV>"#)
+            == r#"<div id="a"><input type="checkbox" checked="checked" /></div>"#
+    );
+    assert!(
+        xhtml("<svg viewBox='0 0 1 1'><linearGradient/></svg>")
+            == "<svg viewBox='0 0 1 1'><linearGradient /></svg>"
+    );
+}
+
+#[test]
+fn other_nodes() {
+    let html = "<!DOCTYPE html><!-- note -->text";
+    assert!(xhtml(html) == html);
+}
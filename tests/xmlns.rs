@@ -0,0 +1,57 @@
+use html_filter::prelude::*;
+
+const SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:ev='http://www.w3.org/2001/xml-events' viewBox="0 0 10 10"><use xlink:href="#a" ev:event="click"></use></svg>"##;
+
+#[test]
+fn svg_roundtrip() {
+    assert!(Html::parse(SVG).unwrap().to_string() == SVG);
+    let options = ParseOptions::new()
+        .preserve_source(true)
+        .lowercase_names(true);
+    let (tree, _) = Html::parse_with_warnings(SVG, &options).unwrap();
+    assert!(tree.to_string() == SVG);
+    assert!(FlatHtml::from(&tree).to_string() == SVG);
+}
+
+#[test]
+fn self_closing_roundtrip() {
+    let svg = SVG.replace("></use>", "/>");
+    let options = ParseOptions::new().preserve_source(true);
+    let (tree, _) = Html::parse_with_warnings(&svg, &options).unwrap();
+    assert!(tree.to_string() == svg);
+    assert!(Html::parse(&svg).unwrap().to_string() == SVG);
+}
+
+#[test]
+fn namespace_declarations() {
+    let tree = Html::parse(SVG).unwrap();
+    let Html::Tag { tag, .. } = &tree else {
+        unreachable!()
+    };
+    let names = tag
+        .as_attrs()
+        .iter()
+        .map(|attr| attr.as_name().as_str())
+        .collect::<Vec<_>>();
+    assert!(names == ["xmlns", "xmlns:xlink", "xmlns:ev", "viewBox"]);
+    assert!(
+        tag.find_attr_value("xmlns:xlink")
+            .is_some_and(|value| value == "http://www.w3.org/1999/xlink")
+    );
+    let used = tree.find(&Filter::new().tag_name("use"));
+    let Html::Tag { tag: use_tag, .. } = used else {
+        unreachable!()
+    };
+    assert!(
+        use_tag
+            .find_attr_value("xlink:href")
+            .is_some_and(|value| value == "#a")
+    );
+}
+
+#[test]
+fn html_namespace_declarations() {
+    let html = r##"<html xmlns="http://www.w3.org/1999/xhtml" xmlns:og="http://ogp.me/ns#" xml:lang="en"></html>"##;
+    assert!(Html::parse(html).unwrap().to_string() == html);
+    assert!(Html::parse(html).unwrap().display_xhtml().to_string() == html);
+}